@@ -0,0 +1,4 @@
+-- Add down migration script here
+ALTER TABLE installed_blender_versions DROP COLUMN build_branch;
+ALTER TABLE installed_blender_versions DROP COLUMN build_date;
+ALTER TABLE installed_blender_versions DROP COLUMN build_hash;
@@ -0,0 +1,4 @@
+-- Add up migration script here
+ALTER TABLE installed_blender_versions ADD COLUMN build_hash TEXT NULL;
+ALTER TABLE installed_blender_versions ADD COLUMN build_date TEXT NULL;
+ALTER TABLE installed_blender_versions ADD COLUMN build_branch TEXT NULL;
@@ -0,0 +1,4 @@
+-- Add down migration script here
+ALTER TABLE blender_repo_paths DROP COLUMN exclude_patterns;
+ALTER TABLE blender_repo_paths DROP COLUMN include_patterns;
+ALTER TABLE blender_repo_paths DROP COLUMN scan_depth;
@@ -0,0 +1,4 @@
+-- Add up migration script here
+ALTER TABLE blender_repo_paths ADD COLUMN scan_depth INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE blender_repo_paths ADD COLUMN include_patterns TEXT NOT NULL DEFAULT '';
+ALTER TABLE blender_repo_paths ADD COLUMN exclude_patterns TEXT NOT NULL DEFAULT '';
@@ -0,0 +1,3 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS idx_launch_sessions_started;
+DROP TABLE IF EXISTS launch_sessions;
@@ -0,0 +1,19 @@
+-- Add up migration script here
+CREATE TABLE launch_sessions (
+    id TEXT PRIMARY KEY NOT NULL,
+    installed_blender_version_id TEXT NOT NULL,
+    blender_version TEXT NOT NULL,
+    launch_argument_id TEXT NULL,
+    python_script_id TEXT NULL,
+    project_file_id TEXT NULL,
+    arguments_json TEXT NOT NULL DEFAULT '[]',
+    started TEXT NOT NULL,
+    ended TEXT NULL,
+    exit_code INTEGER NULL,
+    exit_signal INTEGER NULL,
+    log_file_path TEXT NULL,
+    created TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    modified TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    accessed TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+CREATE INDEX idx_launch_sessions_started ON launch_sessions(started);
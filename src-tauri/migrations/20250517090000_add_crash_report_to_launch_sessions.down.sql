@@ -0,0 +1,3 @@
+-- Add down migration script here
+ALTER TABLE launch_sessions DROP COLUMN stderr_tail;
+ALTER TABLE launch_sessions DROP COLUMN crash_report_file_path;
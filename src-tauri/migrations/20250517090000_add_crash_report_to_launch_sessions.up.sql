@@ -0,0 +1,3 @@
+-- Add up migration script here
+ALTER TABLE launch_sessions ADD COLUMN crash_report_file_path TEXT NULL;
+ALTER TABLE launch_sessions ADD COLUMN stderr_tail TEXT NULL;
@@ -0,0 +1,2 @@
+-- Add down migration script here
+ALTER TABLE launch_arguments DROP COLUMN preset_json;
@@ -0,0 +1,2 @@
+-- Add up migration script here
+ALTER TABLE launch_arguments ADD COLUMN preset_json TEXT NULL;
@@ -0,0 +1,3 @@
+-- Add down migration script here
+ALTER TABLE launch_arguments DROP COLUMN working_directory;
+ALTER TABLE launch_arguments DROP COLUMN environment_json;
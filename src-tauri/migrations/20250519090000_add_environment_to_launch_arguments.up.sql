@@ -0,0 +1,3 @@
+-- Add up migration script here
+ALTER TABLE launch_arguments ADD COLUMN environment_json TEXT NOT NULL DEFAULT '[]';
+ALTER TABLE launch_arguments ADD COLUMN working_directory TEXT NULL;
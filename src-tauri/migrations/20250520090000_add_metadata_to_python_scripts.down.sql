@@ -0,0 +1,7 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS idx_python_script_snapshots_launch_session_id;
+DROP TABLE IF EXISTS python_script_snapshots;
+ALTER TABLE python_scripts DROP COLUMN content_hash;
+ALTER TABLE python_scripts DROP COLUMN metadata_json;
+ALTER TABLE python_scripts DROP COLUMN description;
+ALTER TABLE python_scripts DROP COLUMN name;
@@ -0,0 +1,16 @@
+-- Add up migration script here
+ALTER TABLE python_scripts ADD COLUMN name TEXT NOT NULL DEFAULT '';
+ALTER TABLE python_scripts ADD COLUMN description TEXT NOT NULL DEFAULT '';
+ALTER TABLE python_scripts ADD COLUMN metadata_json TEXT NOT NULL DEFAULT '{}';
+ALTER TABLE python_scripts ADD COLUMN content_hash TEXT NULL;
+
+CREATE TABLE python_script_snapshots (
+    id TEXT PRIMARY KEY NOT NULL,
+    python_script_id TEXT NOT NULL,
+    launch_session_id TEXT NULL,
+    script_file_path TEXT NOT NULL,
+    content_hash TEXT NOT NULL,
+    snapshot_file_path TEXT NOT NULL,
+    created TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+CREATE INDEX idx_python_script_snapshots_launch_session_id ON python_script_snapshots(launch_session_id);
@@ -0,0 +1,7 @@
+-- Add down migration script here
+ALTER TABLE python_scripts DROP COLUMN is_missing;
+ALTER TABLE python_scripts DROP COLUMN tags_json;
+ALTER TABLE python_scripts DROP COLUMN library_folder_id;
+
+DROP INDEX IF EXISTS idx_unique_python_script_library_folder_path;
+DROP TABLE IF EXISTS python_script_library_folders;
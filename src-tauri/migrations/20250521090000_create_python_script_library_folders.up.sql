@@ -0,0 +1,13 @@
+-- Add up migration script here
+CREATE TABLE python_script_library_folders (
+    id TEXT PRIMARY KEY NOT NULL,
+    folder_path TEXT NOT NULL,
+    created TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    modified TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    accessed TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+CREATE UNIQUE INDEX idx_unique_python_script_library_folder_path ON python_script_library_folders(folder_path);
+
+ALTER TABLE python_scripts ADD COLUMN library_folder_id TEXT NULL;
+ALTER TABLE python_scripts ADD COLUMN tags_json TEXT NOT NULL DEFAULT '[]';
+ALTER TABLE python_scripts ADD COLUMN is_missing INTEGER NOT NULL DEFAULT 0;
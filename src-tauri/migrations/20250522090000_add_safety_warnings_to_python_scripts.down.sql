@@ -0,0 +1,2 @@
+-- Add down migration script here
+ALTER TABLE python_scripts DROP COLUMN safety_warnings_json;
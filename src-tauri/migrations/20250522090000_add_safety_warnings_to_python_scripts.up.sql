@@ -0,0 +1,2 @@
+-- Add up migration script here
+ALTER TABLE python_scripts ADD COLUMN safety_warnings_json TEXT NOT NULL DEFAULT '[]';
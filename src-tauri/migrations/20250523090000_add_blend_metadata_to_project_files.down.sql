@@ -0,0 +1,10 @@
+-- Add down migration script here
+ALTER TABLE project_files DROP COLUMN metadata_file_modified;
+ALTER TABLE project_files DROP COLUMN resolution_percentage;
+ALTER TABLE project_files DROP COLUMN resolution_y;
+ALTER TABLE project_files DROP COLUMN resolution_x;
+ALTER TABLE project_files DROP COLUMN render_engine;
+ALTER TABLE project_files DROP COLUMN frame_end;
+ALTER TABLE project_files DROP COLUMN frame_start;
+ALTER TABLE project_files DROP COLUMN scene_names_json;
+ALTER TABLE project_files DROP COLUMN saved_blender_version;
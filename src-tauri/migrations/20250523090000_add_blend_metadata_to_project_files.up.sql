@@ -0,0 +1,10 @@
+-- Add up migration script here
+ALTER TABLE project_files ADD COLUMN saved_blender_version TEXT NULL;
+ALTER TABLE project_files ADD COLUMN scene_names_json TEXT NOT NULL DEFAULT '[]';
+ALTER TABLE project_files ADD COLUMN frame_start INTEGER NULL;
+ALTER TABLE project_files ADD COLUMN frame_end INTEGER NULL;
+ALTER TABLE project_files ADD COLUMN render_engine TEXT NULL;
+ALTER TABLE project_files ADD COLUMN resolution_x INTEGER NULL;
+ALTER TABLE project_files ADD COLUMN resolution_y INTEGER NULL;
+ALTER TABLE project_files ADD COLUMN resolution_percentage INTEGER NULL;
+ALTER TABLE project_files ADD COLUMN metadata_file_modified TEXT NULL;
@@ -0,0 +1,2 @@
+-- Add down migration script here
+ALTER TABLE project_files DROP COLUMN file_hash;
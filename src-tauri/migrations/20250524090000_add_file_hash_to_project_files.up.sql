@@ -0,0 +1,2 @@
+-- Add up migration script here
+ALTER TABLE project_files ADD COLUMN file_hash TEXT NULL;
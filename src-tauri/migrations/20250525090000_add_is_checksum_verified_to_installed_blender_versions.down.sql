@@ -0,0 +1,2 @@
+-- Add down migration script here
+ALTER TABLE installed_blender_versions DROP COLUMN is_checksum_verified;
@@ -0,0 +1,2 @@
+-- Add up migration script here
+ALTER TABLE installed_blender_versions ADD COLUMN is_checksum_verified BOOLEAN NULL;
@@ -0,0 +1,4 @@
+-- Add down migration script here
+ALTER TABLE launch_sessions ADD COLUMN python_script_id TEXT NULL;
+UPDATE launch_sessions SET python_script_id = json_extract(python_script_ids_json, '$[0]');
+ALTER TABLE launch_sessions DROP COLUMN python_script_ids_json;
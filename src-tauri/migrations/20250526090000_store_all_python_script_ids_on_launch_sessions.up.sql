@@ -0,0 +1,4 @@
+-- Add up migration script here
+ALTER TABLE launch_sessions ADD COLUMN python_script_ids_json TEXT NOT NULL DEFAULT '[]';
+UPDATE launch_sessions SET python_script_ids_json = json_array(python_script_id) WHERE python_script_id IS NOT NULL;
+ALTER TABLE launch_sessions DROP COLUMN python_script_id;
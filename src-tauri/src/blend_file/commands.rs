@@ -0,0 +1,1261 @@
+use std::io::{BufRead, Read, Write};
+
+use super::{
+    BLEND_FILE_DEPENDENCY_BLOCKS, BLEND_FILE_MAGIC, BLEND_IMAGE_SOURCES_WITHOUT_FILE,
+    BLEND_IMAGE_SOURCE_SEQUENCE, BLEND_IMAGE_SOURCE_TILED, BLEND_IMAGE_TILE_TOKENS, GZIP_MAGIC,
+    PNG_SIGNATURE, ZSTD_MAGIC,
+};
+use crate::models::{
+    BlendFile, BlendFileBlock, BlendFileDependency, BlendFileField, BlendFileHeader,
+    BlendFileMetadata, BlendFileSdna, BlendFileSdnaStruct, BlendFileThumbnail,
+};
+
+/// ID: BF_001
+/// ABC analīzes rezultāts:4,8,7
+pub fn open_blend_file_stream(file_path: &std::path::Path) -> Result<Box<dyn Read>, String> {
+    let file = match std::fs::File::open(file_path) {
+        // A (1.a.) let file =; C (3.b) match; B (2.a.) ::open()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => return Err(format!("Failed to open blend file: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    };
+    let mut reader = std::io::BufReader::new(file); // A (1.a.) let mut reader =; B (2.a.) ::new()
+    let magic = match reader.fill_buf() {
+        // A (1.a.) let magic =; C (3.b) match; B (2.a.) .fill_buf()
+        Ok(val) => val.to_vec(), // C (3.c.) Ok(); B (2.a.) .to_vec()
+        Err(err) => return Err(format!("Failed to read blend file: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    };
+    if magic.starts_with(ZSTD_MAGIC) {
+        // C (3.a.) magic.starts_with() == true; B (2.a.) .starts_with()
+        // The decoder reads all frames in a row, the seek table frame at the end is skipped.
+        match zstd::stream::read::Decoder::with_buffer(reader) {
+            // C (3.b) match; B (2.a.) ::with_buffer()
+            Ok(val) => Ok(Box::new(val)), // C (3.c.) Ok(); B (2.a.) ::new()
+            Err(err) => Err(format!("Failed to decompress blend file: {:?}", err)), // C (3.c) Err()
+        }
+    } else if magic.starts_with(GZIP_MAGIC) {
+        // C (3.a.) magic.starts_with() == true; B (2.a.) .starts_with()
+        Ok(Box::new(flate2::bufread::GzDecoder::new(reader))) // B (2.a.) ::new(); B (2.a.) ::new()
+    } else {
+        // C (3.b.) else
+        Ok(Box::new(reader)) // B (2.a.) ::new()
+    }
+}
+
+/// ID: BF_002
+/// ABC analīzes rezultāts:10,15,12
+pub fn read_blend_file_header(reader: &mut dyn Read) -> Result<BlendFileHeader, String> {
+    let mut header_bytes = vec![0u8; 12]; // A (1.a.) let mut header_bytes =
+    if let Err(err) = reader.read_exact(&mut header_bytes) {
+        // A (1.d.) if let Err(); B (2.a.) .read_exact()
+        return Err(format!("Failed to read blend file header: {:?}", err)); // B (2.b.) priekšlaicīgs return
+    }
+    if !header_bytes.starts_with(BLEND_FILE_MAGIC) {
+        // C (3.a.) header_bytes.starts_with() != true; B (2.a.) .starts_with()
+        return Err(format!(
+            "Failed to read blend file header: not a blend file"
+        )); // B (2.b.) priekšlaicīgs return
+    }
+    // Blender 5.0 writes `BLENDER17-01v0500` with the header size and file format version where
+    // older versions write the pointer size, as in `BLENDER-v405`. The new layout has 8 byte pointers.
+    let (pointer_size_code, endianness_code, version_bytes, file_format_version) =
+        if header_bytes[7].is_ascii_digit() {
+            // A (1.a.) let (...) =; C (3.a.) .is_ascii_digit() == true; B (2.a.) .is_ascii_digit()
+            header_bytes.resize(17, 0); // A (1.c.) .resize()
+            if let Err(err) = reader.read_exact(&mut header_bytes[12..]) {
+                // A (1.d.) if let Err(); B (2.a.) .read_exact()
+                return Err(format!("Failed to read blend file header: {:?}", err));
+                // B (2.b.) priekšlaicīgs return
+            }
+            let file_format_version = std::str::from_utf8(&header_bytes[10..12])
+                .ok()
+                .and_then(|val| val.parse::<u32>().ok())
+                .unwrap_or(1); // A (1.a.) let file_format_version =; B (2.a.) ::from_utf8(); B (2.a.) .ok(); B (2.a.) .and_then(); B (2.a.) .parse(); B (2.a.) .ok(); B (2.a.) .unwrap_or()
+            (
+                b'-',
+                header_bytes[12],
+                header_bytes[13..17].to_vec(),
+                file_format_version,
+            ) // B (2.a.) .to_vec()
+        } else {
+            // C (3.b.) else
+            (
+                header_bytes[7],
+                header_bytes[8],
+                header_bytes[9..12].to_vec(),
+                0,
+            ) // B (2.a.) .to_vec()
+        };
+    let pointer_size = match pointer_size_code {
+        // A (1.a.) let pointer_size =; C (3.b) match
+        b'_' => 4, // C (3.c) b'_' =>
+        b'-' => 8, // C (3.c) b'-' =>
+        _ => {
+            return Err(format!(
+                "Failed to read blend file header: unknown pointer size"
+            ))
+        } // C (3.c) _ =>; B (2.b.) priekšlaicīgs return
+    };
+    let is_little_endian = match endianness_code {
+        // A (1.a.) let is_little_endian =; C (3.b) match
+        b'v' => true,  // C (3.c) b'v' =>
+        b'V' => false, // C (3.c) b'V' =>
+        _ => {
+            return Err(format!(
+                "Failed to read blend file header: unknown endianness"
+            ))
+        } // C (3.c) _ =>; B (2.b.) priekšlaicīgs return
+    };
+    let version = match std::str::from_utf8(&version_bytes)
+        .ok()
+        .and_then(|val| val.parse::<u32>().ok())
+    {
+        // A (1.a.) let version =; C (3.b) match; B (2.a.) ::from_utf8(); B (2.a.) .ok(); B (2.a.) .and_then(); B (2.a.) .parse(); B (2.a.) .ok()
+        Some(val) => val, // C (3.c) Some()
+        None => return Err(format!("Failed to read blend file header: unknown version")), // C (3.c) None =>; B (2.b.) priekšlaicīgs return
+    };
+    Ok(BlendFileHeader {
+        pointer_size,
+        is_little_endian,
+        version,
+        file_format_version,
+    })
+}
+
+/// ID: BF_003
+/// ABC analīzes rezultāts:3,2,3
+pub fn read_blend_unsigned(bytes: &[u8], is_little_endian: bool) -> u64 {
+    let mut value = 0u64; // A (1.a.) let mut value =
+    for index in 0..bytes.len().min(8) {
+        // A (1.a.) let index =; B (2.a.) .len(); B (2.a.) .min()
+        let byte = if is_little_endian {
+            // A (1.a.) let byte =; C (3.a.) is_little_endian == true
+            bytes[bytes.len() - 1 - index] // B (2.a.) .len()
+        } else {
+            // C (3.b.) else
+            bytes[index]
+        };
+        value = (value << 8) | byte as u64; // A (1.a.) value =
+    }
+    value
+}
+
+/// ID: BF_004
+/// ABC analīzes rezultāts:2,3,3
+pub fn read_blend_integer(
+    data: &[u8],
+    offset: usize,
+    size: usize,
+    is_little_endian: bool,
+) -> Option<i64> {
+    if size == 0 || size > 8 {
+        // C (3.a.) size == 0; C (3.a.) size > 8
+        return None; // B (2.b.) priekšlaicīgs return
+    }
+    let value = read_blend_unsigned(data.get(offset..offset + size)?, is_little_endian); // A (1.a.) let value =; B (2.a.) read_blend_unsigned(); B (2.a.) .get()
+
+    // Shifting up and back down again sign extends values narrower than 8 bytes.
+    let shift = 64 - size * 8; // A (1.a.) let shift =
+    Some(((value << shift) as i64) >> shift)
+}
+
+/// ID: BF_005
+/// ABC analīzes rezultāts:2,6,1
+pub fn read_blend_string(data: &[u8], offset: usize, size: usize) -> Option<String> {
+    let bytes = data.get(offset..offset + size)?; // A (1.a.) let bytes =; B (2.a.) .get()
+    let end = bytes
+        .iter()
+        .position(|val| *val == 0)
+        .unwrap_or(bytes.len()); // A (1.a.) let end =; B (2.a.) .iter(); B (2.a.) .position(); C (3.a.) val == 0; B (2.a.) .unwrap_or(); B (2.a.) .len()
+    Some(String::from_utf8_lossy(&bytes[..end]).to_string()) // B (2.a.) ::from_utf8_lossy(); B (2.a.) .to_string()
+}
+
+/// ID: BF_006
+/// ABC analīzes rezultāts:12,26,17
+pub fn read_blend_file_blocks(
+    reader: &mut dyn Read,
+    header: &BlendFileHeader,
+    block_codes: &[&str],
+) -> Result<Vec<BlendFileBlock>, String> {
+    let mut blocks = Vec::new(); // A (1.a.) let mut blocks =; B (2.a.) ...::new()
+    let pointer_size = header.pointer_size; // A (1.a.) let pointer_size =
+    let block_header_size = if header.file_format_version >= 1 {
+        // A (1.a.) let block_header_size =; C (3.a.) header.file_format_version >= 1
+        32
+    } else {
+        // C (3.b.) else
+        16 + pointer_size
+    };
+    let mut block_header = vec![0u8; block_header_size]; // A (1.a.) let mut block_header =
+    loop {
+        if let Err(err) = reader.read_exact(&mut block_header) {
+            // A (1.d.) if let Err(); B (2.a.) .read_exact()
+            return Err(format!("Failed to read blend file block: {:?}", err)); // B (2.b.) priekšlaicīgs return
+        }
+        let code_length = block_header[..4]
+            .iter()
+            .position(|val| *val == 0)
+            .unwrap_or(4); // A (1.a.) let code_length =; B (2.a.) .iter(); B (2.a.) .position(); C (3.a.) val == 0; B (2.a.) .unwrap_or()
+        let code = String::from_utf8_lossy(&block_header[..code_length]).to_string(); // A (1.a.) let code =; B (2.a.) ::from_utf8_lossy(); B (2.a.) .to_string()
+        if code == "ENDB" {
+            // C (3.a.) code == "ENDB"
+            break; // B (2.b.) break
+        }
+        let read_field = |start: usize, end: usize| {
+            // A (1.a.) let read_field =
+            read_blend_unsigned(&block_header[start..end], header.is_little_endian)
+            // B (2.a.) read_blend_unsigned()
+        };
+        // Blender 5.0 block headers are code, SDNA index, address and a 8 byte length. Older ones
+        // are code, 4 byte length and address, followed by the SDNA index and count.
+        let (length, old_address) = if header.file_format_version >= 1 {
+            // A (1.a.) let (length, old_address) =; C (3.a.) header.file_format_version >= 1
+            (read_field(16, 24), read_field(8, 16)) // B (2.a.) read_field()
+        } else {
+            // C (3.b.) else
+            (read_field(4, 8), read_field(8, 8 + pointer_size)) // B (2.a.) read_field()
+        };
+        if code != "DNA1" && !block_codes.contains(&code.as_str()) {
+            // C (3.a.) code != "DNA1"; C (3.a.) block_codes.contains() != true; B (2.a.) .contains(); B (2.a.) .as_str()
+            // Compressed streams can't seek, skipped blocks are read and dropped.
+            match std::io::copy(&mut (&mut *reader).take(length), &mut std::io::sink()) {
+                // C (3.b) match; B (2.a.) ::copy(); B (2.a.) .take(); B (2.a.) ::sink()
+                Ok(val) if val == length => continue, // C (3.c.) Ok(); C (3.a.) val == length; B (2.b.) continue
+                Ok(_) => {
+                    // C (3.c.) Ok()
+                    return Err(format!(
+                        "Failed to read blend file block: unexpected end of file"
+                    )); // B (2.b.) priekšlaicīgs return
+                }
+                Err(err) => return Err(format!("Failed to read blend file block: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+            }
+        }
+        // The length comes from the file, the buffer only grows as far as there is data to read.
+        let mut data = Vec::new(); // A (1.a.) let mut data =; B (2.a.) ...::new()
+        match (&mut *reader).take(length).read_to_end(&mut data) {
+            // C (3.b) match; B (2.a.) .take(); B (2.a.) .read_to_end()
+            Ok(val) if val as u64 == length => {} // C (3.c.) Ok(); C (3.a.) val == length
+            Ok(_) => {
+                // C (3.c.) Ok()
+                return Err(format!(
+                    "Failed to read blend file block: unexpected end of file"
+                )); // B (2.b.) priekšlaicīgs return
+            }
+            Err(err) => return Err(format!("Failed to read blend file block: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+        }
+        blocks.push(BlendFileBlock {
+            // A (1.c.) .push()
+            code,
+            old_address,
+            data,
+        });
+    }
+    Ok(blocks)
+}
+
+/// ID: BF_007
+/// ABC analīzes rezultāts:20,33,16
+pub fn parse_blend_file_sdna(data: &[u8], is_little_endian: bool) -> Result<BlendFileSdna, String> {
+    let read_number = |offset: usize, size: usize| {
+        // A (1.a.) let read_number =
+        data.get(offset..offset + size)
+            .map(|val| read_blend_unsigned(val, is_little_endian) as usize) // B (2.a.) .get(); B (2.a.) .map(); B (2.a.) read_blend_unsigned()
+    };
+    let is_tag = |offset: usize, tag: &[u8]| data.get(offset..offset + 4) == Some(tag); // A (1.a.) let is_tag =; C (3.a.) data.get() == Some(); B (2.a.) .get()
+    let read_strings = |offset: &mut usize| -> Option<Vec<String>> {
+        // A (1.a.) let read_strings =
+        let count = read_number(*offset, 4)?; // A (1.a.) let count =; B (2.a.) read_number()
+        *offset += 4; // A (1.b.) *offset +=
+
+        // Counts come from the file, capacities are capped at what the block could hold.
+        let mut strings = Vec::with_capacity(count.min(data.len())); // A (1.a.) let mut strings =; B (2.a.) ::with_capacity(); B (2.a.) .min(); B (2.a.) .len()
+        for _ in 0..count {
+            let length = data.get(*offset..)?.iter().position(|val| *val == 0)?; // A (1.a.) let length =; B (2.a.) .get(); B (2.a.) .iter(); B (2.a.) .position(); C (3.a.) val == 0
+            strings.push(String::from_utf8_lossy(&data[*offset..*offset + length]).to_string()); // A (1.c.) .push(); B (2.a.) ::from_utf8_lossy(); B (2.a.) .to_string()
+            *offset += length + 1; // A (1.b.) *offset +=
+        }
+        // Every section starts 4 byte aligned.
+        *offset = (*offset + 3) & !3; // A (1.a.) *offset =
+        Some(strings)
+    };
+    let parse = || -> Option<BlendFileSdna> {
+        // A (1.a.) let parse =
+        if !is_tag(0, b"SDNA") || !is_tag(4, b"NAME") {
+            // C (3.a.) is_tag() != true; B (2.a.) is_tag()
+            return None; // B (2.b.) priekšlaicīgs return
+        }
+        let mut offset = 8; // A (1.a.) let mut offset =
+        let names = read_strings(&mut offset)?; // A (1.a.) let names =; B (2.a.) read_strings()
+        if !is_tag(offset, b"TYPE") {
+            // C (3.a.) is_tag() != true; B (2.a.) is_tag()
+            return None; // B (2.b.) priekšlaicīgs return
+        }
+        offset += 4; // A (1.b.) offset +=
+        let types = read_strings(&mut offset)?; // A (1.a.) let types =; B (2.a.) read_strings()
+        if !is_tag(offset, b"TLEN") {
+            // C (3.a.) is_tag() != true; B (2.a.) is_tag()
+            return None; // B (2.b.) priekšlaicīgs return
+        }
+        offset += 4; // A (1.b.) offset +=
+        let mut type_lengths = Vec::with_capacity(types.len()); // A (1.a.) let mut type_lengths =; B (2.a.) ::with_capacity(); B (2.a.) .len()
+        for index in 0..types.len() {
+            // A (1.a.) let index =; B (2.a.) .len()
+            type_lengths.push(read_number(offset + index * 2, 2)?); // A (1.c.) .push(); B (2.a.) read_number()
+        }
+        offset = (offset + types.len() * 2 + 3) & !3; // A (1.a.) offset =; B (2.a.) .len()
+        if !is_tag(offset, b"STRC") {
+            // C (3.a.) is_tag() != true; B (2.a.) is_tag()
+            return None; // B (2.b.) priekšlaicīgs return
+        }
+        let struct_count = read_number(offset + 4, 4)?; // A (1.a.) let struct_count =; B (2.a.) read_number()
+        offset += 8; // A (1.b.) offset +=
+        let mut structs = Vec::with_capacity(struct_count.min(data.len())); // A (1.a.) let mut structs =; B (2.a.) ::with_capacity(); B (2.a.) .min(); B (2.a.) .len()
+        for _ in 0..struct_count {
+            let type_index = read_number(offset, 2)?; // A (1.a.) let type_index =; B (2.a.) read_number()
+            let field_count = read_number(offset + 2, 2)?; // A (1.a.) let field_count =; B (2.a.) read_number()
+            offset += 4; // A (1.b.) offset +=
+            let mut fields = Vec::with_capacity(field_count.min(data.len())); // A (1.a.) let mut fields =; B (2.a.) ::with_capacity(); B (2.a.) .min(); B (2.a.) .len()
+            for _ in 0..field_count {
+                fields.push((read_number(offset, 2)?, read_number(offset + 2, 2)?)); // A (1.c.) .push(); B (2.a.) read_number()
+                offset += 4; // A (1.b.) offset +=
+            }
+            structs.push(BlendFileSdnaStruct { type_index, fields }); // A (1.c.) .push()
+        }
+        Some(BlendFileSdna {
+            names,
+            types,
+            type_lengths,
+            structs,
+        })
+    };
+    match parse() {
+        // C (3.b) match; B (2.a.) parse()
+        Some(val) => Ok(val), // C (3.c) Some()
+        None => Err(format!("Failed to parse blend file SDNA")), // C (3.c) None =>
+    }
+}
+
+/// ID: BF_008
+/// ABC analīzes rezultāts:2,10,3
+pub fn get_blend_sdna_field_size(
+    sdna: &BlendFileSdna,
+    pointer_size: usize,
+    type_index: usize,
+    name: &str,
+) -> usize {
+    // Names such as `*next`, `(*func)()` and `name[64]` hold the pointer and array parts.
+    let base_size = if name.starts_with('*') || name.starts_with('(') {
+        // A (1.a.) let base_size =; C (3.a.) name.starts_with() == true; B (2.a.) .starts_with()
+        pointer_size
+    } else {
+        // C (3.b.) else
+        sdna.type_lengths.get(type_index).copied().unwrap_or(0) // B (2.a.) .get(); B (2.a.) .copied(); B (2.a.) .unwrap_or()
+    };
+    let array_length: usize = name
+        .split('[')
+        .skip(1)
+        .filter_map(|val| val.split(']').next())
+        .filter_map(|val| val.parse::<usize>().ok())
+        .product(); // A (1.a.) let array_length =; B (2.a.) .split(); B (2.a.) .skip(); B (2.a.) .filter_map(); B (2.a.) .next(); B (2.a.) .parse(); B (2.a.) .ok(); B (2.a.) .product()
+    base_size * array_length
+}
+
+/// ID: BF_009
+/// ABC analīzes rezultāts:0,4,2
+pub fn get_blend_sdna_field_name(name: &str) -> &str {
+    name.trim_start_matches(|val: char| val == '*' || val == '(')
+        .split(|val: char| val == '[' || val == ')')
+        .next()
+        .unwrap_or("") // B (2.a.) .trim_start_matches(); C (3.a.) val == '*'; C (3.a.) val == '('; B (2.a.) .split(); B (2.a.) .next(); B (2.a.) .unwrap_or()
+}
+
+/// ID: BF_010
+/// ABC analīzes rezultāts:10,18,6
+pub fn find_blend_struct_field(
+    blend_file: &BlendFile,
+    struct_name: &str,
+    field_path: &str,
+) -> Option<BlendFileField> {
+    let sdna = &blend_file.sdna; // A (1.a.) let sdna =
+    let mut struct_type_name = struct_name.to_string(); // A (1.a.) let mut struct_type_name =; B (2.a.) .to_string()
+    let mut struct_offset = 0; // A (1.a.) let mut struct_offset =
+    let mut field = None; // A (1.a.) let mut field =
+
+    // Nested struct members are reached with dotted paths such as `r.xsch` of `Scene`.
+    for field_name in field_path.split('.') {
+        // A (1.a.) let field_name =; B (2.a.) .split()
+        let sdna_struct = sdna
+            .structs
+            .iter()
+            .find(|val| sdna.types.get(val.type_index) == Some(&struct_type_name))?; // A (1.a.) let sdna_struct =; B (2.a.) .iter(); B (2.a.) .find(); C (3.a.) sdna.types.get() == Some(); B (2.a.) .get()
+        let mut field_offset = struct_offset; // A (1.a.) let mut field_offset =
+        let mut found_field = None; // A (1.a.) let mut found_field =
+        for (type_index, name_index) in &sdna_struct.fields {
+            // A (1.a.) let (type_index, name_index) =;
+            let name = sdna.names.get(*name_index)?; // A (1.a.) let name =; B (2.a.) .get()
+            let size =
+                get_blend_sdna_field_size(sdna, blend_file.header.pointer_size, *type_index, name); // A (1.a.) let size =; B (2.a.) get_blend_sdna_field_size()
+            if get_blend_sdna_field_name(name) == field_name {
+                // C (3.a.) get_blend_sdna_field_name() == field_name; B (2.a.) get_blend_sdna_field_name()
+                found_field = Some(BlendFileField {
+                    // A (1.a.) found_field =
+                    offset: field_offset,
+                    size,
+                    type_name: sdna.types.get(*type_index)?.clone(), // B (2.a.) .get(); B (2.a.) .clone()
+                    is_pointer: name.starts_with('*') || name.starts_with('('), // C (3.a.) name.starts_with() == true; B (2.a.) .starts_with()
+                });
+                break; // B (2.b.) break
+            }
+            field_offset += size; // A (1.b.) field_offset +=
+        }
+        let current_field = found_field?; // A (1.a.) let current_field =
+        struct_offset = current_field.offset; // A (1.a.) struct_offset =
+        struct_type_name = current_field.type_name.clone(); // A (1.a.) struct_type_name =; B (2.a.) .clone()
+        field = Some(current_field); // A (1.a.) field =
+    }
+    field
+}
+
+/// ID: BF_011
+/// ABC analīzes rezultāts:1,3,1
+pub fn read_blend_field_integer(
+    blend_file: &BlendFile,
+    data: &[u8],
+    struct_name: &str,
+    field_path: &str,
+) -> Option<i64> {
+    let field = find_blend_struct_field(blend_file, struct_name, field_path)?; // A (1.a.) let field =; B (2.a.) find_blend_struct_field()
+    if field.is_pointer {
+        // C (3.a.) field.is_pointer == true
+        return None; // B (2.b.) priekšlaicīgs return
+    }
+    read_blend_integer(
+        data,
+        field.offset,
+        field.size,
+        blend_file.header.is_little_endian,
+    ) // B (2.a.) read_blend_integer()
+}
+
+/// ID: BF_012
+/// ABC analīzes rezultāts:1,4,1
+pub fn read_blend_field_pointer(
+    blend_file: &BlendFile,
+    data: &[u8],
+    struct_name: &str,
+    field_path: &str,
+) -> Option<u64> {
+    let field = find_blend_struct_field(blend_file, struct_name, field_path)?; // A (1.a.) let field =; B (2.a.) find_blend_struct_field()
+    if !field.is_pointer {
+        // C (3.a.) field.is_pointer != true
+        return None; // B (2.b.) priekšlaicīgs return
+    }
+    let bytes = data.get(field.offset..field.offset + field.size)?; // A (1.a.) let bytes =; B (2.a.) .get()
+    Some(read_blend_unsigned(
+        bytes,
+        blend_file.header.is_little_endian,
+    )) // B (2.a.) read_blend_unsigned()
+}
+
+/// ID: BF_013
+/// ABC analīzes rezultāts:1,2,0
+pub fn read_blend_field_string(
+    blend_file: &BlendFile,
+    data: &[u8],
+    struct_name: &str,
+    field_path: &str,
+) -> Option<String> {
+    let field = find_blend_struct_field(blend_file, struct_name, field_path)?; // A (1.a.) let field =; B (2.a.) find_blend_struct_field()
+    read_blend_string(data, field.offset, field.size) // B (2.a.) read_blend_string()
+}
+
+/// ID: BF_014
+/// ABC analīzes rezultāts:6,8,3
+pub fn read_blend_file(
+    file_path: &std::path::Path,
+    block_codes: &[&str],
+) -> Result<BlendFile, String> {
+    let mut reader = open_blend_file_stream(file_path)?; // A (1.a.) let mut reader =; B (2.a.) open_blend_file_stream()
+    let header = read_blend_file_header(&mut *reader)?; // A (1.a.) let header =; B (2.a.) read_blend_file_header()
+    let mut blocks = read_blend_file_blocks(&mut *reader, &header, block_codes)?; // A (1.a.) let mut blocks =; B (2.a.) read_blend_file_blocks()
+    let sdna_block_index = match blocks.iter().position(|val| val.code == "DNA1") {
+        // A (1.a.) let sdna_block_index =; C (3.b) match; B (2.a.) .iter(); B (2.a.) .position(); C (3.a.) val.code == "DNA1"
+        Some(val) => val, // C (3.c) Some()
+        None => return Err(format!("Failed to read blend file: no SDNA block")), // C (3.c) None =>; B (2.b.) priekšlaicīgs return
+    };
+    let sdna_block = blocks.remove(sdna_block_index); // A (1.a.) let sdna_block =; B (2.a.) .remove()
+    let sdna = parse_blend_file_sdna(&sdna_block.data, header.is_little_endian)?; // A (1.a.) let sdna =; B (2.a.) parse_blend_file_sdna()
+    Ok(BlendFile {
+        header,
+        blocks,
+        sdna,
+    })
+}
+
+/// ID: BF_015
+/// ABC analīzes rezultāts:9,26,7
+pub fn read_blend_file_metadata(file_path: &std::path::Path) -> Result<BlendFileMetadata, String> {
+    let blend_file = read_blend_file(file_path, &["GLOB", "SC"])?; // A (1.a.) let blend_file =; B (2.a.) read_blend_file()
+    let version = blend_file.header.version; // A (1.a.) let version =
+    let mut metadata = BlendFileMetadata {
+        // A (1.a.) let mut metadata =
+        saved_blender_version: format!("{}.{}", version / 100, version % 100),
+        ..Default::default() // B (2.a.) ::default()
+    };
+    let scene_blocks: Vec<&BlendFileBlock> = blend_file
+        .blocks
+        .iter()
+        .filter(|val| val.code == "SC")
+        .collect(); // A (1.a.) let scene_blocks =; B (2.a.) .iter(); B (2.a.) .filter(); C (3.a.) val.code == "SC"; B (2.a.) .collect()
+    for block in &scene_blocks {
+        // A (1.a.) let block =;
+        // ID names start with a two letter type code, `SCScene` is the scene `Scene`.
+        if let Some(val) = read_blend_field_string(&blend_file, &block.data, "Scene", "id.name") {
+            // A (1.d.) if let Some(); B (2.a.) read_blend_field_string()
+            metadata.scene_names.push(val.chars().skip(2).collect()); // A (1.c.) .push(); B (2.a.) .chars(); B (2.a.) .skip(); B (2.a.) .collect()
+        }
+    }
+    let current_scene_address = blend_file
+        .blocks
+        .iter()
+        .find(|val| val.code == "GLOB")
+        .and_then(|val| read_blend_field_pointer(&blend_file, &val.data, "FileGlobal", "curscene")); // A (1.a.) let current_scene_address =; B (2.a.) .iter(); B (2.a.) .find(); C (3.a.) val.code == "GLOB"; B (2.a.) .and_then(); B (2.a.) read_blend_field_pointer()
+    let current_scene = scene_blocks
+        .iter()
+        .find(|val| Some(val.old_address) == current_scene_address)
+        .or(scene_blocks.first()); // A (1.a.) let current_scene =; B (2.a.) .iter(); B (2.a.) .find(); C (3.a.) Some() == current_scene_address; B (2.a.) .or(); B (2.a.) .first()
+    let Some(current_scene) = current_scene else {
+        // A (1.d.) let Some() else
+        return Ok(metadata); // B (2.b.) priekšlaicīgs return
+    };
+    let data = &current_scene.data; // A (1.a.) let data =
+    metadata.frame_start = read_blend_field_integer(&blend_file, data, "Scene", "r.sfra"); // A (1.a.) metadata.frame_start =; B (2.a.) read_blend_field_integer()
+    metadata.frame_end = read_blend_field_integer(&blend_file, data, "Scene", "r.efra"); // A (1.a.) metadata.frame_end =; B (2.a.) read_blend_field_integer()
+    metadata.render_engine = read_blend_field_string(&blend_file, data, "Scene", "r.engine"); // A (1.a.) metadata.render_engine =; B (2.a.) read_blend_field_string()
+    metadata.resolution_x = read_blend_field_integer(&blend_file, data, "Scene", "r.xsch"); // A (1.a.) metadata.resolution_x =; B (2.a.) read_blend_field_integer()
+    metadata.resolution_y = read_blend_field_integer(&blend_file, data, "Scene", "r.ysch"); // A (1.a.) metadata.resolution_y =; B (2.a.) read_blend_field_integer()
+    metadata.resolution_percentage = read_blend_field_integer(&blend_file, data, "Scene", "r.size"); // A (1.a.) metadata.resolution_percentage =; B (2.a.) read_blend_field_integer()
+    Ok(metadata)
+}
+
+/// ID: BF_016
+/// ABC analīzes rezultāts:11,19,7
+pub fn read_blend_file_thumbnail(
+    file_path: &std::path::Path,
+) -> Result<Option<BlendFileThumbnail>, String> {
+    let mut reader = open_blend_file_stream(file_path)?; // A (1.a.) let mut reader =; B (2.a.) open_blend_file_stream()
+    let header = read_blend_file_header(&mut *reader)?; // A (1.a.) let header =; B (2.a.) read_blend_file_header()
+    let blocks = read_blend_file_blocks(&mut *reader, &header, &["TEST"])?; // A (1.a.) let blocks =; B (2.a.) read_blend_file_blocks()
+
+    // Files saved without a preview, or by Blender 2.4x, have no TEST block.
+    let Some(block) = blocks.iter().find(|val| val.code == "TEST") else {
+        // A (1.d.) let Some() else; B (2.a.) .iter(); B (2.a.) .find(); C (3.a.) val.code == "TEST"
+        return Ok(None); // B (2.b.) priekšlaicīgs return
+    };
+    let width = read_blend_integer(&block.data, 0, 4, header.is_little_endian).unwrap_or(0); // A (1.a.) let width =; B (2.a.) read_blend_integer(); B (2.a.) .unwrap_or()
+    let height = read_blend_integer(&block.data, 4, 4, header.is_little_endian).unwrap_or(0); // A (1.a.) let height =; B (2.a.) read_blend_integer(); B (2.a.) .unwrap_or()
+    if width <= 0 || height <= 0 {
+        // C (3.a.) width <= 0; C (3.a.) height <= 0
+        return Ok(None); // B (2.b.) priekšlaicīgs return
+    }
+    let (width, height) = (width as usize, height as usize); // A (1.a.) let (width, height) =
+    let pixels_end = width
+        .checked_mul(height)
+        .and_then(|val| val.checked_mul(4))
+        .and_then(|val| val.checked_add(8)); // A (1.a.) let pixels_end =; B (2.a.) .checked_mul(); B (2.a.) .and_then(); B (2.a.) .checked_mul(); B (2.a.) .and_then(); B (2.a.) .checked_add()
+    let Some(pixels_end) = pixels_end else {
+        // A (1.d.) let Some() else
+        return Err(format!(
+            "Failed to read blend file thumbnail: invalid size {}x{}",
+            width, height
+        )); // B (2.b.) priekšlaicīgs return
+    };
+    let Some(pixels) = block.data.get(8..pixels_end) else {
+        // A (1.d.) let Some() else; B (2.a.) .get()
+        return Err(format!(
+            "Failed to read blend file thumbnail: truncated pixel data"
+        )); // B (2.b.) priekšlaicīgs return
+    };
+    Ok(Some(BlendFileThumbnail {
+        width,
+        height,
+        pixels: pixels.to_vec(), // B (2.a.) .to_vec()
+    }))
+}
+
+/// ID: BF_017
+/// ABC analīzes rezultāts:9,22,5
+pub fn encode_blend_file_thumbnail_png(thumbnail: &BlendFileThumbnail) -> Result<Vec<u8>, String> {
+    let mut raw_pixels = Vec::with_capacity((thumbnail.width * 4 + 1) * thumbnail.height); // A (1.a.) let mut raw_pixels =; B (2.a.) ::with_capacity()
+
+    // Blender stores the rows bottom up, PNG top down. Every row starts with filter type 0.
+    for row in thumbnail.pixels.chunks_exact(thumbnail.width * 4).rev() {
+        // A (1.a.) let row =; B (2.a.) .chunks_exact(); B (2.a.) .rev()
+        raw_pixels.push(0); // A (1.c.) .push()
+        raw_pixels.extend_from_slice(row); // A (1.c.) .extend_from_slice()
+    }
+    let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default()); // A (1.a.) let mut encoder =; B (2.a.) ::new(); B (2.a.) ::new(); B (2.a.) ::default()
+    if let Err(err) = encoder.write_all(&raw_pixels) {
+        // A (1.d.) if let Err(); B (2.a.) .write_all()
+        return Err(format!("Failed to encode thumbnail: {:?}", err)); // B (2.b.) priekšlaicīgs return
+    }
+    let compressed_pixels = match encoder.finish() {
+        // A (1.a.) let compressed_pixels =; C (3.b) match; B (2.a.) .finish()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => return Err(format!("Failed to encode thumbnail: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    };
+    let mut image_header = Vec::new(); // A (1.a.) let mut image_header =; B (2.a.) ::new()
+    image_header.extend_from_slice(&(thumbnail.width as u32).to_be_bytes()); // A (1.c.) .extend_from_slice(); B (2.a.) .to_be_bytes()
+    image_header.extend_from_slice(&(thumbnail.height as u32).to_be_bytes()); // A (1.c.) .extend_from_slice(); B (2.a.) .to_be_bytes()
+
+    // 8 bit RGBA, deflate compression, adaptive filtering, no interlacing.
+    image_header.extend_from_slice(&[8, 6, 0, 0, 0]); // A (1.c.) .extend_from_slice()
+    let mut png = PNG_SIGNATURE.to_vec(); // A (1.a.) let mut png =; B (2.a.) .to_vec()
+    for (chunk_type, chunk_data) in [
+        (b"IHDR", image_header),
+        (b"IDAT", compressed_pixels),
+        (b"IEND", Vec::new()),
+    ] {
+        // A (1.a.) let (chunk_type, chunk_data) =; B (2.a.) ::new()
+        let mut crc = flate2::Crc::new(); // A (1.a.) let mut crc =; B (2.a.) ::new()
+        crc.update(chunk_type); // A (1.c.) .update()
+        crc.update(&chunk_data); // A (1.c.) .update()
+        png.extend_from_slice(&(chunk_data.len() as u32).to_be_bytes()); // A (1.c.) .extend_from_slice(); B (2.a.) .len(); B (2.a.) .to_be_bytes()
+        png.extend_from_slice(chunk_type); // A (1.c.) .extend_from_slice()
+        png.extend_from_slice(&chunk_data); // A (1.c.) .extend_from_slice()
+        png.extend_from_slice(&crc.sum().to_be_bytes()); // A (1.c.) .extend_from_slice(); B (2.a.) .sum(); B (2.a.) .to_be_bytes()
+    }
+    Ok(png)
+}
+
+/// ID: BF_018
+/// ABC analīzes rezultāts:0,4,2
+pub fn resolve_blend_file_path(
+    stored_path: &str,
+    blend_file_directory: &std::path::Path,
+) -> std::path::PathBuf {
+    // `//` marks paths relative to the .blend file. Files saved on Windows use backslashes.
+    match stored_path.strip_prefix("//") {
+        // C (3.b) match; B (2.a.) .strip_prefix()
+        Some(val) => blend_file_directory.join(val.replace('\\', "/")), // C (3.c) Some(); B (2.a.) .join(); B (2.a.) .replace()
+        None => std::path::PathBuf::from(stored_path), // C (3.c) None =>; B (2.a.) ::from()
+    }
+}
+
+/// ID: BF_019
+/// ABC analīzes rezultāts:17,45,21
+pub fn read_blend_file_dependencies(
+    file_path: &std::path::Path,
+) -> Result<Vec<BlendFileDependency>, String> {
+    let block_codes: Vec<&str> = BLEND_FILE_DEPENDENCY_BLOCKS
+        .iter()
+        .map(|val| val.0)
+        .collect(); // A (1.a.) let block_codes =; B (2.a.) .iter(); B (2.a.) .map(); B (2.a.) .collect()
+    let blend_file = read_blend_file(file_path, &block_codes)?; // A (1.a.) let blend_file =; B (2.a.) read_blend_file()
+    let blend_file_directory = file_path.parent().unwrap_or(std::path::Path::new("")); // A (1.a.) let blend_file_directory =; B (2.a.) .parent(); B (2.a.) .unwrap_or(); B (2.a.) ::new()
+    let mut dependencies = Vec::new(); // A (1.a.) let mut dependencies =; B (2.a.) ...::new()
+    for block in &blend_file.blocks {
+        // A (1.a.) let block =;
+        let Some((_, struct_name, dependency_type)) = BLEND_FILE_DEPENDENCY_BLOCKS
+            .iter()
+            .find(|val| val.0 == block.code)
+        else {
+            // A (1.d.) let Some() else; B (2.a.) .iter(); B (2.a.) .find(); C (3.a.) val.0 == block.code
+            continue; // B (2.b.) continue
+        };
+        // Blender 2.8 and newer name the path field `filepath`, older versions `name`.
+        let Some(stored_path) =
+            read_blend_field_string(&blend_file, &block.data, struct_name, "filepath")
+                .or_else(|| read_blend_field_string(&blend_file, &block.data, struct_name, "name"))
+        else {
+            // A (1.d.) let Some() else; B (2.a.) read_blend_field_string(); B (2.a.) .or_else()
+            continue; // B (2.b.) continue
+        };
+        if stored_path.is_empty() || stored_path == "<builtin>" {
+            // C (3.a.) stored_path.is_empty() == true; C (3.a.) stored_path == "<builtin>"; B (2.a.) .is_empty()
+            continue; // B (2.b.) continue
+        }
+        let source = read_blend_field_integer(&blend_file, &block.data, struct_name, "source"); // A (1.a.) let source =; B (2.a.) read_blend_field_integer()
+        if *struct_name == "Image"
+            && source.is_some_and(|val| BLEND_IMAGE_SOURCES_WITHOUT_FILE.contains(&val))
+        {
+            // C (3.a.) struct_name == "Image"; C (3.a.) .is_some_and() == true; B (2.a.) .is_some_and(); B (2.a.) .contains()
+            continue; // B (2.b.) continue
+        }
+        // Packed files live inside the .blend, Blender 2.8 and newer keep them in a list.
+        let is_packed =
+            read_blend_field_pointer(&blend_file, &block.data, struct_name, "packedfile")
+                .or_else(|| {
+                    read_blend_field_pointer(
+                        &blend_file,
+                        &block.data,
+                        struct_name,
+                        "packedfiles.first",
+                    )
+                })
+                .is_some_and(|val| val != 0); // A (1.a.) let is_packed =; B (2.a.) read_blend_field_pointer(); B (2.a.) .or_else(); B (2.a.) .is_some_and(); C (3.a.) val != 0
+        let resolved_path = resolve_blend_file_path(&stored_path, blend_file_directory); // A (1.a.) let resolved_path =; B (2.a.) resolve_blend_file_path()
+        let name = read_blend_field_string(&blend_file, &block.data, struct_name, "id.name")
+            .map(|val| val.chars().skip(2).collect())
+            .unwrap_or_default(); // A (1.a.) let name =; B (2.a.) read_blend_field_string(); B (2.a.) .map(); B (2.a.) .chars(); B (2.a.) .skip(); B (2.a.) .collect(); B (2.a.) .unwrap_or_default()
+        let mut dependency = BlendFileDependency {
+            // A (1.a.) let mut dependency =
+            dependency_type: dependency_type.to_string(), // B (2.a.) .to_string()
+            name,
+            stored_path,
+            resolved_path: resolved_path.to_string_lossy().to_string(), // B (2.a.) .to_string_lossy(); B (2.a.) .to_string()
+            is_packed,
+            is_missing: !is_packed && !resolved_path.exists(), // C (3.a.) is_packed != true; C (3.a.) resolved_path.exists() != true; B (2.a.) .exists()
+            dependencies: Vec::new(),                          // B (2.a.) ...::new()
+        };
+        if *struct_name == "Image" && !is_packed {
+            // C (3.a.) struct_name == "Image"; C (3.a.) is_packed != true
+            if let Some(image_file_dependencies) =
+                find_blend_image_file_dependencies(&dependency.stored_path, &resolved_path, source)
+            {
+                // A (1.d.) if let Some(); B (2.a.) find_blend_image_file_dependencies()
+                dependency.dependency_type = match source == Some(BLEND_IMAGE_SOURCE_SEQUENCE) {
+                    // A (1.a.) dependency.dependency_type =; C (3.b) match; C (3.a.) source == Some()
+                    true => String::from("ImageSequence"), // C (3.c) true =>; B (2.a.) ::from()
+                    false => String::from("ImageTiles"),   // C (3.c) false =>; B (2.a.) ::from()
+                };
+                dependency.is_missing = image_file_dependencies.is_empty(); // A (1.a.) dependency.is_missing =; B (2.a.) .is_empty()
+                dependency.dependencies = image_file_dependencies; // A (1.a.) dependency.dependencies =
+            }
+        }
+        dependencies.push(dependency); // A (1.c.) .push()
+    }
+    Ok(dependencies)
+}
+
+/// ID: BF_020
+/// ABC analīzes rezultāts:5,13,7
+pub fn read_blend_file_dependency_tree(
+    file_path: &std::path::Path,
+    visited_file_paths: &mut Vec<std::path::PathBuf>,
+) -> Result<Vec<BlendFileDependency>, String> {
+    visited_file_paths.push(
+        std::fs::canonicalize(file_path).unwrap_or(file_path.to_path_buf()), // B (2.a.) ::canonicalize(); B (2.a.) .unwrap_or(); B (2.a.) .to_path_buf()
+    ); // A (1.c.) .push()
+    let mut dependencies = read_blend_file_dependencies(file_path)?; // A (1.a.) let mut dependencies =; B (2.a.) read_blend_file_dependencies()
+    for dependency in dependencies.iter_mut() {
+        // A (1.a.) let dependency =; B (2.a.) .iter_mut()
+        if dependency.dependency_type != "Library" || dependency.is_missing {
+            // C (3.a.) dependency.dependency_type != "Library"; C (3.a.) dependency.is_missing == true
+            continue; // B (2.b.) continue
+        }
+        let library_file_path = std::path::PathBuf::from(&dependency.resolved_path); // A (1.a.) let library_file_path =; B (2.a.) ::from()
+
+        // Libraries that link each other are expanded only once.
+        if visited_file_paths.contains(
+            &std::fs::canonicalize(&library_file_path).unwrap_or(library_file_path.clone()), // B (2.a.) ::canonicalize(); B (2.a.) .unwrap_or(); B (2.a.) .clone()
+        ) {
+            // C (3.a.) visited_file_paths.contains() == true; B (2.a.) .contains()
+            continue; // B (2.b.) continue
+        }
+        // An unreadable library is still listed, only without dependencies of its own.
+        dependency.dependencies =
+            read_blend_file_dependency_tree(&library_file_path, visited_file_paths)
+                .unwrap_or_default(); // A (1.a.) dependency.dependencies =; B (2.a.) read_blend_file_dependency_tree(); B (2.a.) .unwrap_or_default()
+    }
+    Ok(dependencies)
+}
+
+/// ID: BF_021
+/// ABC analīzes rezultāts:2,2,0
+pub fn read_blend_file_version(file_path: &std::path::Path) -> Result<(u32, u32), String> {
+    let mut reader = open_blend_file_stream(file_path)?; // A (1.a.) let mut reader =; B (2.a.) open_blend_file_stream()
+    let header = read_blend_file_header(&mut *reader)?; // A (1.a.) let header =; B (2.a.) read_blend_file_header()
+    Ok((header.version / 100, header.version % 100))
+}
+
+/// ID: BF_022
+/// ABC analīzes rezultāts:6,16,6
+pub fn get_blend_image_file_pattern(
+    file_name: &str,
+    source: Option<i64>,
+) -> Option<(String, String)> {
+    for token in BLEND_IMAGE_TILE_TOKENS {
+        // A (1.a.) let token =;
+        if let Some(index) = file_name.find(token) {
+            // A (1.d.) if let Some(); B (2.a.) .find()
+            return Some((
+                file_name[..index].to_string(),
+                file_name[index + token.len()..].to_string(),
+            )); // B (2.b.) priekšlaicīgs return; B (2.a.) .to_string(); B (2.a.) .len(); B (2.a.) .to_string()
+        }
+    }
+    if source != Some(BLEND_IMAGE_SOURCE_SEQUENCE) && source != Some(BLEND_IMAGE_SOURCE_TILED) {
+        // C (3.a.) source != Some(); C (3.a.) source != Some()
+        return None; // B (2.b.) priekšlaicīgs return
+    }
+
+    // Like Blender, the frame or tile number is the last run of digits or `#` before the extension.
+    let stem_end = file_name.rfind('.').unwrap_or(file_name.len()); // A (1.a.) let stem_end =; B (2.a.) .rfind(); B (2.a.) .unwrap_or(); B (2.a.) .len()
+    let is_number_char = |val: char| val == '#' || val.is_ascii_digit(); // A (1.a.) let is_number_char =; C (3.a.) val == '#'; B (2.a.) .is_ascii_digit()
+    let number_end = file_name[..stem_end].rfind(is_number_char)? + 1; // A (1.a.) let number_end =; B (2.a.) .rfind()
+    let number_start = file_name[..number_end]
+        .rfind(|val: char| !is_number_char(val))
+        .map(|val| val + 1)
+        .unwrap_or(0); // A (1.a.) let number_start =; B (2.a.) .rfind(); B (2.a.) is_number_char(); B (2.a.) .map(); B (2.a.) .unwrap_or()
+    Some((
+        file_name[..number_start].to_string(),
+        file_name[number_end..].to_string(),
+    )) // B (2.a.) .to_string(); B (2.a.) .to_string()
+}
+
+/// ID: BF_023
+/// ABC analīzes rezultāts:12,38,10
+pub fn find_blend_image_file_dependencies(
+    stored_path: &str,
+    resolved_path: &std::path::Path,
+    source: Option<i64>,
+) -> Option<Vec<BlendFileDependency>> {
+    let file_name = resolved_path.file_name()?.to_string_lossy().to_string(); // A (1.a.) let file_name =; B (2.a.) .file_name(); B (2.a.) .to_string_lossy(); B (2.a.) .to_string()
+    let (prefix, suffix) = get_blend_image_file_pattern(&file_name, source)?; // A (1.a.) let (prefix, suffix) =; B (2.a.) get_blend_image_file_pattern()
+    let is_number = |val: &str| !val.is_empty() && val.chars().all(|val| val.is_ascii_digit()); // A (1.a.) let is_number =; B (2.a.) .is_empty(); B (2.a.) .chars(); B (2.a.) .all(); B (2.a.) .is_ascii_digit()
+
+    // Each file keeps the stored directory, so `//` paths stay relative to the .blend file.
+    let stored_directory = &stored_path[..stored_path
+        .rfind(['/', '\\'])
+        .map(|val| val + 1)
+        .unwrap_or(0)]; // A (1.a.) let stored_directory =; B (2.a.) .rfind(); B (2.a.) .map(); B (2.a.) .unwrap_or()
+    let Ok(directory_entries) = std::fs::read_dir(resolved_path.parent()?) else {
+        // A (1.d.) let Ok() else; B (2.a.) ::read_dir(); B (2.a.) .parent()
+        return Some(Vec::new()); // B (2.b.) priekšlaicīgs return; B (2.a.) ::new()
+    };
+    let mut dependencies = Vec::new(); // A (1.a.) let mut dependencies =; B (2.a.) ::new()
+    for directory_entry in directory_entries.flatten() {
+        // A (1.a.) let directory_entry =; B (2.a.) .flatten()
+        let entry_name = directory_entry.file_name().to_string_lossy().to_string(); // A (1.a.) let entry_name =; B (2.a.) .file_name(); B (2.a.) .to_string_lossy(); B (2.a.) .to_string()
+        let Some(number) = entry_name
+            .strip_prefix(&prefix)
+            .and_then(|val| val.strip_suffix(&suffix))
+        else {
+            // A (1.d.) let Some() else; B (2.a.) .strip_prefix(); B (2.a.) .and_then(); B (2.a.) .strip_suffix()
+            continue; // B (2.b.) continue
+        };
+
+        // UDIM tiles are numbered 1001 and up, `<UVTILE>` tiles are named u1_v1 and up.
+        let is_tile_number = match number
+            .strip_prefix('u')
+            .and_then(|val| val.split_once("_v"))
+        {
+            // A (1.a.) let is_tile_number =; C (3.b) match; B (2.a.) .strip_prefix(); B (2.a.) .and_then(); B (2.a.) .split_once()
+            Some((u, v)) => is_number(u) && is_number(v), // C (3.c) Some(); B (2.a.) is_number(); B (2.a.) is_number()
+            None => is_number(number),                    // C (3.c) None =>; B (2.a.) is_number()
+        };
+        if !is_tile_number || !directory_entry.path().is_file() {
+            // C (3.a.) is_tile_number != true; C (3.a.) .is_file() != true; B (2.a.) .path(); B (2.a.) .is_file()
+            continue; // B (2.b.) continue
+        }
+        dependencies.push(BlendFileDependency {
+            // A (1.c.) .push()
+            dependency_type: String::from("Image"), // B (2.a.) ::from()
+            stored_path: format!("{}{}", stored_directory, entry_name),
+            resolved_path: directory_entry.path().to_string_lossy().to_string(), // B (2.a.) .path(); B (2.a.) .to_string_lossy(); B (2.a.) .to_string()
+            name: entry_name,
+            ..Default::default() // B (2.a.) ::default()
+        });
+    }
+    dependencies.sort_by(|a, b| a.name.cmp(&b.name)); // A (1.c.) .sort_by(); B (2.a.) .cmp()
+    Some(dependencies)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TestBlendFileFormat {
+        pointer_size: usize,
+        is_little_endian: bool,
+        is_new_format: bool,
+    }
+
+    fn pack(value: u64, size: usize, is_little_endian: bool) -> Vec<u8> {
+        let bytes = value.to_le_bytes()[..size].to_vec();
+        match is_little_endian {
+            true => bytes,
+            false => bytes.into_iter().rev().collect(),
+        }
+    }
+
+    fn pack_strings(strings: &[&str]) -> Vec<u8> {
+        let mut bytes: Vec<u8> = strings
+            .iter()
+            .flat_map(|val| val.bytes().chain([0]))
+            .collect();
+        bytes.resize((bytes.len() + 3) & !3, 0);
+        bytes
+    }
+
+    fn create_test_sdna(is_little_endian: bool) -> Vec<u8> {
+        let names = [
+            "name[66]",
+            "sfra",
+            "efra",
+            "engine[32]",
+            "xsch",
+            "ysch",
+            "size",
+            "id",
+            "r",
+            "pad[4]",
+            "*curscene",
+            "*next",
+        ];
+        let types = [
+            "char",
+            "short",
+            "int",
+            "ID",
+            "RenderData",
+            "Scene",
+            "FileGlobal",
+            "void",
+        ];
+        let type_lengths = [1, 2, 4, 72, 52, 0, 0, 0];
+        let structs: [(u64, &[(u64, u64)]); 4] = [
+            (3, &[(0, 0)]),
+            (4, &[(2, 1), (2, 2), (0, 3), (2, 4), (2, 5), (1, 6)]),
+            (5, &[(7, 11), (3, 7), (4, 8)]),
+            (6, &[(0, 9), (5, 10)]),
+        ];
+        let mut sdna = b"SDNANAME".to_vec();
+        sdna.extend(pack(names.len() as u64, 4, is_little_endian));
+        sdna.extend(pack_strings(&names));
+        sdna.extend(b"TYPE");
+        sdna.extend(pack(types.len() as u64, 4, is_little_endian));
+        sdna.extend(pack_strings(&types));
+        sdna.extend(b"TLEN");
+        for type_length in type_lengths {
+            sdna.extend(pack(type_length, 2, is_little_endian));
+        }
+        sdna.resize((sdna.len() + 3) & !3, 0);
+        sdna.extend(b"STRC");
+        sdna.extend(pack(structs.len() as u64, 4, is_little_endian));
+        for (type_index, fields) in structs {
+            sdna.extend(pack(type_index, 2, is_little_endian));
+            sdna.extend(pack(fields.len() as u64, 2, is_little_endian));
+            for (field_type_index, name_index) in fields {
+                sdna.extend(pack(*field_type_index, 2, is_little_endian));
+                sdna.extend(pack(*name_index, 2, is_little_endian));
+            }
+        }
+        sdna
+    }
+
+    fn create_test_scene(
+        format: &TestBlendFileFormat,
+        name: &str,
+        frames: (u64, u64),
+        engine: &str,
+        resolution: (u64, u64, u64),
+    ) -> Vec<u8> {
+        let is_little_endian = format.is_little_endian;
+        let mut scene = vec![0u8; format.pointer_size];
+        let mut id_name = format!("SC{}", name).into_bytes();
+        id_name.resize(72, 0);
+        scene.extend(id_name);
+        scene.extend(pack(frames.0, 4, is_little_endian));
+        scene.extend(pack(frames.1, 4, is_little_endian));
+        let mut engine = engine.as_bytes().to_vec();
+        engine.resize(32, 0);
+        scene.extend(engine);
+        scene.extend(pack(resolution.0, 4, is_little_endian));
+        scene.extend(pack(resolution.1, 4, is_little_endian));
+        scene.extend(pack(resolution.2, 2, is_little_endian));
+        scene.extend([0, 0]);
+        scene
+    }
+
+    fn create_test_block(
+        format: &TestBlendFileFormat,
+        code: &str,
+        data: &[u8],
+        old_address: u64,
+    ) -> Vec<u8> {
+        let is_little_endian = format.is_little_endian;
+        let mut block = code.as_bytes().to_vec();
+        block.resize(4, 0);
+        if format.is_new_format {
+            block.extend(pack(0, 4, is_little_endian));
+            block.extend(pack(old_address, 8, is_little_endian));
+            block.extend(pack(data.len() as u64, 8, is_little_endian));
+            block.extend(pack(1, 8, is_little_endian));
+        } else {
+            block.extend(pack(data.len() as u64, 4, is_little_endian));
+            block.extend(pack(old_address, format.pointer_size, is_little_endian));
+            block.extend(pack(0, 4, is_little_endian));
+            block.extend(pack(1, 4, is_little_endian));
+        }
+        block.extend(data);
+        block
+    }
+
+    fn create_test_blend_file(format: &TestBlendFileFormat) -> Vec<u8> {
+        let is_little_endian = format.is_little_endian;
+        let mut blend_file = match format.is_new_format {
+            true => b"BLENDER17-01v0500".to_vec(),
+            false => format!(
+                "BLENDER{}{}405",
+                if format.pointer_size == 8 { '-' } else { '_' },
+                if is_little_endian { 'v' } else { 'V' }
+            )
+            .into_bytes(),
+        };
+        let mut global = vec![0u8; 4];
+        global.extend(pack(0x2000, format.pointer_size, is_little_endian));
+        let blocks = [
+            ("REND", vec![b'x'; 40], 0x0100),
+            (
+                "SC",
+                create_test_scene(
+                    format,
+                    "First",
+                    (1, 250),
+                    "BLENDER_EEVEE",
+                    (1920, 1080, 100),
+                ),
+                0x1000,
+            ),
+            (
+                "SC",
+                create_test_scene(format, "Shot_B", (10, 120), "CYCLES", (3840, 2160, 50)),
+                0x2000,
+            ),
+            ("GLOB", global, 0x3000),
+            ("DNA1", create_test_sdna(is_little_endian), 0x4000),
+            ("ENDB", Vec::new(), 0),
+        ];
+        for (code, data, old_address) in blocks {
+            blend_file.extend(create_test_block(format, code, &data, old_address));
+        }
+        blend_file
+    }
+
+    fn write_test_file(bytes: &[u8]) -> std::path::PathBuf {
+        let file_path =
+            std::env::temp_dir().join(format!("blendio-test-{}.blend", uuid::Uuid::new_v4()));
+        std::fs::write(&file_path, bytes).unwrap();
+        file_path
+    }
+
+    fn assert_test_metadata(file_path: &std::path::Path) {
+        let metadata = read_blend_file_metadata(file_path).unwrap();
+        std::fs::remove_file(file_path).unwrap();
+        assert_eq!(metadata.saved_blender_version, "4.5");
+        assert_eq!(metadata.scene_names, vec!["First", "Shot_B"]);
+        assert_eq!(metadata.frame_start, Some(10));
+        assert_eq!(metadata.frame_end, Some(120));
+        assert_eq!(metadata.render_engine.as_deref(), Some("CYCLES"));
+        assert_eq!(metadata.resolution_x, Some(3840));
+        assert_eq!(metadata.resolution_y, Some(2160));
+        assert_eq!(metadata.resolution_percentage, Some(50));
+    }
+
+    const LEGACY_FORMAT: TestBlendFileFormat = TestBlendFileFormat {
+        pointer_size: 8,
+        is_little_endian: true,
+        is_new_format: false,
+    };
+
+    #[test]
+    fn read_blend_file_header_reads_legacy_and_new_headers() {
+        let header = read_blend_file_header(&mut &b"BLENDER-v405"[..]).unwrap();
+        assert_eq!((header.pointer_size, header.is_little_endian), (8, true));
+        assert_eq!((header.version, header.file_format_version), (405, 0));
+        let header = read_blend_file_header(&mut &b"BLENDER_V279"[..]).unwrap();
+        assert_eq!((header.pointer_size, header.is_little_endian), (4, false));
+        assert_eq!((header.version, header.file_format_version), (279, 0));
+        let header = read_blend_file_header(&mut &b"BLENDER17-01v0500"[..]).unwrap();
+        assert_eq!((header.pointer_size, header.is_little_endian), (8, true));
+        assert_eq!((header.version, header.file_format_version), (500, 1));
+        assert!(read_blend_file_header(&mut &b"BLENDER?v405"[..]).is_err());
+        assert!(read_blend_file_header(&mut &b"NOTBLEND-v405"[..]).is_err());
+        assert!(read_blend_file_header(&mut &b"BLENDER17-01"[..]).is_err());
+    }
+
+    #[test]
+    fn read_blend_file_metadata_reads_legacy_files() {
+        assert_test_metadata(&write_test_file(&create_test_blend_file(&LEGACY_FORMAT)));
+        assert_test_metadata(&write_test_file(&create_test_blend_file(
+            &TestBlendFileFormat {
+                pointer_size: 4,
+                is_little_endian: false,
+                is_new_format: false,
+            },
+        )));
+    }
+
+    #[test]
+    fn read_blend_file_metadata_reads_new_format_files() {
+        let mut blend_file = create_test_blend_file(&TestBlendFileFormat {
+            pointer_size: 8,
+            is_little_endian: true,
+            is_new_format: true,
+        });
+        blend_file[13..17].copy_from_slice(b"0405");
+        assert_test_metadata(&write_test_file(&blend_file));
+    }
+
+    #[test]
+    fn read_blend_file_metadata_reads_compressed_files() {
+        let blend_file = create_test_blend_file(&LEGACY_FORMAT);
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(&blend_file).unwrap();
+        assert_test_metadata(&write_test_file(&encoder.finish().unwrap()));
+        let compressed_blend_file = zstd::encode_all(&blend_file[..], 3).unwrap();
+        assert_test_metadata(&write_test_file(&compressed_blend_file));
+    }
+
+    #[test]
+    fn find_blend_struct_field_follows_nested_fields() {
+        let file_path = write_test_file(&create_test_blend_file(&LEGACY_FORMAT));
+        let blend_file = read_blend_file(&file_path, &[]).unwrap();
+        std::fs::remove_file(&file_path).unwrap();
+        let field = find_blend_struct_field(&blend_file, "Scene", "r.engine").unwrap();
+        assert_eq!(
+            (field.offset, field.size, field.is_pointer),
+            (8 + 72 + 8, 32, false)
+        );
+        assert_eq!(field.type_name, "char");
+        let field = find_blend_struct_field(&blend_file, "FileGlobal", "curscene").unwrap();
+        assert_eq!((field.offset, field.size, field.is_pointer), (4, 8, true));
+        assert!(find_blend_struct_field(&blend_file, "Scene", "r.missing").is_none());
+        assert!(find_blend_struct_field(&blend_file, "Missing", "id").is_none());
+    }
+
+    #[test]
+    fn read_blend_file_blocks_rejects_oversized_lengths() {
+        let mut blend_file = b"BLENDER-v405".to_vec();
+        blend_file.extend(create_test_block(&LEGACY_FORMAT, "DNA1", &[0; 16], 1));
+        blend_file[12 + 4..12 + 8].copy_from_slice(&u32::MAX.to_le_bytes());
+        let header = read_blend_file_header(&mut &blend_file[..12]).unwrap();
+        let result = read_blend_file_blocks(&mut &blend_file[12..], &header, &[]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn parse_blend_file_sdna_rejects_oversized_counts() {
+        let mut sdna = create_test_sdna(true);
+        sdna[8..12].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(parse_blend_file_sdna(&sdna, true).is_err());
+        assert!(parse_blend_file_sdna(&sdna[..sdna.len() - 2], true).is_err());
+    }
+
+    #[test]
+    fn read_blend_file_thumbnail_rejects_oversized_dimensions() {
+        let mut thumbnail = i32::MAX.to_le_bytes().to_vec();
+        thumbnail.extend(i32::MAX.to_le_bytes());
+        let mut blend_file = b"BLENDER-v405".to_vec();
+        blend_file.extend(create_test_block(&LEGACY_FORMAT, "TEST", &thumbnail, 1));
+        blend_file.extend(create_test_block(&LEGACY_FORMAT, "ENDB", &[], 0));
+        let file_path = write_test_file(&blend_file);
+        let result = read_blend_file_thumbnail(&file_path);
+        std::fs::remove_file(&file_path).unwrap();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn get_blend_image_file_pattern_finds_frame_and_tile_numbers() {
+        let pattern = |prefix: &str, suffix: &str| Some((prefix.to_string(), suffix.to_string()));
+        assert_eq!(
+            get_blend_image_file_pattern("wood.<UDIM>.png", None),
+            pattern("wood.", ".png")
+        );
+        assert_eq!(
+            get_blend_image_file_pattern("wood_<UVTILE>.exr", Some(BLEND_IMAGE_SOURCE_TILED)),
+            pattern("wood_", ".exr")
+        );
+        assert_eq!(
+            get_blend_image_file_pattern("wood.1001.png", Some(BLEND_IMAGE_SOURCE_TILED)),
+            pattern("wood.", ".png")
+        );
+        assert_eq!(
+            get_blend_image_file_pattern("shot2_0001.jp2", Some(BLEND_IMAGE_SOURCE_SEQUENCE)),
+            pattern("shot2_", ".jp2")
+        );
+        assert_eq!(
+            get_blend_image_file_pattern("render_####.png", Some(BLEND_IMAGE_SOURCE_SEQUENCE)),
+            pattern("render_", ".png")
+        );
+        assert_eq!(get_blend_image_file_pattern("shot_0001.png", Some(1)), None);
+        assert_eq!(
+            get_blend_image_file_pattern("render.png", Some(BLEND_IMAGE_SOURCE_SEQUENCE)),
+            None
+        );
+    }
+
+    #[test]
+    fn find_blend_image_file_dependencies_lists_every_frame_and_tile() {
+        let directory_path =
+            std::env::temp_dir().join(format!("blendio-test-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&directory_path).unwrap();
+        for val in [
+            "render_0001.png",
+            "render_0002.png",
+            "render_final.png",
+            "wood.1001.png",
+            "wood.1002.png",
+            "wood_u1_v1.png",
+            "wood_u2_v1.png",
+        ] {
+            std::fs::write(directory_path.join(val), b"payload").unwrap();
+        }
+        let get_stored_paths = |stored_path: &str, file_name: &str, source: i64| {
+            find_blend_image_file_dependencies(
+                stored_path,
+                &directory_path.join(file_name),
+                Some(source),
+            )
+            .map(|val| {
+                val.into_iter()
+                    .map(|val| val.stored_path)
+                    .collect::<Vec<String>>()
+            })
+        };
+
+        assert_eq!(
+            get_stored_paths(
+                "//frames\\render_0001.png",
+                "render_0001.png",
+                BLEND_IMAGE_SOURCE_SEQUENCE
+            ),
+            Some(vec![
+                String::from("//frames\\render_0001.png"),
+                String::from("//frames\\render_0002.png"),
+            ])
+        );
+        assert_eq!(
+            get_stored_paths(
+                "//wood.<UDIM>.png",
+                "wood.<UDIM>.png",
+                BLEND_IMAGE_SOURCE_TILED
+            ),
+            Some(vec![
+                String::from("//wood.1001.png"),
+                String::from("//wood.1002.png"),
+            ])
+        );
+        assert_eq!(
+            get_stored_paths(
+                "/textures/wood_<UVTILE>.png",
+                "wood_<UVTILE>.png",
+                BLEND_IMAGE_SOURCE_TILED
+            ),
+            Some(vec![
+                String::from("/textures/wood_u1_v1.png"),
+                String::from("/textures/wood_u2_v1.png"),
+            ])
+        );
+        assert_eq!(
+            get_stored_paths("//stone.<UDIM>.png", "stone.<UDIM>.png", 1),
+            Some(Vec::new())
+        );
+        assert_eq!(
+            get_stored_paths("//wood.1001.png", "wood.1001.png", 1),
+            None
+        );
+        std::fs::remove_dir_all(&directory_path).unwrap();
+    }
+}
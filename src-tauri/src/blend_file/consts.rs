@@ -0,0 +1,27 @@
+/// First bytes of every uncompressed .blend file.
+pub const BLEND_FILE_MAGIC: &[u8] = b"BLENDER";
+/// Frame magic of zstd compressed .blend files, written by Blender 3.0 and newer.
+pub const ZSTD_MAGIC: &[u8] = &[0x28, 0xB5, 0x2F, 0xFD];
+/// Magic of gzip compressed .blend files, written by Blender 2.9x and older.
+pub const GZIP_MAGIC: &[u8] = &[0x1F, 0x8B];
+/// Signature every PNG file starts with.
+pub const PNG_SIGNATURE: &[u8] = &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+/// Blocks that reference files outside the .blend: block code, SDNA struct name and the dependency type shown to the user.
+pub const BLEND_FILE_DEPENDENCY_BLOCKS: &[(&str, &str, &str)] = &[
+    ("LI", "Library", "Library"),
+    ("IM", "Image", "Image"),
+    ("MC", "MovieClip", "MovieClip"),
+    ("SO", "Sound", "Sound"),
+    ("VF", "VFont", "VFont"),
+    ("CF", "CacheFile", "CacheFile"),
+];
+/// `Image.source` values of images that are created in Blender and have no file, generated and viewer.
+pub const BLEND_IMAGE_SOURCES_WITHOUT_FILE: &[i64] = &[4, 5];
+/// `Image.source` of image sequences, every frame is a file of its own.
+pub const BLEND_IMAGE_SOURCE_SEQUENCE: i64 = 2;
+/// `Image.source` of UDIM images, every tile is a file of its own.
+pub const BLEND_IMAGE_SOURCE_TILED: i64 = 6;
+/// Tokens Blender replaces with the tile number in paths of UDIM images.
+pub const BLEND_IMAGE_TILE_TOKENS: &[&str] = &["<UDIM>", "<UVTILE>"];
+/// Dependency types that stand for several files, the files are listed as their dependencies.
+pub const BLEND_FILE_DEPENDENCY_FILE_LIST_TYPES: &[&str] = &["ImageSequence", "ImageTiles"];
@@ -4,101 +4,103 @@ use crate::{
         PythonScriptRepository,
     },
     file_system_utility::{self, show_ok_notification},
-    models::{BlenderRepoPath, DownloadableBlenderVersion, InstalledBlenderVersion},
-    AppState,
+    launch_argument,
+    models::{
+        BlenderRepoPath, BlenderVersionInfo, DownloadableBlenderVersion, InstalledBlenderVersion,
+        LaunchEnvironment, PythonScript, PythonScriptSnapshot,
+    },
+    process_supervisor, python_script, AppState,
 };
 use regex::Regex;
-use tauri::AppHandle;
+use tauri::{AppHandle, Emitter};
 
 /// ID: BV_001
-/// ABC analīzes rezultāts:10,44,12
+/// ABC analīzes rezultāts:0,4,2
 #[tauri::command]
 pub async fn insert_installed_blender_version(
     app: AppHandle,
     state: tauri::State<'_, AppState>,
     executable_file_path: std::path::PathBuf,
+    install_source: Option<String>,
 ) -> Result<(), String> {
-    let parent_dir = match executable_file_path.parent() {
-        // A (1.a.) let parent_dir =; C (3.b) match; B (2.a.) .parent()
-        Some(val) => val, // C (3.c) Some()
-        None => {
-            // C (3.c) None =>;
+    match insert_installed_blender_version_entry(&state.pool, executable_file_path, install_source)
+        .await
+    {
+        // C (3.b) match; B (2.a.) insert_installed_blender_version_entry()
+        Ok(_) => Ok(()), // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c) Err()
             show_ok_notification(
                 // B (2.a.) show_ok_notification()
                 app.clone(), // B (2.a.) app.clone();
-                format!("Failed to get file path parent"),
+                err.clone(), // B (2.a.) err.clone()
                 tauri_plugin_dialog::MessageDialogKind::Error,
             );
-            return Err(format!("Failed to get file path parent")); // B (2.b.) priekšlaicīgs return
+            Err(err)
         }
-    };
+    }
+}
+
+/// ID: BV_027
+/// ABC analīzes rezultāts:11,36,12
+pub async fn insert_installed_blender_version_entry(
+    pool: &sqlx::SqlitePool,
+    executable_file_path: std::path::PathBuf,
+    install_source: Option<String>,
+) -> Result<(), String> {
+    let install_source = get_install_source(
+        // A (1.a.) let install_source =; B (2.a.) get_install_source()
+        &executable_file_path,
+        &install_source.unwrap_or(String::from("manual")), // B (2.a.) .unwrap_or(); B (2.a.) ::from()
+    );
+    let parent_dir =
+        match file_system_utility::get_installation_directory_path(&executable_file_path) {
+            // A (1.a.) let parent_dir =; C (3.b) match; B (2.a.) ::get_installation_directory_path()
+            Some(val) => val, // C (3.c) Some()
+            None => {
+                // C (3.c) None =>;
+                return Err(format!("Failed to get file path parent")); // B (2.b.) priekšlaicīgs return
+            }
+        };
     let dir_name = match parent_dir.file_name() {
         // A (1.a.) let dir_name =; C (3.b) match; B (2.a.) .file_name()
         Some(val) => val.to_string_lossy().to_string(), // C (3.c) Some(); B (2.a.) .to_string_loosy(); B (2.a.) .to_string();
         None => {
             // C (3.c) None =>;
-            show_ok_notification(
-                // B (2.a.) show_ok_notification()
-                app.clone(), // B (2.a.) app.clone();
-                format!("Failed to get file name"),
-                tauri_plugin_dialog::MessageDialogKind::Error,
-            );
             return Err(format!("Failed to get file name")); // B (2.b.) priekšlaicīgs return
         }
     };
-    let re = match Regex::new(r"blender-(?P<version>\d+\.\d+(?:\.\d+)?)-(?P<variant>[^\-+]+)") {
-        // A (1.a.) let re =; C (3.b) match; B (2.a.) ...::new()
-        Ok(val) => val, // C (3.c.) Ok()
-        Err(err) => {
-            // C (3.c) Err()
-            show_ok_notification(
-                // B (2.a.) show_ok_notification()
-                app.clone(), // B (2.a.) app.clone();
-                format!("Failed to construct regex: {:?}", err),
-                tauri_plugin_dialog::MessageDialogKind::Error,
-            );
-            return Err(format!("Failed to construct regex: {:?}", err)); // B (2.b.) priekšlaicīgs return
-        }
+
+    // When the executable cannot be probed, fall back to parsing the directory name.
+    let info = match probe_blender_version(&executable_file_path).await {
+        // A (1.a.) let info =; C (3.b) match; B (2.a.) probe_blender_version()
+        Ok(val) => val,                                    // C (3.c.) Ok()
+        Err(_) => parse_blender_directory_name(&dir_name), // C (3.c) Err(); B (2.a.) parse_blender_directory_name()
     };
-    let mut version = String::new(); // A (1.a.) let mut version =; B (2.a.) ...::new()
-    let mut variant_type = String::new(); // A (1.a.) let mut variant_type =; B (2.a.) ...::new()
-    if let Some(caps) = re.captures(&dir_name) {
-        // A (1.d.) if let Some(); B (2.a.) .captures()
-        version = caps // A (1.a.) version = caps
-            .name("version") // B (2.a.) .name()
-            .map(|m| m.as_str().to_string()) // B (2.a.) .map(); B (2.a.) .as_str(); B (2.a.) .to_string()
-            .unwrap_or_default(); // B (2.a.) .unwrap_or_default();
-        variant_type = caps // A (1.a.) variant_type = caps
-            .name("variant") // B (2.a.) .name()
-            .map(|m| m.as_str().to_string()) // B (2.a.) .map(); B (2.a.) .as_str(); B (2.a.) .to_string()
-            .unwrap_or_default(); // B (2.a.) .unwrap_or_default();
-    }
-    // Try to extract version and variant_type using regex
     let entry = InstalledBlenderVersion {
         // A (1.a.) let entry =;
         id: uuid::Uuid::new_v4().to_string(), // B (2.a.) uuid::Uuid::new_v4(); B (2.a.) .to_string();
-        version: version,
-        variant_type: variant_type,
+        version: info.version,
+        variant_type: info.variant_type,
         download_url: None,
         is_default: false,
         installation_directory_path: parent_dir.to_string_lossy().to_string(), // B (2.a.) .to_string_loosy(); B (2.a.) .to_string();
         executable_file_path: executable_file_path.to_string_lossy().to_string(), // B (2.a.) .to_string_loosy(); B (2.a.) .to_string();
+        build_hash: info.build_hash,
+        build_date: info.build_date,
+        build_branch: info.build_branch,
+        install_source: install_source,
+        is_checksum_verified: None,
         created: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
         modified: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
         accessed: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
     };
-    let repository = InstalledBlenderVersionRepository::new(&state.pool); // A (1.a.) let repository =; B (2.a.) ...::new()
+    let repository = InstalledBlenderVersionRepository::new(pool); // A (1.a.) let repository =; B (2.a.) ...::new()
     match repository.insert(&entry).await {
         // C (3.b.) match; B (2.a.) repository.insert();
         Ok(_) => Ok(()), // C (3.c.) Ok()
         Err(err) => {
             // C (3.c) Err()
-            show_ok_notification(
-                // B (2.a.) show_ok_notification()
-                app.clone(), // B (2.a.) app.clone();
-                format!("Failed to insert installed Blender version: {:?}", err),
-                tauri_plugin_dialog::MessageDialogKind::Error,
-            );
             return Err(format!(
                 "Failed to insert installed Blender version: {:?}",
                 err
@@ -108,82 +110,72 @@ pub async fn insert_installed_blender_version(
 }
 
 /// ID: BV_002
-/// ABC analīzes rezultāts:12,44,25
+/// ABC analīzes rezultāts:0,4,2
 #[tauri::command]
 pub async fn insert_and_refresh_installed_blender_versions(
     app: AppHandle,
     state: tauri::State<'_, AppState>,
 ) -> Result<(), String> {
-    let blender_repo_paths_repo = BlenderRepoPathRepository::new(&state.pool); // A (1.a.) let blender_repo_paths_repo =; B (2.a.) ...::new()
-    let blender_repo_paths = match blender_repo_paths_repo.fetch(None, None, None).await {
-        // A (1.a.) let blender_repo_paths =; C (3.b) match; B (2.a.) .fetch()
-        Ok(val) => val, // C (3.c.) Ok()
+    match refresh_installed_blender_version_entries(&state.pool).await {
+        // C (3.b) match; B (2.a.) refresh_installed_blender_version_entries()
+        Ok(_) => Ok(()), // C (3.c.) Ok()
         Err(err) => {
             // C (3.c) Err()
             show_ok_notification(
                 // B (2.a.) show_ok_notification()
                 app.clone(), // B (2.a.) app.clone();
-                format!("Failed to fetch Blender repo paths: {:?}", err),
+                err.clone(), // B (2.a.) err.clone()
                 tauri_plugin_dialog::MessageDialogKind::Error,
             );
+            Err(err)
+        }
+    }
+}
+
+/// ID: BV_028
+/// ABC analīzes rezultāts:13,33,22
+pub async fn refresh_installed_blender_version_entries(
+    pool: &sqlx::SqlitePool,
+) -> Result<(), String> {
+    let blender_repo_paths_repo = BlenderRepoPathRepository::new(pool); // A (1.a.) let blender_repo_paths_repo =; B (2.a.) ...::new()
+    let blender_repo_paths = match blender_repo_paths_repo.fetch(None, None, None).await {
+        // A (1.a.) let blender_repo_paths =; C (3.b) match; B (2.a.) .fetch()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c) Err()
             return Err(format!("Failed to fetch Blender repo paths: {:?}", err));
             // B (2.b.) priekšlaicīgs return
         }
     };
-    let installed_blender_version_repo = InstalledBlenderVersionRepository::new(&state.pool); // A (1.a.) let installed_blender_version_repo =; B (2.a.) ...::new()
+    let installed_blender_version_repo = InstalledBlenderVersionRepository::new(pool); // A (1.a.) let installed_blender_version_repo =; B (2.a.) ...::new()
     for repo_path in blender_repo_paths {
         // A (1.a.) let repo_path =;
-        let directory_entries = match std::fs::read_dir(repo_path.repo_directory_path) {
-            // A (1.a.) let directory_entries =; C (3.b) match; B (2.a.) ::read_dir()
+        let scan_result = file_system_utility::get_glob_set(&repo_path.include_patterns) // A (1.a.) let scan_result =; B (2.a.) ::get_glob_set()
+            .and_then(|include_patterns| {
+                // B (2.a.) .and_then()
+                let exclude_patterns =
+                    file_system_utility::get_glob_set(&repo_path.exclude_patterns)?; // A (1.a.) let exclude_patterns =; B (2.a.) ::get_glob_set()
+                file_system_utility::find_blender_executables_recursively(
+                    // B (2.a.) ::find_blender_executables_recursively()
+                    std::path::Path::new(&repo_path.repo_directory_path), // B (2.a.) ::new()
+                    repo_path.scan_depth,
+                    &include_patterns,
+                    &exclude_patterns,
+                )
+            });
+        let launcher_paths = match scan_result {
+            // A (1.a.) let launcher_paths =; C (3.b) match
             Ok(val) => val, // C (3.c.) Ok()
             Err(err) => {
                 // C (3.c) Err()
-                show_ok_notification(
-                    // B (2.a.) show_ok_notification()
-                    app.clone(), // B (2.a.) app.clone();
-                    format!(
-                        "Failed to insert and refresh installed Blender version: {:?}",
-                        err
-                    ),
-                    tauri_plugin_dialog::MessageDialogKind::Error,
-                );
                 return Err(format!(
                     "Failed to insert and refresh installed Blender version: {:?}",
                     err
                 )); // B (2.b.) priekšlaicīgs return
             }
         };
-        for entry in directory_entries {
-            // A (1.a.) let entry =;
-            let entry = match entry {
-                // A (1.a.) let entry =; C (3.b) match
-                Ok(val) => val, // C (3.c.) Ok()
-                Err(err) => {
-                    // C (3.c) Err()
-                    show_ok_notification(
-                        // B (2.a.) show_ok_notification()
-                        app.clone(), // B (2.a.) app.clone();
-                        format!(
-                            "Failed to insert and refresh installed Blender version: {:?}",
-                            err
-                        ),
-                        tauri_plugin_dialog::MessageDialogKind::Error,
-                    );
-                    return Err(format!(
-                        "Failed to insert and refresh installed Blender version: {:?}",
-                        err
-                    )); // B (2.b.) priekšlaicīgs return
-                }
-            };
-            if !entry.path().is_dir() {
-                // C (3.a.) entry.path().is_dir() != true; B (2.a.) .path(); B (2.a.) .is_dir();
-                continue; // B (2.b.) continue
-            }
-            let launcher_path = entry.path().join("blender-launcher.exe"); // A (1.a.) let launcher_path =; B (2.a.) .path(); B (2.a.) .join()
-            if !launcher_path.exists() {
-                // C (3.a.) launcher_path.exists() != true; B (2.a.) .exists()
-                continue; // B (2.b.) continue
-            }
+        for launcher_path in launcher_paths {
+            // A (1.a.) let launcher_path =;
             let existing_entries = match installed_blender_version_repo // A (1.a.) let existing_entries =; C (3.b) match
                 .fetch(
                     None,
@@ -195,12 +187,6 @@ pub async fn insert_and_refresh_installed_blender_versions(
                 Ok(val) => val, // C (3.c.) Ok()
                 Err(err) => {
                     // C (3.c) Err()
-                    show_ok_notification(
-                        // B (2.a.) show_ok_notification()
-                        app.clone(), // B (2.a.) app.clone();
-                        format!("Failed to fetch installed Blender versions: {:?}", err),
-                        tauri_plugin_dialog::MessageDialogKind::Error,
-                    );
                     return Err(format!(
                         "Failed to fetch installed Blender versions: {:?}",
                         err
@@ -211,18 +197,17 @@ pub async fn insert_and_refresh_installed_blender_versions(
                 // C (3.a.) existing_entries.is_empty() != true; B (2.a.) .exists()
                 continue; // B (2.b.) continue
             }
-            match insert_installed_blender_version(app.clone(), state.clone(), launcher_path).await // C (3.b) match; B (2.a.) insert_installed_blender_version(); B (2.a.) app.clone(); B (2.a.) state.clone();
+            match insert_installed_blender_version_entry(
+                // C (3.b) match; B (2.a.) insert_installed_blender_version_entry(); B (2.a.) ::from()
+                pool,
+                launcher_path,
+                Some(String::from("repository")),
+            )
+            .await
             {
                 Ok(_) => {} // C (3.c.) Ok()
-                Err(err) => { // C (3.c) Err()
-                    show_ok_notification( // B (2.a.) show_ok_notification()
-                        app.clone(), // B (2.a.) app.clone();
-                        format!(
-                            "Failed to insert and refresh installed Blender version: {:?}",
-                            err
-                        ),
-                        tauri_plugin_dialog::MessageDialogKind::Error,
-                    );
+                Err(err) => {
+                    // C (3.c) Err()
                     return Err(format!(
                         "Failed to insert and refresh installed Blender version: {:?}",
                         err
@@ -236,12 +221,6 @@ pub async fn insert_and_refresh_installed_blender_versions(
         Ok(val) => val, // C (3.c.) Ok()
         Err(err) => {
             // C (3.c) Err()
-            show_ok_notification(
-                // B (2.a.) show_ok_notification()
-                app.clone(), // B (2.a.) app.clone();
-                format!("Failed to fetch installed Blender versions: {:?}", err),
-                tauri_plugin_dialog::MessageDialogKind::Error,
-            );
             return Err(format!(
                 "Failed to fetch installed Blender versions: {:?}",
                 err
@@ -258,12 +237,6 @@ pub async fn insert_and_refresh_installed_blender_versions(
                 Ok(_) => {} // C (3.c.) Ok()
                 Err(err) => {
                     // C (3.c) Err()
-                    show_ok_notification(
-                        // B (2.a.) show_ok_notification()
-                        app.clone(), // B (2.a.) app.clone();
-                        format!("Failed to delete Blender version entry: {:?}", err),
-                        tauri_plugin_dialog::MessageDialogKind::Error,
-                    );
                     return Err(format!("Failed to delete Blender version entry: {:?}", err));
                     // B (2.b.) priekšlaicīgs return
                 }
@@ -273,6 +246,101 @@ pub async fn insert_and_refresh_installed_blender_versions(
     Ok(())
 }
 
+/// ID: BV_025
+/// ABC analīzes rezultāts:15,45,26
+pub async fn refresh_installed_blender_versions_for_paths(
+    pool: &sqlx::SqlitePool,
+    changed_paths: &[std::path::PathBuf],
+) -> Result<(), String> {
+    let blender_repo_paths_repo = BlenderRepoPathRepository::new(pool); // A (1.a.) let blender_repo_paths_repo =; B (2.a.) ...::new()
+    let blender_repo_paths = match blender_repo_paths_repo.fetch(None, None, None).await {
+        // A (1.a.) let blender_repo_paths =; C (3.b) match; B (2.a.) .fetch()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => return Err(format!("Failed to fetch Blender repo paths: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    };
+    let installed_blender_version_repo = InstalledBlenderVersionRepository::new(pool); // A (1.a.) let installed_blender_version_repo =; B (2.a.) ...::new()
+    let mut current_entries = match installed_blender_version_repo.fetch(None, None, None).await {
+        // A (1.a.) let mut current_entries =; C (3.b) match; B (2.a.) installed_blender_version_repo.fetch()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c) Err()
+            return Err(format!(
+                "Failed to fetch installed Blender versions: {:?}",
+                err
+            )); // B (2.b.) priekšlaicīgs return
+        }
+    };
+    let mut inserted_executable_file_paths: Vec<String> = Vec::new(); // A (1.a.) let mut inserted_executable_file_paths =; B (2.a.) ...::new()
+    for changed_path in changed_paths {
+        // A (1.a.) let changed_path =;
+
+        // A removed installation, or a removed directory holding several, drops only its own rows.
+        let removed_entries: Vec<InstalledBlenderVersion> = current_entries
+            .iter()
+            .filter(|val| {
+                let executable_file_path = std::path::Path::new(&val.executable_file_path); // A (1.a.) let executable_file_path =; B (2.a.) ::new()
+                executable_file_path.starts_with(changed_path) && !executable_file_path.exists()
+                // C (3.a.) .starts_with() == true; C (3.a.) .exists() != true; B (2.a.) .starts_with(); B (2.a.) .exists()
+            })
+            .cloned()
+            .collect(); // A (1.a.) let removed_entries =; B (2.a.) .iter(); B (2.a.) .filter(); B (2.a.) .cloned(); B (2.a.) .collect()
+        for entry in &removed_entries {
+            // A (1.a.) let entry =;
+            if let Err(err) = installed_blender_version_repo.delete(&entry.id).await {
+                // A (1.d.) if let Err(); B (2.a.) installed_blender_version_repo.delete()
+                return Err(format!("Failed to delete Blender version entry: {:?}", err));
+                // B (2.b.) priekšlaicīgs return
+            }
+        }
+        current_entries.retain(|val| removed_entries.iter().all(|entry| entry.id != val.id)); // A (1.c.) .retain(); B (2.a.) .iter(); B (2.a.) .all(); C (3.a.) entry.id != val.id
+        if !changed_path.exists() {
+            // C (3.a.) changed_path.exists() != true; B (2.a.) .exists()
+            continue; // B (2.b.) continue
+        }
+        let Some(repo_path) = blender_repo_paths.iter().find(|val| {
+            let repo_directory_path = std::path::Path::new(&val.repo_directory_path); // A (1.a.) let repo_directory_path =; B (2.a.) ::new()
+            changed_path.starts_with(repo_directory_path) && changed_path != repo_directory_path
+            // C (3.a.) .starts_with() == true; C (3.a.) changed_path != repo_directory_path; B (2.a.) .starts_with()
+        }) else {
+            // A (1.d.) let Some() else; B (2.a.) .iter(); B (2.a.) .find()
+            // A change that belongs to no installation directory needs the whole scan.
+            return refresh_installed_blender_version_entries(pool).await;
+            // B (2.b.) priekšlaicīgs return; B (2.a.) refresh_installed_blender_version_entries()
+        };
+        let include_patterns = file_system_utility::get_glob_set(&repo_path.include_patterns)?; // A (1.a.) let include_patterns =; B (2.a.) ::get_glob_set()
+        let exclude_patterns = file_system_utility::get_glob_set(&repo_path.exclude_patterns)?; // A (1.a.) let exclude_patterns =; B (2.a.) ::get_glob_set()
+        let Some(executable_file_path) =
+            file_system_utility::find_blender_executable_for_changed_path(
+                std::path::Path::new(&repo_path.repo_directory_path),
+                changed_path,
+                repo_path.scan_depth,
+                &include_patterns,
+                &exclude_patterns,
+            )
+        else {
+            // A (1.d.) let Some() else; B (2.a.) ::find_blender_executable_for_changed_path(); B (2.a.) ::new()
+            continue; // B (2.b.) continue
+        };
+        let executable_file_path_string = executable_file_path.to_string_lossy().to_string(); // A (1.a.) let executable_file_path_string =; B (2.a.) .to_string_lossy(); B (2.a.) .to_string()
+        if inserted_executable_file_paths.contains(&executable_file_path_string)
+            || current_entries
+                .iter()
+                .any(|val| val.executable_file_path == executable_file_path_string)
+        {
+            // C (3.a.) .contains() == true; C (3.a.) .any() == true; B (2.a.) .contains(); B (2.a.) .iter(); B (2.a.) .any(); C (3.a.) val.executable_file_path == executable_file_path_string
+            continue; // B (2.b.) continue
+        }
+        insert_installed_blender_version_entry(
+            pool,
+            executable_file_path,
+            Some(String::from("repository")),
+        )
+        .await?; // B (2.a.) insert_installed_blender_version_entry(); B (2.a.) ::from()
+        inserted_executable_file_paths.push(executable_file_path_string); // A (1.c.) .push()
+    }
+    Ok(())
+}
+
 /// ID: BV_003
 /// ABC analīzes rezultāts:8,22,17
 #[tauri::command]
@@ -418,7 +486,7 @@ pub async fn fetch_installed_blender_versions(
 }
 
 /// ID: BV_005
-/// ABC analīzes rezultāts:4,26,14
+/// ABC analīzes rezultāts:4,31,18
 #[tauri::command]
 pub async fn uninstall_and_delete_installed_blender_version_data(
     app: AppHandle,
@@ -462,6 +530,37 @@ pub async fn uninstall_and_delete_installed_blender_version_data(
         return Err(format!("Failed to fetch installed Blender version by ID")); // B (2.b.) priekšlaicīgs return
     }
     let entry = installed_blender_version_list.remove(0); // A (1.a.) let entry =; B (2.a.) .remove()
+    if !is_install_source_uninstallable(&entry.install_source) {
+        // C (3.a.) is_install_source_uninstallable() != true; B (2.a.) is_install_source_uninstallable()
+        // Package managed installs are only forgotten, their files belong to the package manager.
+        match repository.delete(&entry.id).await {
+            // C (3.b) match; B (2.a.) .delete()
+            Ok(_) => {} // C (3.c.) Ok()
+            Err(err) => {
+                // C (3.c) Err()
+                show_ok_notification(
+                    // B (2.a.) show_ok_notification()
+                    app.clone(), // B (2.a.) app.clone();
+                    format!("Failed to delete installed Blender version: {:?}", err),
+                    tauri_plugin_dialog::MessageDialogKind::Error,
+                );
+                return Err(format!(
+                    "Failed to delete installed Blender version: {:?}",
+                    err
+                )); // B (2.b.) priekšlaicīgs return
+            }
+        }
+        show_ok_notification(
+            // B (2.a.) show_ok_notification()
+            app.clone(), // B (2.a.) app.clone();
+            format!(
+                "This Blender version is managed by {} and was only removed from the list, uninstall it with {} instead",
+                entry.install_source, entry.install_source
+            ),
+            tauri_plugin_dialog::MessageDialogKind::Info,
+        );
+        return Ok(()); // B (2.b.) priekšlaicīgs return
+    }
     match file_system_utility::delete_directory(std::path::PathBuf::from(
         entry.installation_directory_path,
     )) // C (3.b) match; B (2.a.) ::delete_directory(); B (2.a.) ::from()
@@ -512,14 +611,16 @@ pub async fn uninstall_and_delete_installed_blender_version_data(
 }
 
 /// ID: BV_006
-/// ABC analīzes rezultāts:15,56,33
+/// ABC analīzes rezultāts:24,69,37
 #[tauri::command]
 pub async fn launch_blender_version_with_launch_args(
     app: AppHandle,
     state: tauri::State<'_, AppState>,
     id: String,
     launch_arguments_id: Option<String>,
-    python_script_id: Option<String>,
+    python_script_ids: Vec<String>,
+    python_expressions: Vec<String>,
+    script_arguments: Vec<String>,
 ) -> Result<(), String> {
     let installed_blender_version_repository = InstalledBlenderVersionRepository::new(&state.pool); // A (1.a.) let installed_blender_version_repository =; B (2.a.) ...::new()
     let launch_argument_repository = LaunchArgumentRepository::new(&state.pool); // A (1.a.) let launch_argument_repository =; B (2.a.) ...::new()
@@ -572,8 +673,9 @@ pub async fn launch_blender_version_with_launch_args(
         }
     }
     let mut final_launch_args: Vec<String> = vec![]; // A (1.a.) let mut final_launch_args =;
-    match launch_arguments_id {
-        // C (3.b) match
+    let mut launch_environment = LaunchEnvironment::default(); // A (1.a.) let mut launch_environment =; B (2.a.) ::default()
+    match launch_arguments_id.clone() {
+        // C (3.b) match; B (2.a.) .clone()
         Some(arg_id) => {
             // C (3.c) Some()
             let mut launch_argument_entry_list = match launch_argument_repository // A (1.a.) let mut launch_argument_entry_list =; C (3.b) match
@@ -619,79 +721,177 @@ pub async fn launch_blender_version_with_launch_args(
                     // B (2.b.) priekšlaicīgs return
                 }
             }
-            let parsed_args: Vec<String> = entry // A (1.a.) let parsed_args =;
-                .argument_string
-                .split_whitespace() // B (2.a.) .split_whitespace()
-                .map(|s| s.to_string()) // B (2.a.) .map(_; B (2.a.) .to_string()
-                .collect(); // B (2.a.) .collect()
-            final_launch_args.extend(parsed_args); // A (1.c.) .extend()
-        }
-        None => {} // C (3.c) None =>;
-    }
-    match python_script_id {
-        // C (3.b) match
-        Some(script_id) => {
-            // C (3.c) Some()
-            let mut python_script_entry_list = match python_script_repository // A (1.a.) let mut python_script_entry_list =; C (3.b) match
-                .fetch(Some(&script_id), None, None) // B (2.a.) .fetch()
-                .await
-            {
+            let parsed_args = match launch_argument::parse_argument_string(&entry.argument_string) {
+                // A (1.a.) let parsed_args =; C (3.b) match; B (2.a.) parse_argument_string()
                 Ok(val) => val, // C (3.c.) Ok()
                 Err(err) => {
                     // C (3.c) Err()
                     show_ok_notification(
                         // B (2.a.) show_ok_notification()
                         app.clone(), // B (2.a.) app.clone();
-                        format!("Failed to fetch python scripts: {:?}", err),
+                        format!("Failed to parse launch argument: {}", err),
                         tauri_plugin_dialog::MessageDialogKind::Error,
                     );
-                    return Err(format!("Failed to fetch python scripts: {:?}", err));
+                    return Err(format!("Failed to parse launch argument: {}", err));
                     // B (2.b.) priekšlaicīgs return
                 }
             };
-            if python_script_entry_list.is_empty() {
-                // B (2.a.) .is_empty(); C (3.a) python_script_entry_list.is_empty() == true
-                show_ok_notification(
-                    // B (2.a.) show_ok_notification()
-                    app.clone(), // B (2.a.) app.clone();
-                    format!("Failed to fetch python script by ID"),
-                    tauri_plugin_dialog::MessageDialogKind::Error,
-                );
-                return Err(format!("Failed to fetch python script by ID")); // B (2.b.) priekšlaicīgs return
-            }
-            let entry = python_script_entry_list.remove(0); // A (1.a.) let entry =; B (2.a.) .remove()
-            match python_script_repository.update(&entry).await {
-                // C (3.b) match; B (2.a.) .update()
-                Ok(_) => {} // C (3.c.) Ok()
+            launch_environment = match launch_argument::get_launch_environment(&entry, None) {
+                // A (1.a.) launch_environment =; C (3.b) match; B (2.a.) ::get_launch_environment()
+                Ok(val) => val, // C (3.c.) Ok()
                 Err(err) => {
                     // C (3.c) Err()
                     show_ok_notification(
                         // B (2.a.) show_ok_notification()
                         app.clone(), // B (2.a.) app.clone();
-                        format!("Failed to update python script: {:?}", err),
+                        format!("Failed to resolve launch environment: {}", err),
                         tauri_plugin_dialog::MessageDialogKind::Error,
                     );
-                    return Err(format!("Failed to update python script: {:?}", err));
+                    return Err(format!("Failed to resolve launch environment: {}", err));
                     // B (2.b.) priekšlaicīgs return
                 }
+            };
+            final_launch_args.extend(parsed_args); // A (1.c.) .extend()
+        }
+        None => {} // C (3.c) None =>;
+    }
+    let mut python_script_entries: Vec<PythonScript> = vec![]; // A (1.a.) let mut python_script_entries =
+    let mut python_script_safety_summaries: Vec<String> = vec![]; // A (1.a.) let mut python_script_safety_summaries =
+    for script_id in &python_script_ids {
+        // A (1.a.) let script_id =;
+        let mut python_script_entry_list = match python_script_repository // A (1.a.) let mut python_script_entry_list =; C (3.b) match
+            .fetch(Some(script_id), None, None) // B (2.a.) .fetch()
+            .await
+        {
+            Ok(val) => val, // C (3.c.) Ok()
+            Err(err) => {
+                // C (3.c) Err()
+                show_ok_notification(
+                    // B (2.a.) show_ok_notification()
+                    app.clone(), // B (2.a.) app.clone();
+                    format!("Failed to fetch python scripts: {:?}", err),
+                    tauri_plugin_dialog::MessageDialogKind::Error,
+                );
+                return Err(format!("Failed to fetch python scripts: {:?}", err));
+                // B (2.b.) priekšlaicīgs return
             }
-            if !final_launch_args.contains(&"--python".to_string()) {
-                // C (3.a.) final_launch_args.contains(&"--python".to_string()) != true; B (2.a.) .contains(); B (2.a.) to_string()
-                final_launch_args.push("--python".to_string()); // A (1.c.) .push(); B (2.a.) .to_string()
-                final_launch_args.push(entry.script_file_path); // A (1.c.) .push()
-            } else if final_launch_args.contains(&"--python".to_string()) {
-                // C (3.b.) else; C (3.a.) final_launch_args.contains(&"--python".to_string()) == true; B (2.a.) .contains(); B (2.a.) .to_string()
-                final_launch_args.push(entry.script_file_path); // A (1.c.) .push()
+        };
+        if python_script_entry_list.is_empty() {
+            // B (2.a.) .is_empty(); C (3.a) python_script_entry_list.is_empty() == true
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to fetch python script by ID"),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            return Err(format!("Failed to fetch python script by ID")); // B (2.b.) priekšlaicīgs return
+        }
+        let mut entry = python_script_entry_list.remove(0); // A (1.a.) let mut entry =; B (2.a.) .remove()
+
+        match python_script::refresh_python_script_metadata(&mut entry) {
+            // C (3.b) match; B (2.a.) ::refresh_python_script_metadata()
+            Ok(_) => {} // C (3.c.) Ok()
+            Err(err) => {
+                // C (3.c) Err()
+                show_ok_notification(
+                    // B (2.a.) show_ok_notification()
+                    app.clone(), // B (2.a.) app.clone();
+                    format!("Failed to read python script: {}", err),
+                    tauri_plugin_dialog::MessageDialogKind::Error,
+                );
+                return Err(format!("Failed to read python script: {}", err)); // B (2.b.) priekšlaicīgs return
             }
         }
-        None => {} // C (3.c) None =>;
+        match python_script_repository.update(&entry).await {
+            // C (3.b) match; B (2.a.) .update()
+            Ok(_) => {} // C (3.c.) Ok()
+            Err(err) => {
+                // C (3.c) Err()
+                show_ok_notification(
+                    // B (2.a.) show_ok_notification()
+                    app.clone(), // B (2.a.) app.clone();
+                    format!("Failed to update python script: {:?}", err),
+                    tauri_plugin_dialog::MessageDialogKind::Error,
+                );
+                return Err(format!("Failed to update python script: {:?}", err));
+                // B (2.b.) priekšlaicīgs return
+            }
+        }
+        if let Some(val) = python_script::get_python_script_safety_summary(&entry) {
+            // A (1.d.) if let Some(); B (2.a.) ::get_python_script_safety_summary()
+            python_script_safety_summaries.push(val); // A (1.c.) .push()
+        }
+        python_script_entries.push(entry); // A (1.c.) .push()
     }
-    match file_system_utility::launch_executable(
-        // C (3.b) match; B (2.a.) ::launch_executable()
-        std::path::PathBuf::from(instance.executable_file_path), // B (2.a.) ::from()
-        Some(final_launch_args),
-    ) {
-        Ok(_) => Ok(()), // C (3.c.) Ok()
+    if !python_script_safety_summaries.is_empty() {
+        // C (3.a.) python_script_safety_summaries.is_empty() != true; B (2.a.) .is_empty()
+        let confirmation = file_system_utility::show_ask_notification(
+            // A (1.a.) let confirmation =; B (2.a.) ::show_ask_notification()
+            app.clone(), // B (2.a.) app.clone();
+            format!(
+                "The selected python scripts were flagged by the safety check:\n\n{}\n\nLaunch anyway?",
+                python_script_safety_summaries.join("\n")
+            ), // B (2.a.) .join()
+            tauri_plugin_dialog::MessageDialogKind::Warning,
+        );
+        if confirmation == false {
+            // C (3.a.) confirmation == false
+            return Ok(()); // B (2.b.) priekšlaicīgs return
+        }
+    }
+
+    // Snapshots are only taken for launches that go ahead, they keep the exact script contents
+    // of this launch for the launch history.
+    let mut python_script_paths: Vec<String> = vec![]; // A (1.a.) let mut python_script_paths =
+    let mut python_script_snapshots: Vec<PythonScriptSnapshot> = vec![]; // A (1.a.) let mut python_script_snapshots =
+    for entry in python_script_entries {
+        // A (1.a.) let entry =;
+        let python_script_snapshot = match python_script::create_python_script_snapshot(&entry) {
+            // A (1.a.) let python_script_snapshot =; C (3.b) match; B (2.a.) ::create_python_script_snapshot()
+            Ok(val) => val, // C (3.c.) Ok()
+            Err(err) => {
+                // C (3.c) Err()
+                show_ok_notification(
+                    // B (2.a.) show_ok_notification()
+                    app.clone(), // B (2.a.) app.clone();
+                    format!("Failed to read python script: {}", err),
+                    tauri_plugin_dialog::MessageDialogKind::Error,
+                );
+                return Err(format!("Failed to read python script: {}", err)); // B (2.b.) priekšlaicīgs return
+            }
+        };
+        python_script_snapshots.push(python_script_snapshot); // A (1.c.) .push()
+        python_script_paths.push(entry.script_file_path); // A (1.c.) .push()
+    }
+    let final_launch_args = launch_argument::build_python_launch_arguments(
+        // A (1.a.) let final_launch_args =; B (2.a.) ::build_python_launch_arguments()
+        final_launch_args,
+        python_script_paths,
+        python_expressions,
+        script_arguments,
+    );
+    match process_supervisor::launch_supervised_executable(
+        // C (3.b) match; B (2.a.) ::launch_supervised_executable(); B (2.a.) app.clone()
+        app.clone(),
+        &instance,
+        final_launch_args,
+        launch_arguments_id,
+        python_script_ids.clone(), // B (2.a.) .clone()
+        None,
+        launch_environment,
+    )
+    .await
+    {
+        Ok(instance) => {
+            // C (3.c.) Ok()
+            python_script::insert_python_script_snapshots(
+                // B (2.a.) ::insert_python_script_snapshots(); B (2.a.) app.clone()
+                app.clone(),
+                python_script_snapshots,
+                &instance.id,
+            )
+            .await
+        }
         Err(err) => {
             // C (3.c) Err()
             show_ok_notification(
@@ -785,7 +985,7 @@ pub async fn get_downloadable_blender_version_data(
 }
 
 /// ID: BV_008
-/// ABC analīzes rezultāts:16,47,17
+/// ABC analīzes rezultāts:14,42,19
 #[tauri::command]
 pub async fn download_and_install_blender_version(
     app: AppHandle,
@@ -794,8 +994,8 @@ pub async fn download_and_install_blender_version(
     downloadable_blender_version: DownloadableBlenderVersion,
 ) -> Result<(), String> {
     let repository = InstalledBlenderVersionRepository::new(&state.pool); // A (1.a.) let repository =; B (2.a.) ...::new()
-    let mut entry = InstalledBlenderVersion {
-        // A (1.a.) let mut entry =;
+    let entry = InstalledBlenderVersion {
+        // A (1.a.) let entry =;
         id: uuid::Uuid::new_v4().to_string(), // B (2.a.) uuid::Uuid::new_v4(); B (2.a.) .to_string();
         version: downloadable_blender_version.version,
         variant_type: downloadable_blender_version.release_cycle,
@@ -803,54 +1003,158 @@ pub async fn download_and_install_blender_version(
         is_default: false,
         installation_directory_path: String::new(), // B (2.a.) ...::new()
         executable_file_path: String::new(),        // B (2.a.) ...::new()
+        build_hash: Some(downloadable_blender_version.hash),
+        build_date: None,
+        build_branch: Some(downloadable_blender_version.branch),
+        install_source: String::from("download"), // B (2.a.) ::from()
+        is_checksum_verified: Some(!downloadable_blender_version.checksum.is_empty()), // B (2.a.) .is_empty()
         created: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
         modified: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
         accessed: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
     };
-    let installation_directory_path = match file_system_utility::extract_archive(
-        // A (1.a.) let installation_directory_path =; C (3.b) match; B (2.a.) ::extract_archive()
-        archive_file_path.clone(), // B (2.a.) .clone();
-    )
-    .await
-    {
-        Ok(val) => val, // C (3.c.) Ok()
-        Err(err) => {
-            // C (3.c) Err()
+    if downloadable_blender_version.checksum.is_empty() {
+        // C (3.a.) downloadable_blender_version.checksum.is_empty() == true; B (2.a.) .is_empty()
+        // Without a published checksum nothing proves the archive is intact, the user decides.
+        let confirmation = file_system_utility::show_ask_notification(
+            // A (1.a.) let confirmation =; B (2.a.) show_ask_notification()
+            app.clone(), // B (2.a.) app.clone();
+            format!(
+                "No checksum was published for {}, the downloaded archive can't be verified. Install it anyway?",
+                entry.version
+            ),
+            tauri_plugin_dialog::MessageDialogKind::Warning,
+        );
+        if !confirmation {
+            // C (3.a.) confirmation != true
+            let _ = file_system_utility::delete_file(archive_file_path).await; // A (1.a.) let _ =; B (2.a.) ::delete_file()
+            return Err(format!(
+                "Installation cancelled: downloaded archive file has no checksum"
+            )); // B (2.b.) priekšlaicīgs return
+        }
+    } else {
+        // C (3.b.) else
+        if let Err(err) = file_system_utility::verify_file_sha256_checksum(
+            // C (3.b.) if let Err(); B (2.a.) ::verify_file_sha256_checksum()
+            &archive_file_path,
+            &downloadable_blender_version.checksum,
+        ) {
             show_ok_notification(
                 // B (2.a.) show_ok_notification()
                 app.clone(), // B (2.a.) app.clone();
-                format!(
-                    "Failed to extract downloaded Blender versions files from archive file: {:?}",
-                    err
-                ),
+                format!("Failed to verify downloaded archive file: {:?}", err),
                 tauri_plugin_dialog::MessageDialogKind::Error,
             );
             return Err(format!(
-                "Failed to extract downloaded Blender versions files from archive file: {:?}",
+                "Failed to verify downloaded archive file: {:?}",
                 err
             )); // B (2.b.) priekšlaicīgs return
         }
-    };
-    entry.installation_directory_path = installation_directory_path.to_string_lossy().to_string(); // A (1.a.) entry.installation_directory_path =; B (2.a.) .to_string_loosy(); B (2.a.) .to_string();
-    entry.executable_file_path = installation_directory_path // A (1.a.) entry.executable_file_path =;
-        .join("blender-launcher.exe") // B (2.a.) .join()
-        .to_string_lossy() // B (2.a.) .to_string_lossy()
-        .to_string(); // B (2.a.) .to_string()
-    match file_system_utility::delete_file(archive_file_path).await {
-        // C (3.b) match; B (2.a.) ::delete_file()
-        Ok(_) => {} // C (3.c.) Ok()
-        Err(err) => {
-            // C (3.c) Err()
+    }
+    let (installation_directory_path, executable_file_path, backup_directory_path) = // A (1.a.) let (installation_directory_path, executable_file_path, backup_directory_path) =;
+        match file_system_utility::install_archive(archive_file_path.clone()).await { // C (3.b) match; B (2.a.) ::install_archive(); B (2.a.) .clone();
+            Ok(val) => val, // C (3.c.) Ok()
+            Err(err) => {
+                // C (3.c) Err()
+                show_ok_notification(
+                    // B (2.a.) show_ok_notification()
+                    app.clone(), // B (2.a.) app.clone();
+                    format!(
+                        "Failed to extract downloaded Blender versions files from archive file: {:?}",
+                        err
+                    ),
+                    tauri_plugin_dialog::MessageDialogKind::Error,
+                );
+                return Err(format!(
+                    "Failed to extract downloaded Blender versions files from archive file: {:?}",
+                    err
+                )); // B (2.b.) priekšlaicīgs return
+            }
+        };
+    // The replaced installation stays as a backup until the new one is registered.
+    let result = register_installed_blender_version(
+        // A (1.a.) let result =; B (2.a.) register_installed_blender_version()
+        app.clone(), // B (2.a.) app.clone();
+        &repository,
+        entry,
+        &installation_directory_path,
+        &executable_file_path,
+    )
+    .await;
+    if let Some(backup_directory_path) = backup_directory_path {
+        // A (1.d.) if let Some()
+        if result.is_ok() {
+            // C (3.a.) result.is_ok() == true; B (2.a.) .is_ok()
+            let _ = std::fs::remove_dir_all(&backup_directory_path); // A (1.a.) let _ =; B (2.a.) ::remove_dir_all()
+        } else if let Err(err) = file_system_utility::restore_directory_backup(
+            // C (3.b.) else; A (1.d.) if let Err(); B (2.a.) ::restore_directory_backup()
+            &backup_directory_path,
+            &installation_directory_path,
+        ) {
             show_ok_notification(
                 // B (2.a.) show_ok_notification()
                 app.clone(), // B (2.a.) app.clone();
-                format!("Failed to delete downloaded archive file: {:?}", err),
+                format!(
+                    "Failed to restore previous Blender version, it is kept at {}: {:?}",
+                    backup_directory_path.display(),
+                    err
+                ), // B (2.a.) .display()
                 tauri_plugin_dialog::MessageDialogKind::Error,
             );
-            return Err(format!(
-                "Failed to delete downloaded archive file: {:?}",
-                err
-            )); // B (2.b.) priekšlaicīgs return
+        }
+    }
+
+    // The archive is kept until the installation is registered, a failed install is retried
+    // without downloading again.
+    if result.is_ok() {
+        // C (3.a.) result.is_ok() == true; B (2.a.) .is_ok()
+        if let Err(err) = file_system_utility::delete_file(archive_file_path).await {
+            // A (1.d.) if let Err(); B (2.a.) ::delete_file()
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to delete downloaded archive file: {:?}", err),
+                tauri_plugin_dialog::MessageDialogKind::Warning,
+            );
+        }
+    }
+    result
+}
+
+/// ID: BV_024
+/// ABC analīzes rezultāts:16,22,11
+pub async fn register_installed_blender_version(
+    app: AppHandle,
+    repository: &InstalledBlenderVersionRepository<'_>,
+    mut entry: InstalledBlenderVersion,
+    installation_directory_path: &std::path::Path,
+    executable_file_path: &std::path::Path,
+) -> Result<(), String> {
+    entry.installation_directory_path = installation_directory_path.to_string_lossy().to_string(); // A (1.a.) entry.installation_directory_path =; B (2.a.) .to_string_loosy(); B (2.a.) .to_string();
+    entry.executable_file_path = executable_file_path.to_string_lossy().to_string(); // A (1.a.) entry.executable_file_path =; B (2.a.) .to_string_loosy(); B (2.a.) .to_string();
+    match probe_blender_version(executable_file_path).await {
+        // C (3.b) match; B (2.a.) probe_blender_version()
+        Ok(info) => {
+            // C (3.c.) Ok()
+            entry.build_hash = info.build_hash.or(entry.build_hash); // A (1.a.) entry.build_hash =; B (2.a.) .or()
+            entry.build_date = info.build_date; // A (1.a.) entry.build_date =
+            entry.build_branch = info.build_branch.or(entry.build_branch); // A (1.a.) entry.build_branch =; B (2.a.) .or()
+        }
+        Err(_) => {
+            // C (3.c) Err()
+            // The download already names the build, the directory name only fills in what it lacks.
+            let directory_name = installation_directory_path
+                .file_name()
+                .map(|val| val.to_string_lossy().to_string())
+                .unwrap_or_default(); // A (1.a.) let directory_name =; B (2.a.) .file_name(); B (2.a.) .map(); B (2.a.) .to_string_lossy(); B (2.a.) .to_string(); B (2.a.) .unwrap_or_default()
+            let info = parse_blender_directory_name(&directory_name); // A (1.a.) let info =; B (2.a.) parse_blender_directory_name()
+            if entry.version.is_empty() {
+                // C (3.a.) entry.version.is_empty() == true; B (2.a.) .is_empty()
+                entry.version = info.version; // A (1.a.) entry.version =
+            }
+            if entry.variant_type.is_empty() {
+                // C (3.a.) entry.variant_type.is_empty() == true; B (2.a.) .is_empty()
+                entry.variant_type = info.variant_type; // A (1.a.) entry.variant_type =
+            }
         }
     }
     let mut existing_entries = match repository // A (1.a.) let mut existing_entries =; C (3.b) match
@@ -898,6 +1202,11 @@ pub async fn download_and_install_blender_version(
         old_entry.is_default = entry.is_default; // A (1.a.) old_entry.is_default =
         old_entry.installation_directory_path = entry.installation_directory_path; // A (1.a.) old_entry.installation_directory_path =
         old_entry.executable_file_path = entry.executable_file_path; // A (1.a.) old_entry.executable_file_path =
+        old_entry.build_hash = entry.build_hash; // A (1.a.) old_entry.build_hash =
+        old_entry.build_date = entry.build_date; // A (1.a.) old_entry.build_date =
+        old_entry.build_branch = entry.build_branch; // A (1.a.) old_entry.build_branch =
+        old_entry.install_source = entry.install_source; // A (1.a.) old_entry.install_source =
+        old_entry.is_checksum_verified = entry.is_checksum_verified; // A (1.a.) old_entry.is_checksum_verified =
         old_entry.created = chrono::Utc::now().to_rfc3339(); // A (1.a.) old_entry.created =; B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
         old_entry.modified = chrono::Utc::now().to_rfc3339(); // A (1.a.) old_entry.modified =; B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
         old_entry.accessed = chrono::Utc::now().to_rfc3339(); // A (1.a.) old_entry.accessed =; B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
@@ -921,8 +1230,30 @@ pub async fn download_and_install_blender_version(
     }
 }
 
+/// ID: BV_026
+/// ABC analīzes rezultāts:5,11,3
+pub fn parse_blender_directory_name(directory_name: &str) -> BlenderVersionInfo {
+    let mut info = BlenderVersionInfo::default(); // A (1.a.) let mut info =; B (2.a.) ::default()
+    let Ok(re) = Regex::new(r"blender-(?P<version>\d+\.\d+(?:\.\d+)?)-(?P<variant>[^\-+]+)") else {
+        // A (1.d.) let Ok() else; B (2.a.) ...::new()
+        return info; // B (2.b.) priekšlaicīgs return
+    };
+    if let Some(caps) = re.captures(directory_name) {
+        // A (1.d.) if let Some(); B (2.a.) .captures()
+        info.version = caps // A (1.a.) info.version = caps
+            .name("version") // B (2.a.) .name()
+            .map(|m| m.as_str().to_string()) // B (2.a.) .map(); B (2.a.) .as_str(); B (2.a.) .to_string()
+            .unwrap_or_default(); // B (2.a.) .unwrap_or_default();
+        info.variant_type = caps // A (1.a.) info.variant_type = caps
+            .name("variant") // B (2.a.) .name()
+            .map(|m| m.as_str().to_string()) // B (2.a.) .map(); B (2.a.) .as_str(); B (2.a.) .to_string()
+            .unwrap_or_default(); // B (2.a.) .unwrap_or_default();
+    }
+    info
+}
+
 /// ID: BV_009
-/// ABC analīzes rezultāts:5,26,13
+/// ABC analīzes rezultāts:5,28,13
 #[tauri::command]
 pub async fn insert_blender_version_installation_location(
     app: AppHandle,
@@ -967,6 +1298,9 @@ pub async fn insert_blender_version_installation_location(
         id: uuid::Uuid::new_v4().to_string(), // B (2.a.) uuid::Uuid::new_v4(); B (2.a.) .to_string();
         repo_directory_path: repo_directory_path.to_string_lossy().to_string(), // B (2.a.) .to_string_loosy(); B (2.a.) .to_string();
         is_default: false,
+        scan_depth: 1,
+        include_patterns: String::new(), // B (2.a.) ...::new()
+        exclude_patterns: String::new(), // B (2.a.) ...::new()
         created: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
         modified: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
         accessed: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
@@ -1233,3 +1567,532 @@ pub async fn delete_blender_version_installation_location(
         }
     }
 }
+
+/// ID: BV_013
+/// ABC analīzes rezultāts:8,32,15
+#[tauri::command]
+pub async fn download_blender_version(
+    app: AppHandle,
+    state: tauri::State<'_, AppState>,
+    download_id: String,
+    repo_directory_path: std::path::PathBuf,
+    downloadable_blender_version: DownloadableBlenderVersion,
+) -> Result<std::path::PathBuf, String> {
+    // The file name comes from the builder API response and must not leave the repo directory.
+    if !file_system_utility::is_plain_file_name(&downloadable_blender_version.file_name) {
+        // C (3.a.) is_plain_file_name() != true; B (2.a.) ::is_plain_file_name()
+        return Err(format!(
+            "Failed to download Blender version: invalid file name {:?}",
+            downloadable_blender_version.file_name
+        )); // B (2.b.) priekšlaicīgs return
+    }
+    let cancel_flag = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false)); // A (1.a.) let cancel_flag =; B (2.a.) ...::new(); B (2.a.) ...::new()
+    match state.active_downloads.lock() {
+        // C (3.b) match; B (2.a.) .lock()
+        Ok(mut active_downloads) => {
+            // C (3.c.) Ok()
+            if active_downloads.contains_key(&download_id) {
+                // C (3.a.) active_downloads.contains_key() == true; B (2.a.) .contains_key()
+                return Err(format!(
+                    "Failed to download Blender version: download is already in progress"
+                )); // B (2.b.) priekšlaicīgs return
+            }
+            active_downloads.insert(download_id.clone(), cancel_flag.clone()); // A (1.c.) .insert(); B (2.a.) download_id.clone(); B (2.a.) cancel_flag.clone()
+        }
+        Err(err) => return Err(format!("Failed to download Blender version: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    }
+    let archive_file_path = repo_directory_path.join(&downloadable_blender_version.file_name); // A (1.a.) let archive_file_path =; B (2.a.) .join()
+    let result = file_system_utility::download_file(
+        // A (1.a.) let result =; B (2.a.) ::download_file()
+        &downloadable_blender_version.url,
+        &archive_file_path,
+        &download_id,
+        cancel_flag.clone(), // B (2.a.) cancel_flag.clone()
+        |progress| {
+            let _ = app.emit("blender-download-progress", progress); // A (1.a.) let _ =; B (2.a.) app.emit()
+        },
+    )
+    .await;
+    if let Ok(mut active_downloads) = state.active_downloads.lock() {
+        // A (1.d.) if let Ok(); B (2.a.) .lock()
+        active_downloads.remove(&download_id); // A (1.c.) .remove()
+    }
+    match result {
+        // C (3.b) match
+        Ok(val) => Ok(val), // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c) Err()
+            if !cancel_flag.load(std::sync::atomic::Ordering::SeqCst) {
+                // C (3.a.) cancel_flag.load() != true; B (2.a.) .load()
+                show_ok_notification(
+                    // B (2.a.) show_ok_notification()
+                    app.clone(), // B (2.a.) app.clone();
+                    format!("Failed to download Blender version: {:?}", err),
+                    tauri_plugin_dialog::MessageDialogKind::Error,
+                );
+            }
+            return Err(format!("Failed to download Blender version: {:?}", err));
+            // B (2.b.) priekšlaicīgs return
+        }
+    }
+}
+
+/// ID: BV_014
+/// ABC analīzes rezultāts:2,5,4
+#[tauri::command]
+pub async fn cancel_blender_version_download(
+    state: tauri::State<'_, AppState>,
+    download_id: String,
+) -> Result<(), String> {
+    let active_downloads = match state.active_downloads.lock() {
+        // A (1.a.) let active_downloads =; C (3.b) match; B (2.a.) .lock()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => {
+            return Err(format!(
+                "Failed to cancel Blender version download: {:?}",
+                err
+            ))
+        } // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    };
+    match active_downloads.get(&download_id) {
+        // C (3.b) match; B (2.a.) .get()
+        Some(cancel_flag) => {
+            // C (3.c) Some()
+            cancel_flag.store(true, std::sync::atomic::Ordering::SeqCst); // B (2.a.) .store()
+            Ok(())
+        }
+        None => Err(format!(
+            "Failed to cancel Blender version download: no active download with this ID"
+        )), // C (3.c) None =>
+    }
+}
+
+/// ID: BV_015
+/// ABC analīzes rezultāts:9,18,13
+pub fn parse_blender_version_output(output: &str) -> Option<BlenderVersionInfo> {
+    let mut info: Option<BlenderVersionInfo> = None; // A (1.a.) let mut info =
+    for line in output.lines() {
+        // A (1.a.) let line =; B (2.a.) .lines()
+        let line = line.trim(); // A (1.a.) let line =; B (2.a.) .trim()
+        if let Some(rest) = line.strip_prefix("Blender ") {
+            // A (1.d.) if let Some(); B (2.a.) .strip_prefix()
+            if info.is_some() {
+                // C (3.a.) info.is_some() == true; B (2.a.) .is_some()
+                continue; // B (2.b.) continue
+            }
+            let mut parts = rest.splitn(2, ' '); // A (1.a.) let mut parts =; B (2.a.) .splitn()
+            let version = parts.next().unwrap_or_default().to_string(); // A (1.a.) let version =; B (2.a.) .next(); B (2.a.) .unwrap_or_default(); B (2.a.) .to_string()
+            let variant_type = match parts.next().map(|val| val.trim().to_lowercase()) {
+                // A (1.a.) let variant_type =; C (3.b) match; B (2.a.) .next(); B (2.a.) .map(); B (2.a.) .trim(); B (2.a.) .to_lowercase()
+                Some(val) if val == "release candidate" => String::from("candidate"), // C (3.c) Some(); C (3.a.) val == "release candidate"; B (2.a.) ::from()
+                // Blender 2.7x adds the sub version instead, as in `Blender 2.79 (sub 0)`.
+                Some(val) if !val.is_empty() && !val.starts_with('(') => val, // C (3.c) Some(); C (3.a.) val.is_empty() != true; C (3.a.) val.starts_with() != true; B (2.a.) .is_empty(); B (2.a.) .starts_with()
+                _ => String::from("stable"), // C (3.c) _ =>; B (2.a.) ::from()
+            };
+            info = Some(BlenderVersionInfo {
+                // A (1.a.) info =
+                version: version,
+                variant_type: variant_type,
+                ..Default::default() // B (2.a.) ::default()
+            });
+            continue; // B (2.b.) continue
+        }
+        let Some(val) = info.as_mut() else {
+            // A (1.d.) let Some() else; B (2.a.) .as_mut()
+            continue; // B (2.b.) continue
+        };
+        if let Some((key, value)) = line.split_once(':') {
+            // A (1.d.) if let Some(); B (2.a.) .split_once()
+            let value = Some(value.trim().to_string()); // A (1.a.) let value =; B (2.a.) .trim(); B (2.a.) .to_string()
+            match key.trim() {
+                // C (3.b) match; B (2.a.) .trim()
+                "build hash" => val.build_hash = value, // C (3.c) "build hash" =>; A (1.a.) val.build_hash =
+                "build date" => val.build_date = value, // C (3.c) "build date" =>; A (1.a.) val.build_date =
+                "build branch" => val.build_branch = value, // C (3.c) "build branch" =>; A (1.a.) val.build_branch =
+                _ => {}                                     // C (3.c) _ =>
+            }
+        }
+    }
+    match info {
+        // C (3.b) match
+        Some(val) if !val.version.is_empty() => Some(val), // C (3.c) Some(); C (3.a.) val.version.is_empty() != true; B (2.a.) .is_empty()
+        _ => None,                                         // C (3.c) _ =>
+    }
+}
+
+/// ID: BV_016
+/// ABC analīzes rezultāts:2,5,4
+pub async fn probe_blender_version(
+    executable_file_path: &std::path::Path,
+) -> Result<BlenderVersionInfo, String> {
+    let output = file_system_utility::get_executable_output(
+        // A (1.a.) let output =; B (2.a.) ::get_executable_output()
+        executable_file_path,
+        vec![String::from("--version")],    // B (2.a.) ::from()
+        std::time::Duration::from_secs(10), // B (2.a.) ::from_secs()
+    )
+    .await?;
+    match parse_blender_version_output(&output) {
+        // C (3.b) match; B (2.a.) parse_blender_version_output()
+        Some(val) => Ok(val), // C (3.c) Some()
+        None => Err(format!(
+            "Failed to probe Blender version: unrecognized --version output"
+        )), // C (3.c) None =>
+    }
+}
+
+/// ID: BV_017
+/// ABC analīzes rezultāts:7,22,12
+#[tauri::command]
+pub async fn insert_system_installed_blender_versions(
+    app: AppHandle,
+    state: tauri::State<'_, AppState>,
+    install_sources: Vec<String>,
+) -> Result<(), String> {
+    let repository = InstalledBlenderVersionRepository::new(&state.pool); // A (1.a.) let repository =; B (2.a.) ...::new()
+    for install_source in install_sources {
+        // A (1.a.) let install_source =;
+        for executable_file_path in find_blender_executables_by_install_source(&install_source) {
+            // A (1.a.) let executable_file_path =; B (2.a.) find_blender_executables_by_install_source()
+            let existing_entries = match repository // A (1.a.) let existing_entries =; C (3.b) match
+                .fetch(
+                    None,
+                    None,
+                    Some(&executable_file_path.to_string_lossy().to_string()), // B (2.a.) .to_string_loosy(); B (2.a.) .to_string();
+                ) // B (2.a.) repository.fetch()
+                .await
+            {
+                Ok(val) => val, // C (3.c.) Ok()
+                Err(err) => {
+                    // C (3.c) Err()
+                    show_ok_notification(
+                        // B (2.a.) show_ok_notification()
+                        app.clone(), // B (2.a.) app.clone();
+                        format!("Failed to fetch installed Blender versions: {:?}", err),
+                        tauri_plugin_dialog::MessageDialogKind::Error,
+                    );
+                    return Err(format!(
+                        "Failed to fetch installed Blender versions: {:?}",
+                        err
+                    )); // B (2.b.) priekšlaicīgs return
+                }
+            };
+            if !existing_entries.is_empty() {
+                // C (3.a.) existing_entries.is_empty() != true; B (2.a.) .is_empty()
+                continue; // B (2.b.) continue
+            }
+            insert_installed_blender_version(
+                // B (2.a.) insert_installed_blender_version(); B (2.a.) app.clone(); B (2.a.) state.clone(); B (2.a.) install_source.clone()
+                app.clone(),
+                state.clone(),
+                executable_file_path,
+                Some(install_source.clone()),
+            )
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+/// ID: BV_018
+/// ABC analīzes rezultāts:1,7,7
+pub fn find_blender_executables_by_install_source(install_source: &str) -> Vec<std::path::PathBuf> {
+    match install_source {
+        // C (3.b) match
+        "system" => file_system_utility::find_system_blender_executables(), // C (3.c) "system" =>; B (2.a.) ::find_system_blender_executables()
+        "local" => file_system_utility::find_local_blender_executables(), // C (3.c) "local" =>; B (2.a.) ::find_local_blender_executables()
+        "snap" => file_system_utility::find_snap_blender_executables(), // C (3.c) "snap" =>; B (2.a.) ::find_snap_blender_executables()
+        "flatpak" => file_system_utility::find_flatpak_blender_executables(), // C (3.c) "flatpak" =>; B (2.a.) ::find_flatpak_blender_executables()
+        "steam" => file_system_utility::find_steam_blender_executables(), // C (3.c) "steam" =>; B (2.a.) ::find_steam_blender_executables()
+        _ => Vec::new(), // C (3.c) _ =>; B (2.a.) ...::new()
+    }
+}
+
+/// ID: BV_019
+/// ABC analīzes rezultāts:2,7,9
+pub fn get_install_source(executable_file_path: &std::path::Path, default: &str) -> String {
+    // Package managed paths always win over the caller's guess, so a manually picked
+    // /usr/bin/blender can never be uninstalled with remove_dir_all. Builds unpacked into /opt
+    // by hand stay uninstallable, only packages own their /opt directories.
+    let path = executable_file_path
+        .to_string_lossy()
+        .replace('\\', "/")
+        .to_lowercase(); // A (1.a.) let path =; B (2.a.) .to_string_lossy(); B (2.a.) .replace(); B (2.a.) .to_lowercase()
+    let install_source = if path.starts_with("/snap/") {
+        // A (1.a.) let install_source =; C (3.a.) path.starts_with() == true; B (2.a.) .starts_with()
+        "snap"
+    } else if path.contains("/flatpak/") {
+        // C (3.b.) else if; C (3.a.) path.contains() == true; B (2.a.) .contains()
+        "flatpak"
+    } else if path.contains("/steamapps/common/") {
+        // C (3.b.) else if; C (3.a.) path.contains() == true; B (2.a.) .contains()
+        "steam"
+    } else if path.starts_with("/usr/")
+        || path.starts_with("/applications/")
+        || path.contains("/program files/")
+        || (path.starts_with("/opt/")
+            && file_system_utility::is_package_owned_path(executable_file_path))
+    {
+        // C (3.b.) else if; C (3.a.) path.starts_with() == true; C (3.a.) path.contains() == true; B (2.a.) .starts_with(); B (2.a.) .contains(); C (3.a.) ::is_package_owned_path() == true; B (2.a.) ::is_package_owned_path()
+        "system"
+    } else {
+        // C (3.b.) else
+        default
+    };
+    install_source.to_string() // B (2.a.) .to_string()
+}
+
+/// ID: BV_020
+/// ABC analīzes rezultāts:0,0,4
+pub fn is_install_source_uninstallable(install_source: &str) -> bool {
+    matches!(
+        install_source,
+        "repository" | "download" | "manual" | "local"
+    ) // C (3.a.) matches!()
+}
+
+/// ID: BV_021
+/// ABC analīzes rezultāts:5,17,9
+#[tauri::command]
+pub async fn update_blender_version_installation_location_scan_settings(
+    app: AppHandle,
+    state: tauri::State<'_, AppState>,
+    id: String,
+    scan_depth: i64,
+    include_patterns: String,
+    exclude_patterns: String,
+) -> Result<(), String> {
+    // Reject broken patterns up front instead of failing every later refresh.
+    for patterns in [&include_patterns, &exclude_patterns] {
+        // A (1.a.) let patterns =;
+        if let Err(err) = file_system_utility::get_glob_set(patterns) {
+            // A (1.d.) if let Err(); B (2.a.) ::get_glob_set()
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to update Blender repo path: {:?}", err),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            return Err(format!("Failed to update Blender repo path: {:?}", err));
+            // B (2.b.) priekšlaicīgs return
+        }
+    }
+    let repository = BlenderRepoPathRepository::new(&state.pool); // A (1.a.) let repository =; B (2.a.) ...::new()
+    let mut results = match repository.fetch(Some(&id), None, None).await {
+        // A (1.a.) let mut results =; C (3.b) match; B (2.a.) .fetch()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c) Err()
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to fetch Blender repo paths: {:?}", err),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            return Err(format!("Failed to fetch Blender repo paths: {:?}", err));
+            // B (2.b.) priekšlaicīgs return
+        }
+    };
+    if results.is_empty() {
+        // B (2.a.) .is_empty(); C (3.a) results.is_empty() == true
+        show_ok_notification(
+            // B (2.a.) show_ok_notification()
+            app.clone(), // B (2.a.) app.clone();
+            format!("Failed to fetch Blender repo path by ID"),
+            tauri_plugin_dialog::MessageDialogKind::Error,
+        );
+        return Err(format!("Failed to fetch Blender repo path by ID")); // B (2.b.) priekšlaicīgs return
+    }
+    let mut entry = results.remove(0); // A (1.a.) let mut entry =; B (2.a.) .remove()
+    entry.scan_depth = scan_depth.max(1); // A (1.a.) entry.scan_depth =; B (2.a.) .max()
+    entry.include_patterns = include_patterns; // A (1.a.) entry.include_patterns =
+    entry.exclude_patterns = exclude_patterns; // A (1.a.) entry.exclude_patterns =
+    match repository.update(&entry).await {
+        // C (3.b) match; B (2.a.) .update()
+        Ok(_) => Ok(()), // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c) Err()
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to update Blender repo path: {:?}", err),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            return Err(format!("Failed to update Blender repo path: {:?}", err));
+            // B (2.b.) priekšlaicīgs return
+        }
+    }
+}
+
+/// ID: BV_022
+/// ABC analīzes rezultāts:3,9,2
+pub fn parse_blender_major_minor_version(version: &str) -> Option<(u32, u32)> {
+    // Versions are stored as `4.2.3`, older releases as `2.79b`.
+    let mut parts = version.split(|val: char| !val.is_ascii_digit()); // A (1.a.) let mut parts =; B (2.a.) .split(); C (3.a.) val.is_ascii_digit() != true; B (2.a.) .is_ascii_digit()
+    let major = parts.next()?.parse::<u32>().ok()?; // A (1.a.) let major =; B (2.a.) .next(); B (2.a.) .parse(); B (2.a.) .ok()
+    let minor = parts.next()?.parse::<u32>().ok()?; // A (1.a.) let minor =; B (2.a.) .next(); B (2.a.) .parse(); B (2.a.) .ok()
+    Some((major, minor))
+}
+
+/// ID: BV_023
+/// ABC analīzes rezultāts:3,16,7
+pub fn find_closest_blender_version(
+    installed_blender_versions: &[InstalledBlenderVersion],
+    saved_version: (u32, u32),
+) -> Option<&InstalledBlenderVersion> {
+    // Stable and LTS builds win over alpha, beta and candidate builds of the same version.
+    let is_stable = |val: &InstalledBlenderVersion| {
+        // A (1.a.) let is_stable =
+        val.variant_type == "stable" || val.variant_type == "lts" // C (3.a.) val.variant_type == "stable"; C (3.a.) val.variant_type == "lts"
+    };
+    let versions: Vec<(&InstalledBlenderVersion, (u32, u32))> = installed_blender_versions
+        .iter()
+        .filter_map(|val| Some((val, parse_blender_major_minor_version(&val.version)?)))
+        .collect(); // A (1.a.) let versions =; B (2.a.) .iter(); B (2.a.) .filter_map(); B (2.a.) parse_blender_major_minor_version(); B (2.a.) .collect()
+
+    // The oldest version that opens the file without a downgrade is the closest match,
+    // without one the newest installed version loses the least.
+    match versions
+        .iter()
+        .filter(|val| val.1 >= saved_version)
+        .min_by_key(|val| (val.1, !is_stable(val.0)))
+    {
+        // C (3.b) match; B (2.a.) .iter(); B (2.a.) .filter(); C (3.a.) val.1 >= saved_version; B (2.a.) .min_by_key(); B (2.a.) is_stable()
+        Some(val) => Some(val.0), // C (3.c) Some()
+        None => versions
+            .iter()
+            .max_by_key(|val| (val.1, is_stable(val.0)))
+            .map(|val| val.0), // C (3.c) None =>; B (2.a.) .iter(); B (2.a.) .max_by_key(); B (2.a.) is_stable(); B (2.a.) .map()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_blender_version_output_reads_release_output() {
+        let output = "Blender 4.2.3 LTS
+\tbuild date: 2024-10-15
+\tbuild time: 23:38:55
+\tbuild commit date: 2024-10-14
+\tbuild commit time: 14:30
+\tbuild hash: 2e5c37a2bcd7
+\tbuild branch: blender-v4.2-release
+\tbuild platform: Linux
+\tbuild type: Release
+\tbuild c flags:  -Wall -Wcast-align -fopenmp -std=gnu11
+\tbuild linkflags:  -Wl,--version-script=/home/builder/blender.map -latomic
+\tbuild system: CMake
+";
+        let info = parse_blender_version_output(output).unwrap();
+        assert_eq!(info.version, "4.2.3");
+        assert_eq!(info.variant_type, "lts");
+        assert_eq!(info.build_hash.as_deref(), Some("2e5c37a2bcd7"));
+        assert_eq!(info.build_date.as_deref(), Some("2024-10-15"));
+        assert_eq!(info.build_branch.as_deref(), Some("blender-v4.2-release"));
+    }
+
+    #[test]
+    fn parse_blender_version_output_reads_prerelease_output() {
+        let output = "Color management: using fallback mode for management
+Blender 4.5.0 Alpha
+\tbuild date: 2025-04-20
+\tbuild hash: 6a3bb1a0d5a6
+\tbuild branch: main
+";
+        let info = parse_blender_version_output(output).unwrap();
+        assert_eq!(info.version, "4.5.0");
+        assert_eq!(info.variant_type, "alpha");
+        assert_eq!(info.build_branch.as_deref(), Some("main"));
+        let info = parse_blender_version_output("Blender 4.4.0 Release Candidate\n").unwrap();
+        assert_eq!(info.variant_type, "candidate");
+        let info = parse_blender_version_output("Blender 4.3.2\n").unwrap();
+        assert_eq!(info.variant_type, "stable");
+        assert_eq!(info.build_hash, None);
+    }
+
+    #[test]
+    fn parse_blender_version_output_reads_legacy_output() {
+        let output = "Blender 2.79 (sub 0)
+\tbuild date: 2017-09-11
+\tbuild hash: 5bd8ac9
+\tbuild branch: master
+";
+        let info = parse_blender_version_output(output).unwrap();
+        assert_eq!(info.version, "2.79");
+        assert_eq!(info.variant_type, "stable");
+        assert_eq!(info.build_hash.as_deref(), Some("5bd8ac9"));
+    }
+
+    #[test]
+    fn parse_blender_version_output_rejects_other_output() {
+        assert!(parse_blender_version_output("").is_none());
+        assert!(parse_blender_version_output("bash: blender: command not found").is_none());
+        assert!(parse_blender_version_output("Blender \n\tbuild hash: 1234").is_none());
+    }
+
+    #[test]
+    fn get_install_source_keeps_unpacked_opt_builds_uninstallable() {
+        let get = |path: &str| get_install_source(std::path::Path::new(path), "repository");
+        assert_eq!(get("/opt/blender-4.2.3-linux-x64/blender"), "repository");
+        assert_eq!(get("/usr/bin/blender"), "system");
+        assert_eq!(get("/snap/blender/5581/blender"), "snap");
+        assert_eq!(get("/home/user/blender-4.2/blender"), "repository");
+        assert!(is_install_source_uninstallable(&get(
+            "/opt/blender-4.2.3-linux-x64/blender"
+        )));
+    }
+
+    #[cfg(unix)]
+    fn create_stub_executable(script: &str) -> std::path::PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+        let directory_path =
+            std::env::temp_dir().join(format!("blendio-test-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&directory_path).unwrap();
+        let executable_file_path = directory_path.join("blender");
+        std::fs::write(&executable_file_path, script).unwrap();
+        std::fs::set_permissions(
+            &executable_file_path,
+            std::fs::Permissions::from_mode(0o755),
+        )
+        .unwrap();
+        executable_file_path
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn probe_blender_version_reads_stub_executable_output() {
+        let executable_file_path = create_stub_executable(
+            "#!/bin/sh\n[ \"$1\" = \"--version\" ] || exit 1\nprintf 'Blender 4.2.3 LTS\\n\\tbuild date: 2024-10-15\\n\\tbuild hash: 2e5c37a2bcd7\\n\\tbuild branch: blender-v4.2-release\\n'\n",
+        );
+        let info = probe_blender_version(&executable_file_path).await.unwrap();
+        assert_eq!(info.version, "4.2.3");
+        assert_eq!(info.variant_type, "lts");
+        assert_eq!(info.build_hash.as_deref(), Some("2e5c37a2bcd7"));
+        assert_eq!(info.build_date.as_deref(), Some("2024-10-15"));
+        assert_eq!(info.build_branch.as_deref(), Some("blender-v4.2-release"));
+        let _ = std::fs::remove_dir_all(executable_file_path.parent().unwrap());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn probe_blender_version_rejects_other_executable_output() {
+        let executable_file_path = create_stub_executable("#!/bin/sh\necho 'not blender'\n");
+        assert!(probe_blender_version(&executable_file_path).await.is_err());
+        let _ = std::fs::remove_dir_all(executable_file_path.parent().unwrap());
+    }
+
+    #[test]
+    fn parse_blender_directory_name_reads_archive_directory_names() {
+        let info = parse_blender_directory_name("blender-4.2.3-linux-x64");
+        assert_eq!(info.version, "4.2.3");
+        let info =
+            parse_blender_directory_name("blender-4.5.0-alpha+main.6a3bb1a0d5a6-linux.x86_64");
+        assert_eq!(info.version, "4.5.0");
+        assert_eq!(info.variant_type, "alpha");
+        let info = parse_blender_directory_name("my-blender");
+        assert!(info.version.is_empty());
+        assert!(info.variant_type.is_empty());
+    }
+}
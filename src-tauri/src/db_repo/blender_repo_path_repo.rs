@@ -12,10 +12,13 @@ impl<'a> BlenderRepoPathRepository<'a> {
 
     pub async fn insert(&self, repo: &BlenderRepoPath) -> Result<(), sqlx::Error> {
         sqlx::query!(
-            "INSERT INTO blender_repo_paths (id, repo_directory_path, is_default) VALUES (?, ?, ?)",
+            "INSERT INTO blender_repo_paths (id, repo_directory_path, is_default, scan_depth, include_patterns, exclude_patterns) VALUES (?, ?, ?, ?, ?, ?)",
             repo.id,
             repo.repo_directory_path,
-            repo.is_default
+            repo.is_default,
+            repo.scan_depth,
+            repo.include_patterns,
+            repo.exclude_patterns
         )
         .execute(self.pool)
         .await?;
@@ -61,9 +64,12 @@ impl<'a> BlenderRepoPathRepository<'a> {
 
     pub async fn update(&self, repo: &BlenderRepoPath) -> Result<(), sqlx::Error> {
         sqlx::query!(
-            "UPDATE blender_repo_paths SET repo_directory_path = ?, is_default = ?, modified = CURRENT_TIMESTAMP, accessed = CURRENT_TIMESTAMP WHERE id = ?",
+            "UPDATE blender_repo_paths SET repo_directory_path = ?, is_default = ?, scan_depth = ?, include_patterns = ?, exclude_patterns = ?, modified = CURRENT_TIMESTAMP, accessed = CURRENT_TIMESTAMP WHERE id = ?",
             repo.repo_directory_path,
             repo.is_default,
+            repo.scan_depth,
+            repo.include_patterns,
+            repo.exclude_patterns,
             repo.id
         )
         .execute(self.pool)
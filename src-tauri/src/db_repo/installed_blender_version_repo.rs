@@ -12,14 +12,19 @@ impl<'a> InstalledBlenderVersionRepository<'a> {
 
     pub async fn insert(&self, entry: &InstalledBlenderVersion) -> Result<(), sqlx::Error> {
         sqlx::query!(
-            "INSERT INTO installed_blender_versions (id, version, variant_type, download_url, is_default, installation_directory_path, executable_file_path) VALUES (?, ?, ?, ?, ?, ?, ?) ON CONFLICT(executable_file_path) DO NOTHING",
+            "INSERT INTO installed_blender_versions (id, version, variant_type, download_url, is_default, installation_directory_path, executable_file_path, build_hash, build_date, build_branch, install_source, is_checksum_verified) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?) ON CONFLICT(executable_file_path) DO NOTHING",
             entry.id,
             entry.version,
             entry.variant_type,
             entry.download_url,
             entry.is_default,
             entry.installation_directory_path,
-            entry.executable_file_path
+            entry.executable_file_path,
+            entry.build_hash,
+            entry.build_date,
+            entry.build_branch,
+            entry.install_source,
+            entry.is_checksum_verified
         )
         .execute(self.pool)
         .await?;
@@ -67,13 +72,18 @@ impl<'a> InstalledBlenderVersionRepository<'a> {
 
     pub async fn update(&self, version: &InstalledBlenderVersion) -> Result<(), sqlx::Error> {
         sqlx::query!(
-            "UPDATE installed_blender_versions SET version = ?, variant_type = ?, download_url = ?, is_default = ?, installation_directory_path = ?, executable_file_path = ?, modified = CURRENT_TIMESTAMP, accessed = CURRENT_TIMESTAMP WHERE id = ?",
+            "UPDATE installed_blender_versions SET version = ?, variant_type = ?, download_url = ?, is_default = ?, installation_directory_path = ?, executable_file_path = ?, build_hash = ?, build_date = ?, build_branch = ?, install_source = ?, is_checksum_verified = ?, modified = CURRENT_TIMESTAMP, accessed = CURRENT_TIMESTAMP WHERE id = ?",
             version.version,
             version.variant_type,
             version.download_url,
             version.is_default,
             version.installation_directory_path,
             version.executable_file_path,
+            version.build_hash,
+            version.build_date,
+            version.build_branch,
+            version.install_source,
+            version.is_checksum_verified,
             version.id
         )
         .execute(self.pool)
@@ -12,10 +12,13 @@ impl<'a> LaunchArgumentRepository<'a> {
 
     pub async fn insert(&self, arg: &LaunchArgument) -> Result<(), sqlx::Error> {
         sqlx::query!(
-            "INSERT INTO launch_arguments (id, is_default, argument_string, last_used_project_file_id, last_used_python_script_id) VALUES (?, ?, ?, ?, ?)",
+            "INSERT INTO launch_arguments (id, is_default, argument_string, preset_json, environment_json, working_directory, last_used_project_file_id, last_used_python_script_id) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
             arg.id,
             arg.is_default,
             arg.argument_string,
+            arg.preset_json,
+            arg.environment_json,
+            arg.working_directory,
             arg.last_used_project_file_id,
             arg.last_used_python_script_id
         )
@@ -62,9 +65,12 @@ impl<'a> LaunchArgumentRepository<'a> {
 
     pub async fn update(&self, arg: &LaunchArgument) -> Result<(), sqlx::Error> {
         sqlx::query!(
-            "UPDATE launch_arguments SET is_default = ?, argument_string = ?, last_used_project_file_id = ?, last_used_python_script_id = ?, modified = CURRENT_TIMESTAMP, accessed = CURRENT_TIMESTAMP WHERE id = ?",
+            "UPDATE launch_arguments SET is_default = ?, argument_string = ?, preset_json = ?, environment_json = ?, working_directory = ?, last_used_project_file_id = ?, last_used_python_script_id = ?, modified = CURRENT_TIMESTAMP, accessed = CURRENT_TIMESTAMP WHERE id = ?",
             arg.is_default,
             arg.argument_string,
+            arg.preset_json,
+            arg.environment_json,
+            arg.working_directory,
             arg.last_used_project_file_id,
             arg.last_used_python_script_id,
             arg.id
@@ -0,0 +1,79 @@
+use crate::models::LaunchSession;
+use sqlx::SqlitePool;
+
+pub struct LaunchSessionRepository<'a> {
+    pub pool: &'a SqlitePool,
+}
+
+impl<'a> LaunchSessionRepository<'a> {
+    pub fn new(pool: &'a SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn insert(&self, session: &LaunchSession) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "INSERT INTO launch_sessions (id, installed_blender_version_id, blender_version, launch_argument_id, python_script_ids_json, project_file_id, arguments_json, started, ended, exit_code, exit_signal, log_file_path) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            session.id,
+            session.installed_blender_version_id,
+            session.blender_version,
+            session.launch_argument_id,
+            session.python_script_ids_json,
+            session.project_file_id,
+            session.arguments_json,
+            session.started,
+            session.ended,
+            session.exit_code,
+            session.exit_signal,
+            session.log_file_path
+        )
+        .execute(self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn fetch(
+        &self,
+        id: Option<&str>,
+        limit: Option<i64>,
+        installed_blender_version_id: Option<&str>,
+        project_file_id: Option<&str>,
+        only_failed: bool,
+    ) -> Result<Vec<LaunchSession>, sqlx::Error> {
+        // A negative LIMIT means no limit in SQLite.
+        sqlx::query_as::<_, LaunchSession>(
+            "SELECT * FROM launch_sessions WHERE (?1 IS NULL OR id = ?1) AND (?2 IS NULL OR installed_blender_version_id = ?2) AND (?3 IS NULL OR project_file_id = ?3) AND (?4 = 0 OR (ended IS NOT NULL AND (exit_code IS NULL OR exit_code != 0))) ORDER BY started DESC LIMIT ?5",
+        )
+        .bind(id)
+        .bind(installed_blender_version_id)
+        .bind(project_file_id)
+        .bind(only_failed)
+        .bind(limit.unwrap_or(-1))
+        .fetch_all(self.pool)
+        .await
+    }
+
+    pub async fn fetch_ended_before(&self, ended: &str) -> Result<Vec<LaunchSession>, sqlx::Error> {
+        sqlx::query_as::<_, LaunchSession>(
+            "SELECT * FROM launch_sessions WHERE ended IS NOT NULL AND ended < ? AND log_file_path IS NOT NULL",
+        )
+        .bind(ended)
+        .fetch_all(self.pool)
+        .await
+    }
+
+    pub async fn update(&self, session: &LaunchSession) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "UPDATE launch_sessions SET ended = ?, exit_code = ?, exit_signal = ?, log_file_path = ?, crash_report_file_path = ?, stderr_tail = ?, modified = CURRENT_TIMESTAMP, accessed = CURRENT_TIMESTAMP WHERE id = ?",
+            session.ended,
+            session.exit_code,
+            session.exit_signal,
+            session.log_file_path,
+            session.crash_report_file_path,
+            session.stderr_tail,
+            session.id
+        )
+        .execute(self.pool)
+        .await?;
+        Ok(())
+    }
+}
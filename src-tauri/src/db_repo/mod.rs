@@ -1,11 +1,17 @@
 mod blender_repo_path_repo;
 mod installed_blender_version_repo;
 mod launch_argument_repo;
+mod launch_session_repo;
 mod project_fiile_repo;
+mod python_script_library_folder_repo;
 mod python_script_repo;
+mod python_script_snapshot_repo;
 
 pub use blender_repo_path_repo::BlenderRepoPathRepository;
 pub use installed_blender_version_repo::InstalledBlenderVersionRepository;
 pub use launch_argument_repo::LaunchArgumentRepository;
+pub use launch_session_repo::LaunchSessionRepository;
 pub use project_fiile_repo::ProjectFileRepository;
+pub use python_script_library_folder_repo::PythonScriptLibraryFolderRepository;
 pub use python_script_repo::PythonScriptRepository;
+pub use python_script_snapshot_repo::PythonScriptSnapshotRepository;
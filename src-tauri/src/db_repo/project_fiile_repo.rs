@@ -72,6 +72,26 @@ impl<'a> ProjectFileRepository<'a> {
         Ok(())
     }
 
+    pub async fn update_metadata(&self, file: &ProjectFile) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "UPDATE project_files SET saved_blender_version = ?, scene_names_json = ?, frame_start = ?, frame_end = ?, render_engine = ?, resolution_x = ?, resolution_y = ?, resolution_percentage = ?, metadata_file_modified = ?, file_hash = ? WHERE id = ?",
+            file.saved_blender_version,
+            file.scene_names_json,
+            file.frame_start,
+            file.frame_end,
+            file.render_engine,
+            file.resolution_x,
+            file.resolution_y,
+            file.resolution_percentage,
+            file.metadata_file_modified,
+            file.file_hash,
+            file.id
+        )
+        .execute(self.pool)
+        .await?;
+        Ok(())
+    }
+
     pub async fn delete(&self, id: &str) -> Result<(), sqlx::Error> {
         sqlx::query("DELETE FROM project_files WHERE id = ?")
             .bind(id)
@@ -0,0 +1,61 @@
+use crate::models::PythonScriptLibraryFolder;
+use sqlx::SqlitePool;
+
+pub struct PythonScriptLibraryFolderRepository<'a> {
+    pub pool: &'a SqlitePool,
+}
+
+impl<'a> PythonScriptLibraryFolderRepository<'a> {
+    pub fn new(pool: &'a SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn insert(&self, folder: &PythonScriptLibraryFolder) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "INSERT INTO python_script_library_folders (id, folder_path) VALUES (?, ?)",
+            folder.id,
+            folder.folder_path
+        )
+        .execute(self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn fetch(
+        &self,
+        id: Option<&str>,
+        folder_path: Option<&str>,
+    ) -> Result<Vec<PythonScriptLibraryFolder>, sqlx::Error> {
+        if let Some(id) = id {
+            // A (1.d.) if let Some()
+            sqlx::query_as::<_, PythonScriptLibraryFolder>(
+                "SELECT * FROM python_script_library_folders WHERE id = ?",
+            )
+            .bind(id)
+            .fetch_all(self.pool)
+            .await
+        } else if let Some(folder_path) = folder_path {
+            // A (1.d.) if let Some()
+            sqlx::query_as::<_, PythonScriptLibraryFolder>(
+                "SELECT * FROM python_script_library_folders WHERE folder_path = ?",
+            )
+            .bind(folder_path)
+            .fetch_all(self.pool)
+            .await
+        } else {
+            sqlx::query_as::<_, PythonScriptLibraryFolder>(
+                "SELECT * FROM python_script_library_folders",
+            )
+            .fetch_all(self.pool)
+            .await
+        }
+    }
+
+    pub async fn delete(&self, id: &str) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM python_script_library_folders WHERE id = ?")
+            .bind(id)
+            .execute(self.pool)
+            .await?;
+        Ok(())
+    }
+}
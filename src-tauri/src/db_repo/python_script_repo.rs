@@ -12,9 +12,17 @@ impl<'a> PythonScriptRepository<'a> {
 
     pub async fn insert(&self, script: &PythonScript) -> Result<(), sqlx::Error> {
         sqlx::query!(
-            "INSERT INTO python_scripts (id, script_file_path) VALUES (?, ?) ON CONFLICT(script_file_path) DO NOTHING",
+            "INSERT INTO python_scripts (id, script_file_path, name, description, metadata_json, content_hash, library_folder_id, tags_json, is_missing, safety_warnings_json) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?) ON CONFLICT(script_file_path) DO NOTHING",
             script.id,
-            script.script_file_path
+            script.script_file_path,
+            script.name,
+            script.description,
+            script.metadata_json,
+            script.content_hash,
+            script.library_folder_id,
+            script.tags_json,
+            script.is_missing,
+            script.safety_warnings_json
         )
         .execute(self.pool)
         .await?;
@@ -59,8 +67,16 @@ impl<'a> PythonScriptRepository<'a> {
 
     pub async fn update(&self, script: &PythonScript) -> Result<(), sqlx::Error> {
         sqlx::query!(
-            "UPDATE python_scripts SET script_file_path = ?, modified = CURRENT_TIMESTAMP, accessed = CURRENT_TIMESTAMP WHERE id = ?",
+            "UPDATE python_scripts SET script_file_path = ?, name = ?, description = ?, metadata_json = ?, content_hash = ?, library_folder_id = ?, tags_json = ?, is_missing = ?, safety_warnings_json = ?, modified = CURRENT_TIMESTAMP, accessed = CURRENT_TIMESTAMP WHERE id = ?",
             script.script_file_path,
+            script.name,
+            script.description,
+            script.metadata_json,
+            script.content_hash,
+            script.library_folder_id,
+            script.tags_json,
+            script.is_missing,
+            script.safety_warnings_json,
             script.id
         )
         .execute(self.pool)
@@ -68,6 +84,18 @@ impl<'a> PythonScriptRepository<'a> {
         Ok(())
     }
 
+    pub async fn fetch_by_library_folder(
+        &self,
+        library_folder_id: &str,
+    ) -> Result<Vec<PythonScript>, sqlx::Error> {
+        sqlx::query_as::<_, PythonScript>(
+            "SELECT * FROM python_scripts WHERE library_folder_id = ?",
+        )
+        .bind(library_folder_id)
+        .fetch_all(self.pool)
+        .await
+    }
+
     pub async fn delete(&self, id: &str) -> Result<(), sqlx::Error> {
         sqlx::query("DELETE FROM python_scripts WHERE id = ?")
             .bind(id)
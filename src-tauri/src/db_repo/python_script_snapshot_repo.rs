@@ -0,0 +1,43 @@
+use crate::models::PythonScriptSnapshot;
+use sqlx::SqlitePool;
+
+pub struct PythonScriptSnapshotRepository<'a> {
+    pub pool: &'a SqlitePool,
+}
+
+impl<'a> PythonScriptSnapshotRepository<'a> {
+    pub fn new(pool: &'a SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn insert(&self, snapshot: &PythonScriptSnapshot) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "INSERT INTO python_script_snapshots (id, python_script_id, launch_session_id, script_file_path, content_hash, snapshot_file_path) VALUES (?, ?, ?, ?, ?, ?)",
+            snapshot.id,
+            snapshot.python_script_id,
+            snapshot.launch_session_id,
+            snapshot.script_file_path,
+            snapshot.content_hash,
+            snapshot.snapshot_file_path
+        )
+        .execute(self.pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn fetch(
+        &self,
+        id: Option<&str>,
+        launch_session_id: Option<&str>,
+        python_script_id: Option<&str>,
+    ) -> Result<Vec<PythonScriptSnapshot>, sqlx::Error> {
+        sqlx::query_as::<_, PythonScriptSnapshot>(
+            "SELECT * FROM python_script_snapshots WHERE (?1 IS NULL OR id = ?1) AND (?2 IS NULL OR launch_session_id = ?2) AND (?3 IS NULL OR python_script_id = ?3) ORDER BY created DESC, rowid",
+        )
+        .bind(id)
+        .bind(launch_session_id)
+        .bind(python_script_id)
+        .fetch_all(self.pool)
+        .await
+    }
+}
@@ -1,9 +1,14 @@
 use std::io::{Read, Write};
 
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use sha2::{Digest, Sha256};
 use tauri::{AppHandle, WebviewUrl, WebviewWindowBuilder};
 use tauri_plugin_dialog::{DialogExt, MessageDialogButtons};
+use tokio::io::AsyncWriteExt;
 use zip::ZipArchive;
 
+use crate::models::DownloadProgress;
+
 /// ID: FSU_001
 /// ABC analīzes rezultāts:0,12,3
 #[tauri::command]
@@ -85,30 +90,64 @@ pub fn show_ask_notification(
 }
 
 /// ID: FSU_005
-/// ABC analīzes rezultāts:11,26,23
+/// ABC analīzes rezultāts:4,17,11
 pub async fn extract_archive(
     archive_file_path: std::path::PathBuf,
+    extract_dir: std::path::PathBuf,
 ) -> Result<std::path::PathBuf, String> {
+    let archive_file_name = match archive_file_path.file_name() {
+        // A (1.a.) let archive_file_name =; C (3.b) match; B (2.a.) .file_name()
+        Some(name) => name.to_string_lossy().to_lowercase(), // C (3.c) Some(); B (2.a.) .to_string_loosy(); B (2.a.) .to_lowercase();
+        None => return Err(format!("Failed to extract archive file")), // C (3.c) None =>; B (2.b.) priekšlaicīgs return
+    };
     let file = match std::fs::File::open(&archive_file_path) {
         // A (1.a.) let file =; C (3.b.) match; B (2.a.) ::open()
         Ok(val) => val, // C (3.c.) Ok()
         Err(err) => return Err(format!("Failed to extract archive file: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
     };
+    let top_level_entries = if archive_file_name.ends_with(".zip") {
+        // A (1.a.) let top_level_entries =; C (3.a.) archive_file_name.ends_with(".zip") == true; B (2.a.) .ends_with()
+        extract_zip_archive(file, &extract_dir)? // B (2.a.) extract_zip_archive()
+    } else if archive_file_name.ends_with(".tar.xz") || archive_file_name.ends_with(".txz") {
+        // C (3.b.) else; C (3.a.) archive_file_name.ends_with(".tar.xz") == true; B (2.a.) .ends_with()
+        extract_tar_archive(xz2::read::XzDecoder::new(file), &extract_dir)? // B (2.a.) extract_tar_archive(); B (2.a.) ...::new()
+    } else if archive_file_name.ends_with(".tar.gz") || archive_file_name.ends_with(".tgz") {
+        // C (3.b.) else; C (3.a.) archive_file_name.ends_with(".tar.gz") == true; B (2.a.) .ends_with()
+        extract_tar_archive(flate2::read::GzDecoder::new(file), &extract_dir)? // B (2.a.) extract_tar_archive(); B (2.a.) ...::new()
+    } else if archive_file_name.ends_with(".tar.bz2") || archive_file_name.ends_with(".tbz2") {
+        // C (3.b.) else; C (3.a.) archive_file_name.ends_with(".tar.bz2") == true; B (2.a.) .ends_with()
+        extract_tar_archive(bzip2::read::BzDecoder::new(file), &extract_dir)? // B (2.a.) extract_tar_archive(); B (2.a.) ...::new()
+    } else {
+        // C (3.b.) else
+        return Err(format!(
+            "Failed to extract archive file: unsupported archive type {}",
+            archive_file_name
+        )); // B (2.b.) priekšlaicīgs return
+    };
+    // Archives are expected to wrap their contents in a single top level directory.
+    if top_level_entries.len() != 1 {
+        // C (3.a.) top_level_entries.len() != 1; B (2.a.) .len()
+        return Err(format!(
+            "Failed to extract archive file: expected a single top level directory, found {:?}",
+            top_level_entries
+        )); // B (2.b.) priekšlaicīgs return
+    }
+    let archive_dir = top_level_entries.into_iter().next().unwrap_or_default(); // A (1.a.) let archive_dir =; B (2.a.) .into_iter(); B (2.a.) .next(); B (2.a.) .unwrap_or_default()
+    Ok(extract_dir.join(archive_dir)) // B (2.a.) .join()
+}
+
+/// ID: FSU_016
+/// ABC analīzes rezultāts:10,30,22
+pub fn extract_zip_archive(
+    file: std::fs::File,
+    extract_dir: &std::path::Path,
+) -> Result<std::collections::BTreeSet<String>, String> {
     let mut archive = match ZipArchive::new(file) {
         // A (1.a.) let mut archive =; C (3.b.) match; B (2.a.) ...::new()
         Ok(val) => val, // C (3.c.) Ok()
         Err(err) => return Err(format!("Failed to extract archive file: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
     };
-    let archive_dir = match archive_file_path.file_stem() {
-        // A (1.a.) let archive_dir =; C (3.b) match; B (2.a.) .file_stem()
-        Some(name) => name.to_string_lossy().to_string(), // C (3.c) Some(); B (2.a.) .to_string_loosy(); B (2.a.) .to_string();
-        None => return Err(format!("Failed to extract archive file")), // C (3.c) None =>; B (2.b.) priekšlaicīgs return
-    };
-    let extract_dir = match archive_file_path.parent() {
-        // A (1.a.) let extract_dir =; C (3.b) match; B (2.a.) .parent()
-        Some(parent) => parent, // C (3.c) Some()
-        None => return Err(format!("Failed to extract archive file")), // C (3.c) None =>; B (2.b.) priekšlaicīgs return
-    };
+    let mut top_level_entries = std::collections::BTreeSet::new(); // A (1.a.) let mut top_level_entries =; B (2.a.) ...::new()
     for i in 0..archive.len() {
         // A (1.a.) let i =; B (2.b.) .len()
         let mut inner_file = match archive.by_index(i) {
@@ -116,9 +155,38 @@ pub async fn extract_archive(
             Ok(val) => val, // C (3.c.) Ok()
             Err(err) => return Err(format!("Failed to extract archive file: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
         };
-        let outpath = extract_dir.join(inner_file.name()); // A (1.a.) let outpath =; B (2.a.) .join(); B (2.a.) .name()
-        if inner_file.name().ends_with('/') {
-            // C (3.a.) inner_file.name().ends_with('/') == true;  B (2.a.) .name(); B (2.a.) .ends_with()
+        let entry_path = get_enclosed_entry_path(std::path::Path::new(inner_file.name()))?; // A (1.a.) let entry_path =; B (2.a.) get_enclosed_entry_path(); B (2.a.) ...::new(); B (2.a.) .name()
+        if let Some(top_level_entry) = get_top_level_entry(&entry_path) {
+            // A (1.d.) if let Some(); B (2.a.) get_top_level_entry()
+            top_level_entries.insert(top_level_entry); // A (1.c.) .insert()
+        }
+        let outpath = extract_dir.join(&entry_path); // A (1.a.) let outpath =; B (2.a.) .join()
+        create_enclosed_parent_directory(extract_dir, &entry_path)?; // B (2.a.) create_enclosed_parent_directory()
+        if inner_file.is_symlink() {
+            // C (3.a.) inner_file.is_symlink() == true; B (2.a.) .is_symlink()
+            let mut link_target = String::new(); // A (1.a.) let mut link_target =; B (2.a.) ...::new()
+            if let Err(err) = inner_file.read_to_string(&mut link_target) {
+                // A (1.d.) if let Err(); B (2.a.) .read_to_string()
+                return Err(format!("Failed to extract archive file: {:?}", err));
+                // B (2.b.) priekšlaicīgs return
+            }
+            if !is_link_target_enclosed(&entry_path, std::path::Path::new(&link_target)) {
+                // C (3.a.) is_link_target_enclosed() != true; B (2.a.) is_link_target_enclosed(); B (2.a.) ...::new()
+                return Err(format!(
+                    "Failed to extract archive file: unsafe symlink entry {} -> {}",
+                    inner_file.name(),
+                    link_target
+                )); // B (2.b.) priekšlaicīgs return; B (2.a.) .name()
+            }
+            // Windows needs elevated privileges for symlinks, Blender builds there do not use them.
+            #[cfg(unix)]
+            if let Err(err) = std::os::unix::fs::symlink(&link_target, &outpath) {
+                // A (1.d.) if let Err(); B (2.a.) ::symlink()
+                return Err(format!("Failed to extract archive file: {:?}", err));
+                // B (2.b.) priekšlaicīgs return
+            }
+        } else if inner_file.is_dir() {
+            // C (3.b.) else; C (3.a.) inner_file.is_dir() == true; B (2.a.) .is_dir()
             match std::fs::create_dir_all(&outpath) {
                 // C (3.b.) match; B (2.a.) ::create_dir_all()
                 Ok(_) => {} // C (3.c.) Ok()
@@ -126,10 +194,6 @@ pub async fn extract_archive(
             }
         } else {
             // C (3.b.) else
-            if let Some(parent) = outpath.parent() {
-                // A (1.d.) if let Some(); B (2.a.) .parent()
-                let _ = std::fs::create_dir_all(parent); // A (1.a.) let _ =; B (2.a.) ::create_dir_all()
-            }
             let mut outfile = match std::fs::File::create(&outpath) {
                 // A (1.a.) let mut outfile =; C (3.b) match; B (2.a.) ::create()
                 Ok(val) => val, // C (3.c.) Ok()
@@ -140,74 +204,384 @@ pub async fn extract_archive(
                 return Err(format!("Failed to extract archive file: {:?}", err));
                 // B (2.b.) priekšlaicīgs return
             }
+            // Keep the executable bit on the Blender binary and its bundled tools.
+            #[cfg(unix)]
+            if let Some(mode) = inner_file.unix_mode() {
+                // A (1.d.) if let Some(); B (2.a.) .unix_mode()
+                use std::os::unix::fs::PermissionsExt;
+                let _ = std::fs::set_permissions(&outpath, std::fs::Permissions::from_mode(mode));
+                // A (1.a.) let _ =; B (2.a.) ::set_permissions(); B (2.a.) ::from_mode()
+            }
         }
     }
-    Ok(extract_dir.join(archive_dir)) // B (2.a.) .join()
+    Ok(top_level_entries)
 }
 
-/// ID: FSU_006
-/// ABC analīzes rezultāts:7,21,21
-pub fn archive_file(file_path: std::path::PathBuf) -> Result<std::path::PathBuf, String> {
-    let file_name = match file_path.file_name() {
-        // A (1.a.) let file_name =; C (3.b) match; B (2.a.) .file_name()
-        Some(val) => val,                                      // C (3.c) Some()
-        None => return Err(format!("Failed to archive file")), // C (3.c) None =>; B (2.b.) priekšlaicīgs return
-    };
-    let zip_path = file_path.with_extension("zip"); // A (1.a.) let zip_path =; B (2.a.) .with_extension()
-    let zip_file = match std::fs::File::create(&zip_path) {
-        // A (1.a.) let zip_file =; C (3.b.) match; B (2.a.) ::create()
+/// ID: FSU_017
+/// ABC analīzes rezultāts:11,26,17
+pub fn extract_tar_archive<R: Read>(
+    reader: R,
+    extract_dir: &std::path::Path,
+) -> Result<std::collections::BTreeSet<String>, String> {
+    let mut archive = tar::Archive::new(reader); // A (1.a.) let mut archive =; B (2.a.) ...::new()
+    let mut top_level_entries = std::collections::BTreeSet::new(); // A (1.a.) let mut top_level_entries =; B (2.a.) ...::new()
+    let entries = match archive.entries() {
+        // A (1.a.) let entries =; C (3.b.) match; B (2.a.) .entries()
         Ok(val) => val, // C (3.c.) Ok()
-        Err(err) => return Err(format!("Failed to archive file: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+        Err(err) => return Err(format!("Failed to extract archive file: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
     };
-    let mut zip_writer = zip::ZipWriter::new(zip_file); // A (1.a.) let mut zip_writer =; B (2.a.) ...::new()
-    let options: zip::write::FileOptions<()> = // A (1.a.) let options =;
-        zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Stored); // B (2.a.) ::default(); B (2.a.) .compression_method();
-    let mut buffer = Vec::new(); // A (1.a.) let mut buffer =; B (2.a.) ...::new()
-    let mut source_file = match std::fs::File::open(&file_path) {
-        // A (1.a.) let mut source_file =; C (3.b) match; B (2.a.) ::open()
-        Ok(val) => val, // C (3.c.) Ok()
-        Err(err) => return Err(format!("Failed to archive file: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    for entry in entries {
+        // A (1.a.) let entry =;
+        let mut entry = match entry {
+            // A (1.a.) let mut entry =; C (3.b.) match
+            Ok(val) => val, // C (3.c.) Ok()
+            Err(err) => return Err(format!("Failed to extract archive file: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+        };
+        let entry_path = match entry.path() {
+            // A (1.a.) let entry_path =; C (3.b.) match; B (2.a.) .path()
+            Ok(val) => get_enclosed_entry_path(&val)?, // C (3.c.) Ok(); B (2.a.) get_enclosed_entry_path()
+            Err(err) => return Err(format!("Failed to extract archive file: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+        };
+        if let Some(top_level_entry) = get_top_level_entry(&entry_path) {
+            // A (1.d.) if let Some(); B (2.a.) get_top_level_entry()
+            top_level_entries.insert(top_level_entry); // A (1.c.) .insert()
+        }
+        let entry_type = entry.header().entry_type(); // A (1.a.) let entry_type =; B (2.a.) .header(); B (2.a.) .entry_type()
+        if entry_type.is_symlink() || entry_type.is_hard_link() {
+            // C (3.a.) entry_type.is_symlink() == true; C (3.a.) entry_type.is_hard_link() == true; B (2.a.) .is_symlink(); B (2.a.) .is_hard_link()
+            let link_target = match entry.link_name() {
+                // A (1.a.) let link_target =; C (3.b.) match; B (2.a.) .link_name()
+                Ok(Some(val)) => val.to_path_buf(), // C (3.c.) Ok(Some()); B (2.a.) .to_path_buf()
+                Ok(None) => std::path::PathBuf::new(), // C (3.c.) Ok(None); B (2.a.) ...::new()
+                Err(err) => return Err(format!("Failed to extract archive file: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+            };
+            // Hard link targets are relative to the archive root, symlink targets to the entry.
+            let is_enclosed = if entry_type.is_hard_link() {
+                // A (1.a.) let is_enclosed =; C (3.a.) entry_type.is_hard_link() == true; B (2.a.) .is_hard_link()
+                get_enclosed_entry_path(&link_target).is_ok() // B (2.a.) get_enclosed_entry_path(); B (2.a.) .is_ok()
+            } else {
+                // C (3.b.) else
+                is_link_target_enclosed(&entry_path, &link_target) // B (2.a.) is_link_target_enclosed()
+            };
+            if !is_enclosed {
+                // C (3.a.) is_enclosed != true
+                return Err(format!(
+                    "Failed to extract archive file: unsafe link entry {} -> {}",
+                    entry_path.display(),
+                    link_target.display()
+                )); // B (2.b.) priekšlaicīgs return; B (2.a.) .display(); B (2.a.) .display()
+            }
+        }
+        create_enclosed_parent_directory(extract_dir, &entry_path)?; // B (2.a.) create_enclosed_parent_directory()
+        if let Err(err) = entry.unpack_in(extract_dir) {
+            // A (1.d.) if let Err(); B (2.a.) .unpack_in()
+            return Err(format!("Failed to extract archive file: {:?}", err)); // B (2.b.) priekšlaicīgs return
+        }
+    }
+    Ok(top_level_entries)
+}
+
+/// ID: FSU_018
+/// ABC analīzes rezultāts:1,3,5
+pub fn get_top_level_entry(entry_path: &std::path::Path) -> Option<String> {
+    for component in entry_path.components() {
+        // A (1.a.) let component =; B (2.a.) .components()
+        match component {
+            // C (3.b) match
+            std::path::Component::CurDir => continue, // C (3.c) CurDir =>; B (2.b.) continue
+            std::path::Component::Normal(name) => return Some(name.to_string_lossy().to_string()), // C (3.c) Normal(); B (2.b.) priekšlaicīgs return; B (2.a.) .to_string_loosy(); B (2.a.) .to_string();
+            _ => return None, // C (3.c) _ =>; B (2.b.) priekšlaicīgs return
+        }
+    }
+    None
+}
+
+/// ID: FSU_022
+/// ABC analīzes rezultāts:2,5,5
+pub fn get_enclosed_entry_path(entry_path: &std::path::Path) -> Result<std::path::PathBuf, String> {
+    let mut enclosed_entry_path = std::path::PathBuf::new(); // A (1.a.) let mut enclosed_entry_path =; B (2.a.) ...::new()
+    for component in entry_path.components() {
+        // A (1.a.) let component =; B (2.a.) .components()
+        match component {
+            // C (3.b) match
+            std::path::Component::Normal(name) => enclosed_entry_path.push(name), // C (3.c) Normal(); A (1.c.) .push()
+            std::path::Component::CurDir => {} // C (3.c) CurDir =>
+            // Absolute paths, drive prefixes and ".." would place the entry outside the extract directory.
+            _ => {
+                // C (3.c) _ =>
+                return Err(format!(
+                    "Failed to extract archive file: unsafe entry path {}",
+                    entry_path.display()
+                )); // B (2.b.) priekšlaicīgs return; B (2.a.) .display()
+            }
+        }
+    }
+    if enclosed_entry_path.as_os_str().is_empty() {
+        // C (3.a.) enclosed_entry_path.as_os_str().is_empty() == true; B (2.a.) .as_os_str(); B (2.a.) .is_empty()
+        return Err(format!(
+            "Failed to extract archive file: unsafe entry path {}",
+            entry_path.display()
+        )); // B (2.b.) priekšlaicīgs return; B (2.a.) .display()
+    }
+    Ok(enclosed_entry_path)
+}
+
+/// ID: FSU_023
+/// ABC analīzes rezultāts:2,3,7
+pub fn is_link_target_enclosed(
+    entry_path: &std::path::Path,
+    link_target: &std::path::Path,
+) -> bool {
+    // Depth below the extract directory of the directory that holds the link.
+    let mut depth = entry_path.components().count() as i64 - 1; // A (1.a.) let mut depth =; B (2.a.) .components(); B (2.a.) .count()
+    for component in link_target.components() {
+        // A (1.a.) let component =; B (2.a.) .components()
+        match component {
+            // C (3.b) match
+            std::path::Component::Normal(_) => depth += 1, // C (3.c) Normal(); A (1.b.) depth +=
+            std::path::Component::CurDir => {}             // C (3.c) CurDir =>
+            std::path::Component::ParentDir => {
+                // C (3.c) ParentDir =>
+                depth -= 1; // A (1.b.) depth -=
+                if depth < 0 {
+                    // C (3.a.) depth < 0
+                    return false; // B (2.b.) priekšlaicīgs return
+                }
+            }
+            _ => return false, // C (3.c) _ =>; B (2.b.) priekšlaicīgs return
+        }
+    }
+    !link_target.as_os_str().is_empty() // B (2.a.) .as_os_str(); B (2.a.) .is_empty()
+}
+
+/// ID: FSU_042
+/// ABC analīzes rezultāts:5,16,10
+pub fn create_enclosed_parent_directory(
+    extract_dir: &std::path::Path,
+    entry_path: &std::path::Path,
+) -> Result<(), String> {
+    let mut parent_directory_path = extract_dir.to_path_buf(); // A (1.a.) let mut parent_directory_path =; B (2.a.) .to_path_buf()
+    let entry_components: Vec<std::path::Component> = entry_path.components().collect(); // A (1.a.) let entry_components =; B (2.a.) .components(); B (2.a.) .collect()
+    for (index, component) in entry_components.iter().enumerate() {
+        // A (1.a.) let (index, component) =; B (2.a.) .iter(); B (2.a.) .enumerate()
+        let current_path = parent_directory_path.join(component); // A (1.a.) let current_path =; B (2.a.) .join()
+
+        // A symlink extracted earlier would redirect the entry, even when every link on its own
+        // stays inside, e.g. `top/a -> ..` followed by `top/a/b -> ..`.
+        if std::fs::symlink_metadata(&current_path).is_ok_and(|val| val.file_type().is_symlink()) {
+            // C (3.a.) .is_ok_and() == true; B (2.a.) ::symlink_metadata(); B (2.a.) .is_ok_and(); B (2.a.) .file_type(); B (2.a.) .is_symlink()
+            return Err(format!(
+                "Failed to extract archive file: entry {} goes through a symlink",
+                entry_path.display()
+            )); // B (2.b.) priekšlaicīgs return; B (2.a.) .display()
+        }
+        if index + 1 < entry_components.len() {
+            // C (3.a.) index + 1 < entry_components.len(); B (2.a.) .len()
+            parent_directory_path = current_path; // A (1.a.) parent_directory_path =
+        }
+    }
+    if let Err(err) = std::fs::create_dir_all(&parent_directory_path) {
+        // A (1.d.) if let Err(); B (2.a.) ::create_dir_all()
+        return Err(format!("Failed to extract archive file: {:?}", err)); // B (2.b.) priekšlaicīgs return
+    }
+    let canonical_paths = std::fs::canonicalize(extract_dir)
+        .and_then(|val| Ok((val, std::fs::canonicalize(&parent_directory_path)?))); // A (1.a.) let canonical_paths =; B (2.a.) ::canonicalize(); B (2.a.) .and_then(); B (2.a.) ::canonicalize()
+    match canonical_paths {
+        // C (3.b) match
+        Ok((extract_dir, parent_directory_path))
+            if parent_directory_path.starts_with(&extract_dir) =>
+        {
+            Ok(())
+        } // C (3.c.) Ok(); C (3.a.) .starts_with() == true; B (2.a.) .starts_with()
+        Ok(_) => Err(format!(
+            "Failed to extract archive file: entry {} is outside the extract directory",
+            entry_path.display()
+        )), // C (3.c.) Ok(); B (2.a.) .display()
+        Err(err) => Err(format!("Failed to extract archive file: {:?}", err)), // C (3.c) Err()
+    }
+}
+
+/// ID: FSU_024
+/// ABC analīzes rezultāts:4,9,4
+pub async fn install_archive(
+    archive_file_path: std::path::PathBuf,
+) -> Result<
+    (
+        std::path::PathBuf,
+        std::path::PathBuf,
+        Option<std::path::PathBuf>,
+    ),
+    String,
+> {
+    let install_dir = match archive_file_path.parent() {
+        // A (1.a.) let install_dir =; C (3.b) match; B (2.a.) .parent()
+        Some(val) => val.to_path_buf(), // C (3.c) Some(); B (2.a.) .to_path_buf()
+        None => return Err(format!("Failed to install archive file")), // C (3.c) None =>; B (2.b.) priekšlaicīgs return
+    };
+    // The leading dot keeps the staging directory out of installed version scans.
+    let staging_dir = install_dir.join(format!(".blendio-staging-{}", uuid::Uuid::new_v4())); // A (1.a.) let staging_dir =; B (2.a.) .join(); B (2.a.) uuid::Uuid::new_v4()
+    if let Err(err) = std::fs::create_dir_all(&staging_dir) {
+        // A (1.d.) if let Err(); B (2.a.) ::create_dir_all()
+        return Err(format!("Failed to install archive file: {:?}", err)); // B (2.b.) priekšlaicīgs return
+    }
+    // Whatever happens, nothing of the staging directory is kept.
+    let result = install_staged_archive(archive_file_path, &staging_dir, &install_dir).await; // A (1.a.) let result =; B (2.a.) install_staged_archive()
+    let _ = std::fs::remove_dir_all(&staging_dir); // A (1.a.) let _ =; B (2.a.) ::remove_dir_all()
+    result
+}
+
+/// ID: FSU_025
+/// ABC analīzes rezultāts:5,12,6
+pub async fn install_staged_archive(
+    archive_file_path: std::path::PathBuf,
+    staging_dir: &std::path::Path,
+    install_dir: &std::path::Path,
+) -> Result<
+    (
+        std::path::PathBuf,
+        std::path::PathBuf,
+        Option<std::path::PathBuf>,
+    ),
+    String,
+> {
+    let staged_installation_directory_path =
+        extract_archive(archive_file_path, staging_dir.to_path_buf()).await?; // A (1.a.) let staged_installation_directory_path =; B (2.a.) extract_archive(); B (2.a.) .to_path_buf()
+    let staged_executable_file_path =
+        match find_blender_executable(&staged_installation_directory_path) {
+            // A (1.a.) let staged_executable_file_path =; C (3.b) match; B (2.a.) find_blender_executable()
+            Some(val) => val, // C (3.c) Some()
+            None => {
+                // C (3.c) None =>;
+                return Err(format!(
+                    "Failed to install archive file: no Blender executable found in extracted files"
+                )); // B (2.b.) priekšlaicīgs return
+            }
+        };
+    let relative_executable_file_path =
+        staged_executable_file_path.strip_prefix(&staged_installation_directory_path); // A (1.a.) let relative_executable_file_path =; B (2.a.) .strip_prefix()
+    let relative_executable_file_path = match relative_executable_file_path {
+        // A (1.a.) let relative_executable_file_path =; C (3.b) match
+        Ok(val) => val.to_path_buf(), // C (3.c.) Ok(); B (2.a.) .to_path_buf()
+        Err(err) => return Err(format!("Failed to install archive file: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
     };
-    match source_file.read_to_end(&mut buffer) {
-        // C (3.b) match; B (2.a.) .read_to_end()
-        Ok(_) => {} // C (3.c.) Ok()
-        Err(err) => return Err(format!("Failed to archive file: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    let installation_directory_path = match staged_installation_directory_path.file_name() {
+        // A (1.a.) let installation_directory_path =; C (3.b) match; B (2.a.) .file_name()
+        Some(val) => install_dir.join(val), // C (3.c) Some(); B (2.a.) .join()
+        None => return Err(format!("Failed to install archive file")), // C (3.c) None =>; B (2.b.) priekšlaicīgs return
+    };
+    let backup_directory_path = replace_directory(
+        // A (1.a.) let backup_directory_path =; B (2.a.) replace_directory()
+        &staged_installation_directory_path,
+        &installation_directory_path,
+    )?;
+    let executable_file_path = installation_directory_path.join(relative_executable_file_path); // A (1.a.) let executable_file_path =; B (2.a.) .join()
+    Ok((
+        installation_directory_path,
+        executable_file_path,
+        backup_directory_path,
+    ))
+}
+
+/// ID: FSU_026
+/// ABC analīzes rezultāts:2,8,5
+pub fn replace_directory(
+    source_directory_path: &std::path::Path,
+    target_directory_path: &std::path::Path,
+) -> Result<Option<std::path::PathBuf>, String> {
+    if !target_directory_path.exists() {
+        // C (3.a.) target_directory_path.exists() != true; B (2.a.) .exists()
+        return match std::fs::rename(source_directory_path, target_directory_path) {
+            // C (3.b) match; B (2.a.) ::rename(); B (2.b.) priekšlaicīgs return
+            Ok(_) => Ok(None), // C (3.c.) Ok()
+            Err(err) => Err(format!("Failed to replace directory: {:?}", err)), // C (3.c) Err()
+        };
     }
-    match zip_writer.start_file(file_name.to_string_lossy().to_string(), options) {
-        // C (3.b) match; B (2.a.) .start_file(); B (2.a.) .to_string_loosy(); B (2.a.) .to_string();
-        Ok(_) => {} // C (3.c.) Ok()
-        Err(err) => return Err(format!("Failed to archive file: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    // Move the previous directory aside first so it can be restored if the swap fails.
+    let backup_directory_name = format!(".blendio-backup-{}", uuid::Uuid::new_v4()); // A (1.a.) let backup_directory_name =; B (2.a.) uuid::Uuid::new_v4()
+    let backup_directory_path = target_directory_path.with_file_name(backup_directory_name); // A (1.a.) let backup_directory_path =; B (2.a.) .with_file_name()
+    if let Err(err) = std::fs::rename(target_directory_path, &backup_directory_path) {
+        // A (1.d.) if let Err(); B (2.a.) ::rename()
+        return Err(format!("Failed to replace directory: {:?}", err)); // B (2.b.) priekšlaicīgs return
     }
-    match zip_writer.write_all(&buffer) {
-        // C (3.b) match; B (2.a.) .write_all()
-        Ok(_) => {} // C (3.c.) Ok()
-        Err(err) => return Err(format!("Failed to archive file: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    if let Err(err) = std::fs::rename(source_directory_path, target_directory_path) {
+        // A (1.d.) if let Err(); B (2.a.) ::rename()
+        let _ = std::fs::rename(&backup_directory_path, target_directory_path); // A (1.a.) let _ =; B (2.a.) ::rename()
+        return Err(format!("Failed to replace directory: {:?}", err)); // B (2.b.) priekšlaicīgs return
     }
-    match zip_writer.finish() {
-        // C (3.b) match; B (2.a.) .finish()
-        Ok(_) => {} // C (3.c.) Ok()
-        Err(err) => return Err(format!("Failed to archive file: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    // The caller removes the backup once the replacement is registered, or restores it.
+    Ok(Some(backup_directory_path))
+}
+
+/// ID: FSU_043
+/// ABC analīzes rezultāts:0,3,3
+pub fn restore_directory_backup(
+    backup_directory_path: &std::path::Path,
+    target_directory_path: &std::path::Path,
+) -> Result<(), String> {
+    let _ = std::fs::remove_dir_all(target_directory_path); // A (1.a.) let _ =; B (2.a.) ::remove_dir_all()
+    match std::fs::rename(backup_directory_path, target_directory_path) {
+        // C (3.b) match; B (2.a.) ::rename()
+        Ok(_) => Ok(()), // C (3.c.) Ok()
+        Err(err) => Err(format!("Failed to restore directory backup: {:?}", err)), // C (3.c) Err()
     }
-    Ok(zip_path)
 }
 
-/// ID: FSU_007
-/// ABC analīzes rezultāts:3,4,6
-pub fn launch_executable(
-    executable_file_path: std::path::PathBuf,
-    args: Option<Vec<String>>,
+/// ID: FSU_006
+/// ABC analīzes rezultāts:8,21,15
+pub fn archive_files(
+    zip_file_path: &std::path::Path,
+    files: &[(std::path::PathBuf, String)],
+    generated_files: &[(String, String)],
+    compression_method: zip::CompressionMethod,
 ) -> Result<(), String> {
-    let mut command = std::process::Command::new(executable_file_path); // A (1.a.) let mut command =; B (2.a.) ...::new()
-    let arguments = match args {
-        // A (1.a.) let arguments =; C (3.b) match
-        Some(val) => val, // C (3.c) Some()
-        None => vec![],   // C (3.c) None =>;
+    let zip_file = match std::fs::File::create(zip_file_path) {
+        // A (1.a.) let zip_file =; C (3.b.) match; B (2.a.) ::create()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => return Err(format!("Failed to archive files: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
     };
-    let output = command.args(arguments).output(); // A (1.a.) let output =; B (2.a.) .args(); B (2.a.) .output()
-    match output {
-        // C (3.b) match
+    let mut zip_writer = zip::ZipWriter::new(zip_file); // A (1.a.) let mut zip_writer =; B (2.a.) ...::new()
+    let options: zip::write::FileOptions<()> = // A (1.a.) let options =;
+        zip::write::FileOptions::default().compression_method(compression_method); // B (2.a.) ::default(); B (2.a.) .compression_method();
+    for (source_file_path, archive_file_name) in files {
+        // A (1.a.) let (source_file_path, archive_file_name) =;
+        let mut source_file = match std::fs::File::open(source_file_path) {
+            // A (1.a.) let mut source_file =; C (3.b) match; B (2.a.) ::open()
+            Ok(val) => val, // C (3.c.) Ok()
+            Err(err) => return Err(format!("Failed to archive files: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+        };
+        // Textures and caches can be larger than the 4 GiB a plain zip entry holds.
+        let is_large_file = source_file
+            .metadata()
+            .is_ok_and(|val| val.len() >= u32::MAX as u64); // A (1.a.) let is_large_file =; B (2.a.) .metadata(); B (2.a.) .is_ok_and(); B (2.a.) .len(); C (3.a.) val.len() >= u32::MAX
+        match zip_writer.start_file(archive_file_name, options.large_file(is_large_file)) {
+            // C (3.b) match; B (2.a.) .start_file(); B (2.a.) .large_file()
+            Ok(_) => {} // C (3.c.) Ok()
+            Err(err) => return Err(format!("Failed to archive files: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+        }
+        // Copied in chunks, files are never held in memory as a whole.
+        match std::io::copy(&mut source_file, &mut zip_writer) {
+            // C (3.b) match; B (2.a.) ::copy()
+            Ok(_) => {} // C (3.c.) Ok()
+            Err(err) => return Err(format!("Failed to archive files: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+        }
+    }
+    for (archive_file_name, content) in generated_files {
+        // A (1.a.) let (archive_file_name, content) =;
+        match zip_writer.start_file(archive_file_name, options) {
+            // C (3.b) match; B (2.a.) .start_file()
+            Ok(_) => {} // C (3.c.) Ok()
+            Err(err) => return Err(format!("Failed to archive files: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+        }
+        match zip_writer.write_all(content.as_bytes()) {
+            // C (3.b) match; B (2.a.) .write_all(); B (2.a.) .as_bytes()
+            Ok(_) => {} // C (3.c.) Ok()
+            Err(err) => return Err(format!("Failed to archive files: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+        }
+    }
+    match zip_writer.finish() {
+        // C (3.b) match; B (2.a.) .finish()
         Ok(_) => Ok(()), // C (3.c.) Ok()
-        Err(err) => return Err(format!("Failed to launch executable: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+        Err(err) => Err(format!("Failed to archive files: {:?}", err)), // C (3.c) Err()
     }
 }
 
@@ -298,3 +672,1544 @@ pub async fn delete_directory(directory_path: std::path::PathBuf) -> Result<(),
         Err(err) => return Err(format!("Failed to delete directory: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
     }
 }
+
+/// ID: FSU_013
+/// ABC analīzes rezultāts:1,3,3
+pub fn get_blender_executable_candidates() -> Vec<std::path::PathBuf> {
+    #[cfg(target_os = "windows")]
+    let candidates = vec![
+        // A (1.a.) let candidates =;
+        std::path::PathBuf::from("blender-launcher.exe"), // B (2.a.) ::from()
+        std::path::PathBuf::from("blender.exe"),          // B (2.a.) ::from()
+    ];
+    #[cfg(target_os = "macos")]
+    let candidates = vec![
+        // A (1.a.) let candidates =;
+        std::path::PathBuf::from("Blender.app") // B (2.a.) ::from()
+            .join("Contents") // B (2.a.) .join()
+            .join("MacOS") // B (2.a.) .join()
+            .join("Blender"), // B (2.a.) .join()
+    ];
+    #[cfg(target_os = "linux")]
+    let candidates = vec![std::path::PathBuf::from("blender")]; // A (1.a.) let candidates =; B (2.a.) ::from()
+    return candidates;
+}
+
+/// ID: FSU_014
+/// ABC analīzes rezultāts:2,5,2
+pub fn find_blender_executable(directory_path: &std::path::Path) -> Option<std::path::PathBuf> {
+    for candidate in get_blender_executable_candidates() {
+        // A (1.a.) let candidate =; B (2.a.) get_blender_executable_candidates()
+        let executable_file_path = directory_path.join(candidate); // A (1.a.) let executable_file_path =; B (2.a.) .join()
+        if executable_file_path.is_file() {
+            // C (3.a.) executable_file_path.is_file() == true; B (2.a.) .is_file()
+            return Some(executable_file_path); // B (2.b.) priekšlaicīgs return
+        }
+    }
+    None
+}
+
+/// ID: FSU_015
+/// ABC analīzes rezultāts:3,7,5
+pub fn get_installation_directory_path(
+    executable_file_path: &std::path::Path,
+) -> Option<std::path::PathBuf> {
+    // macOS executables live inside the app bundle, so the installation directory is the
+    // directory that contains Blender.app rather than Contents/MacOS.
+    for ancestor in executable_file_path.ancestors() {
+        // A (1.a.) let ancestor =; B (2.a.) .ancestors()
+        let is_app_bundle = ancestor // A (1.a.) let is_app_bundle =;
+            .extension() // B (2.a.) .extension()
+            .map(|ext| ext == "app") // B (2.a.) .map(); C (3.a.) ext == "app"
+            .unwrap_or(false); // B (2.a.) .unwrap_or()
+        if is_app_bundle {
+            // C (3.a.) is_app_bundle == true
+            return ancestor.parent().map(|val| val.to_path_buf()); // B (2.b.) priekšlaicīgs return; B (2.a.) .parent(); B (2.a.) .map(); B (2.a.) .to_path_buf()
+        }
+    }
+    executable_file_path.parent().map(|val| val.to_path_buf()) // B (2.a.) .parent(); B (2.a.) .map(); B (2.a.) .to_path_buf()
+}
+
+/// ID: FSU_019
+/// ABC analīzes rezultāts:6,10,8
+pub fn get_file_sha256_checksum(file_path: &std::path::Path) -> Result<String, String> {
+    let mut file = match std::fs::File::open(file_path) {
+        // A (1.a.) let mut file =; C (3.b.) match; B (2.a.) ::open()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => return Err(format!("Failed to calculate file checksum: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    };
+    // Hash in chunks, Blender archives are several hundred megabytes.
+    let mut hasher = Sha256::new(); // A (1.a.) let mut hasher =; B (2.a.) ...::new()
+    let mut buffer = vec![0u8; 1024 * 1024]; // A (1.a.) let mut buffer =;
+    loop {
+        let bytes_read = match file.read(&mut buffer) {
+            // A (1.a.) let bytes_read =; C (3.b.) match; B (2.a.) .read()
+            Ok(val) => val, // C (3.c.) Ok()
+            Err(err) => return Err(format!("Failed to calculate file checksum: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+        };
+        if bytes_read == 0 {
+            // C (3.a.) bytes_read == 0
+            break; // B (2.b.) break
+        }
+        hasher.update(&buffer[..bytes_read]); // A (1.c.) .update()
+    }
+    let checksum = hasher // A (1.a.) let checksum =;
+        .finalize() // B (2.a.) .finalize()
+        .iter() // B (2.a.) .iter()
+        .map(|byte| format!("{:02x}", byte)) // B (2.a.) .map()
+        .collect::<String>(); // B (2.a.) .collect()
+    Ok(checksum)
+}
+
+/// ID: FSU_047
+/// ABC analīzes rezultāts:2,6,6
+pub fn verify_file_sha256_checksum(
+    file_path: &std::path::Path,
+    expected_checksum: &str,
+) -> Result<(), String> {
+    let checksum = get_file_sha256_checksum(file_path)?; // A (1.a.) let checksum =; B (2.a.) get_file_sha256_checksum(); C (3.c.) ?
+    if checksum.eq_ignore_ascii_case(expected_checksum) {
+        // C (3.a.) checksum.eq_ignore_ascii_case() == true; B (2.a.) .eq_ignore_ascii_case()
+        return Ok(()); // B (2.b.) priekšlaicīgs return
+    }
+    // The file is corrupt or tampered with, never keep it around for a retry.
+    let _ = std::fs::remove_file(file_path); // A (1.a.) let _ =; B (2.a.) ::remove_file()
+    Err(format!(
+        "Downloaded archive file checksum mismatch: expected {}, got {}",
+        expected_checksum, checksum
+    ))
+}
+
+/// ID: FSU_020
+/// ABC analīzes rezultāts:21,52,27
+pub async fn download_file(
+    url: &str,
+    destination_file_path: &std::path::Path,
+    download_id: &str,
+    cancel_flag: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    mut on_progress: impl FnMut(DownloadProgress),
+) -> Result<std::path::PathBuf, String> {
+    // Unfinished downloads are kept next to the destination so they can be resumed later.
+    let part_file_path =
+        std::path::PathBuf::from(format!("{}.part", destination_file_path.to_string_lossy())); // A (1.a.) let part_file_path =; B (2.a.) ::from(); B (2.a.) .to_string_lossy()
+    let mut downloaded_bytes = match std::fs::metadata(&part_file_path) {
+        // A (1.a.) let mut downloaded_bytes =; C (3.b) match; B (2.a.) ::metadata()
+        Ok(val) => val.len(), // C (3.c.) Ok(); B (2.a.) .len()
+        Err(_) => 0,          // C (3.c) Err()
+    };
+    let mut request = reqwest::Client::new().get(url); // A (1.a.) let mut request =; B (2.a.) ...::new(); B (2.a.) .get()
+    if downloaded_bytes > 0 {
+        // C (3.a.) downloaded_bytes > 0
+        request = request.header(
+            reqwest::header::RANGE,
+            format!("bytes={}-", downloaded_bytes),
+        ); // A (1.a.) request =; B (2.a.) .header()
+    }
+    let mut response = match request.send().await {
+        // A (1.a.) let mut response =; C (3.b) match; B (2.a.) .send()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => return Err(format!("Failed to download file: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    };
+    let status = response.status(); // A (1.a.) let status =; B (2.a.) .status()
+    if status == reqwest::StatusCode::RANGE_NOT_SATISFIABLE && downloaded_bytes > 0 {
+        // C (3.a.) status == RANGE_NOT_SATISFIABLE; C (3.a.) downloaded_bytes > 0
+        // The part file already holds the whole file, the previous run stopped before renaming it.
+        if let Err(err) = tokio::fs::rename(&part_file_path, destination_file_path).await {
+            // A (1.d.) if let Err(); B (2.a.) ::rename()
+            return Err(format!("Failed to download file: {:?}", err)); // B (2.b.) priekšlaicīgs return
+        }
+        on_progress(get_download_progress(
+            // B (2.a.) on_progress(); B (2.a.) get_download_progress()
+            download_id,
+            downloaded_bytes,
+            Some(downloaded_bytes),
+            0,
+            std::time::Duration::ZERO,
+            true,
+        ));
+        return Ok(destination_file_path.to_path_buf()); // B (2.b.) priekšlaicīgs return; B (2.a.) .to_path_buf()
+    }
+    if !status.is_success() {
+        // C (3.a.) status.is_success() != true; B (2.a.) .is_success()
+        return Err(format!("Failed to download file: HTTP status {}", status)); // B (2.b.) priekšlaicīgs return
+    }
+    // Servers that ignore the Range header send the whole file again.
+    let is_resumed = status == reqwest::StatusCode::PARTIAL_CONTENT; // A (1.a.) let is_resumed =; C (3.a.) status == PARTIAL_CONTENT
+    if !is_resumed {
+        // C (3.a.) is_resumed != true
+        downloaded_bytes = 0; // A (1.a.) downloaded_bytes =
+    }
+    let total_bytes = response // A (1.a.) let total_bytes =;
+        .content_length() // B (2.a.) .content_length()
+        .map(|content_length| content_length + downloaded_bytes); // B (2.a.) .map()
+    let mut part_file = match tokio::fs::OpenOptions::new() // A (1.a.) let mut part_file =; C (3.b) match; B (2.a.) ...::new()
+        .create(true) // B (2.a.) .create()
+        .write(true) // B (2.a.) .write()
+        .append(is_resumed) // B (2.a.) .append()
+        .truncate(!is_resumed) // B (2.a.) .truncate()
+        .open(&part_file_path) // B (2.a.) .open()
+        .await
+    {
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => return Err(format!("Failed to download file: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    };
+    let session_start_bytes = downloaded_bytes; // A (1.a.) let session_start_bytes =;
+    let started = std::time::Instant::now(); // A (1.a.) let started =; B (2.a.) ::now()
+    let mut last_progress_emit: Option<std::time::Instant> = None; // A (1.a.) let mut last_progress_emit =;
+    loop {
+        if cancel_flag.load(std::sync::atomic::Ordering::SeqCst) {
+            // C (3.a.) cancel_flag.load() == true; B (2.a.) .load()
+            let _ = part_file.flush().await; // A (1.a.) let _ =; B (2.a.) .flush()
+            return Err(format!("Failed to download file: download cancelled")); // B (2.b.) priekšlaicīgs return
+        }
+        let chunk = match response.chunk().await {
+            // A (1.a.) let chunk =; C (3.b) match; B (2.a.) .chunk()
+            Ok(Some(val)) => val, // C (3.c.) Ok(Some())
+            Ok(None) => break,    // C (3.c.) Ok(None); B (2.b.) break
+            Err(err) => {
+                // C (3.c) Err()
+                let _ = part_file.flush().await; // A (1.a.) let _ =; B (2.a.) .flush()
+                return Err(format!("Failed to download file: {:?}", err)); // B (2.b.) priekšlaicīgs return
+            }
+        };
+        if let Err(err) = part_file.write_all(&chunk).await {
+            // A (1.d.) if let Err(); B (2.a.) .write_all()
+            return Err(format!("Failed to download file: {:?}", err)); // B (2.b.) priekšlaicīgs return
+        }
+        downloaded_bytes += chunk.len() as u64; // A (1.b.) downloaded_bytes +=; B (2.a.) .len()
+        let should_emit = match last_progress_emit {
+            // A (1.a.) let should_emit =; C (3.b) match
+            Some(val) => val.elapsed() >= std::time::Duration::from_millis(250), // C (3.c) Some(); C (3.a.) val.elapsed() >= 250ms; B (2.a.) .elapsed(); B (2.a.) ::from_millis()
+            None => true,                                                        // C (3.c) None =>
+        };
+        if should_emit {
+            // C (3.a.) should_emit == true
+            last_progress_emit = Some(std::time::Instant::now()); // A (1.a.) last_progress_emit =; B (2.a.) ::now()
+            on_progress(get_download_progress(
+                // B (2.a.) on_progress(); B (2.a.) get_download_progress()
+                download_id,
+                downloaded_bytes,
+                total_bytes,
+                downloaded_bytes - session_start_bytes,
+                started.elapsed(), // B (2.a.) .elapsed()
+                false,
+            ));
+        }
+    }
+    if let Err(err) = part_file.flush().await {
+        // A (1.d.) if let Err(); B (2.a.) .flush()
+        return Err(format!("Failed to download file: {:?}", err)); // B (2.b.) priekšlaicīgs return
+    }
+    drop(part_file); // B (2.a.) drop()
+    if let Err(err) = tokio::fs::rename(&part_file_path, destination_file_path).await {
+        // A (1.d.) if let Err(); B (2.a.) ::rename()
+        return Err(format!("Failed to download file: {:?}", err)); // B (2.b.) priekšlaicīgs return
+    }
+    on_progress(get_download_progress(
+        // B (2.a.) on_progress(); B (2.a.) get_download_progress()
+        download_id,
+        downloaded_bytes,
+        total_bytes,
+        downloaded_bytes - session_start_bytes,
+        started.elapsed(), // B (2.a.) .elapsed()
+        true,
+    ));
+    Ok(destination_file_path.to_path_buf()) // B (2.a.) .to_path_buf()
+}
+
+/// ID: FSU_021
+/// ABC analīzes rezultāts:3,4,4
+pub fn get_download_progress(
+    download_id: &str,
+    downloaded_bytes: u64,
+    total_bytes: Option<u64>,
+    session_bytes: u64,
+    elapsed: std::time::Duration,
+    is_finished: bool,
+) -> DownloadProgress {
+    let elapsed_seconds = elapsed.as_secs_f64(); // A (1.a.) let elapsed_seconds =; B (2.a.) .as_secs_f64()
+    let bytes_per_second = if elapsed_seconds > 0.0 {
+        // A (1.a.) let bytes_per_second =; C (3.a.) elapsed_seconds > 0.0
+        session_bytes as f64 / elapsed_seconds
+    } else {
+        // C (3.b.) else
+        0.0
+    };
+    let eta_seconds = match total_bytes {
+        // A (1.a.) let eta_seconds =; C (3.b) match
+        Some(total) if bytes_per_second > 0.0 => {
+            // C (3.c) Some(); C (3.a.) bytes_per_second > 0.0
+            Some(total.saturating_sub(downloaded_bytes) as f64 / bytes_per_second)
+            // B (2.a.) .saturating_sub()
+        }
+        _ => None, // C (3.c) _ =>
+    };
+    DownloadProgress {
+        download_id: download_id.to_string(), // B (2.a.) .to_string()
+        downloaded_bytes: downloaded_bytes,
+        total_bytes: total_bytes,
+        bytes_per_second: bytes_per_second,
+        eta_seconds: eta_seconds,
+        is_finished: is_finished,
+    }
+}
+
+/// ID: FSU_027
+/// ABC analīzes rezultāts:4,12,6
+pub async fn get_executable_output(
+    executable_file_path: &std::path::Path,
+    args: Vec<String>,
+    timeout: std::time::Duration,
+) -> Result<String, String> {
+    let mut command = tokio::process::Command::new(executable_file_path); // A (1.a.) let mut command =; B (2.a.) ...::new()
+    command
+        .args(args) // B (2.a.) .args()
+        .stdin(std::process::Stdio::null()) // B (2.a.) .stdin(); B (2.a.) ::null()
+        .stdout(std::process::Stdio::piped()) // B (2.a.) .stdout(); B (2.a.) ::piped()
+        .stderr(std::process::Stdio::null()) // B (2.a.) .stderr(); B (2.a.) ::null()
+        .kill_on_drop(true); // B (2.a.) .kill_on_drop()
+    let output = match tokio::time::timeout(timeout, command.output()).await {
+        // A (1.a.) let output =; C (3.b) match; B (2.a.) ::timeout(); B (2.a.) .output()
+        Ok(Ok(val)) => val, // C (3.c.) Ok()
+        Ok(Err(err)) => return Err(format!("Failed to run executable: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+        Err(_) => {
+            // C (3.c) Err()
+            return Err(format!(
+                "Failed to run executable: timed out after {} seconds",
+                timeout.as_secs() // B (2.a.) .as_secs()
+            )); // B (2.b.) priekšlaicīgs return
+        }
+    };
+    Ok(String::from_utf8_lossy(&output.stdout).to_string()) // B (2.a.) ::from_utf8_lossy(); B (2.a.) .to_string()
+}
+
+/// ID: FSU_028
+/// ABC analīzes rezultāts:6,12,8
+pub fn find_blender_executables_in_subdirectories(
+    directory_path: &std::path::Path,
+    name_prefix: &str,
+) -> Vec<std::path::PathBuf> {
+    let mut executable_file_paths = Vec::new(); // A (1.a.) let mut executable_file_paths =; B (2.a.) ...::new()
+    let directory_entries = match std::fs::read_dir(directory_path) {
+        // A (1.a.) let directory_entries =; C (3.b) match; B (2.a.) ::read_dir()
+        Ok(val) => val,                         // C (3.c.) Ok()
+        Err(_) => return executable_file_paths, // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    };
+    for entry in directory_entries.flatten() {
+        // A (1.a.) let entry =; B (2.a.) .flatten()
+        let name = entry.file_name().to_string_lossy().to_lowercase(); // A (1.a.) let name =; B (2.a.) .file_name(); B (2.a.) .to_string_lossy(); B (2.a.) .to_lowercase()
+        if !name.starts_with(name_prefix) || !entry.path().is_dir() {
+            // C (3.a.) name.starts_with() != true; C (3.a.) entry.path().is_dir() != true; B (2.a.) .starts_with(); B (2.a.) .path(); B (2.a.) .is_dir()
+            continue; // B (2.b.) continue
+        }
+        if let Some(val) = find_blender_executable(&entry.path()) {
+            // A (1.d.) if let Some(); B (2.a.) find_blender_executable(); B (2.a.) .path()
+            executable_file_paths.push(val); // A (1.c.) .push()
+        }
+    }
+    executable_file_paths
+}
+
+/// ID: FSU_029
+/// ABC analīzes rezultāts:3,9,5
+pub fn find_system_blender_executables() -> Vec<std::path::PathBuf> {
+    let mut executable_file_paths = Vec::new(); // A (1.a.) let mut executable_file_paths =; B (2.a.) ...::new()
+    #[cfg(target_os = "linux")]
+    {
+        for file_path in ["/usr/bin/blender", "/usr/local/bin/blender"] {
+            // A (1.a.) let file_path =;
+            let file_path = std::path::PathBuf::from(file_path); // A (1.a.) let file_path =; B (2.a.) ::from()
+            if file_path.is_file() {
+                // C (3.a.) file_path.is_file() == true; B (2.a.) .is_file()
+                executable_file_paths.push(file_path); // A (1.c.) .push()
+            }
+        }
+        executable_file_paths.extend(find_blender_executables_in_subdirectories(
+            // B (2.a.) .extend(); B (2.a.) find_blender_executables_in_subdirectories()
+            std::path::Path::new("/opt"), // B (2.a.) ::new()
+            "blender",
+        ));
+    }
+    #[cfg(target_os = "macos")]
+    {
+        if let Some(val) = find_blender_executable(std::path::Path::new("/Applications")) {
+            // A (1.d.) if let Some(); B (2.a.) find_blender_executable(); B (2.a.) ::new()
+            executable_file_paths.push(val); // A (1.c.) .push()
+        }
+    }
+    #[cfg(target_os = "windows")]
+    {
+        if let Ok(program_files) = std::env::var("ProgramFiles") {
+            // A (1.d.) if let Ok(); B (2.a.) ::var()
+            executable_file_paths.extend(find_blender_executables_in_subdirectories(
+                // B (2.a.) .extend(); B (2.a.) find_blender_executables_in_subdirectories()
+                &std::path::Path::new(&program_files).join("Blender Foundation"), // B (2.a.) ::new(); B (2.a.) .join()
+                "blender",
+            ));
+        }
+    }
+    executable_file_paths
+}
+
+/// ID: FSU_030
+/// ABC analīzes rezultāts:1,3,1
+pub fn find_local_blender_executables() -> Vec<std::path::PathBuf> {
+    match dirs::data_dir() {
+        // C (3.b) match; B (2.a.) ::data_dir()
+        Some(val) => find_blender_executables_in_subdirectories(&val, "blender"), // C (3.c) Some(); B (2.a.) find_blender_executables_in_subdirectories()
+        None => Vec::new(), // C (3.c) None =>; B (2.a.) ...::new()
+    }
+}
+
+/// ID: FSU_031
+/// ABC analīzes rezultāts:2,3,2
+pub fn find_snap_blender_executables() -> Vec<std::path::PathBuf> {
+    let file_path = std::path::PathBuf::from("/snap/bin/blender"); // A (1.a.) let file_path =; B (2.a.) ::from()
+    if file_path.is_file() {
+        // C (3.a.) file_path.is_file() == true; B (2.a.) .is_file()
+        return vec![file_path]; // B (2.b.) priekšlaicīgs return
+    }
+    Vec::new() // B (2.a.) ...::new()
+}
+
+/// ID: FSU_032
+/// ABC analīzes rezultāts:4,7,4
+pub fn find_flatpak_blender_executables() -> Vec<std::path::PathBuf> {
+    // Flatpak apps have no plain executable, the exported wrapper script runs `flatpak run`.
+    let mut export_directory_paths = vec![std::path::PathBuf::from("/var/lib/flatpak")]; // A (1.a.) let mut export_directory_paths =; B (2.a.) ::from()
+    if let Some(val) = dirs::data_dir() {
+        // A (1.d.) if let Some(); B (2.a.) ::data_dir()
+        export_directory_paths.push(val.join("flatpak")); // A (1.c.) .push(); B (2.a.) .join()
+    }
+    export_directory_paths
+        .into_iter() // B (2.a.) .into_iter()
+        .map(|val| val.join("exports").join("bin").join("org.blender.Blender")) // B (2.a.) .map(); B (2.a.) .join()
+        .filter(|val| val.is_file()) // B (2.a.) .filter(); C (3.a.) val.is_file() == true
+        .collect() // A (1.a.) .collect()
+}
+
+/// ID: FSU_033
+/// ABC analīzes rezultāts:5,10,7
+pub fn get_steam_library_directory_paths() -> Vec<std::path::PathBuf> {
+    let mut steam_directory_paths = Vec::new(); // A (1.a.) let mut steam_directory_paths =; B (2.a.) ...::new()
+    #[cfg(target_os = "windows")]
+    if let Ok(program_files) = std::env::var("ProgramFiles(x86)") {
+        // A (1.d.) if let Ok(); B (2.a.) ::var()
+        steam_directory_paths.push(std::path::Path::new(&program_files).join("Steam"));
+        // A (1.c.) .push(); B (2.a.) ::new(); B (2.a.) .join()
+    }
+    #[cfg(not(target_os = "windows"))]
+    if let Some(val) = dirs::data_dir() {
+        // A (1.d.) if let Some(); B (2.a.) ::data_dir()
+        steam_directory_paths.push(val.join("Steam")); // A (1.c.) .push(); B (2.a.) .join()
+    }
+    #[cfg(target_os = "linux")]
+    if let Some(val) = dirs::home_dir() {
+        // A (1.d.) if let Some(); B (2.a.) ::home_dir()
+        steam_directory_paths.push(val.join(".steam").join("steam")); // A (1.c.) .push(); B (2.a.) .join()
+    }
+    let mut library_directory_paths: Vec<std::path::PathBuf> = Vec::new(); // A (1.a.) let mut library_directory_paths =; B (2.a.) ...::new()
+    for steam_directory_path in steam_directory_paths {
+        // A (1.a.) let steam_directory_path =;
+        let mut candidates = vec![steam_directory_path.clone()]; // A (1.a.) let mut candidates =; B (2.a.) .clone()
+
+        // Additional library folders are listed as `"path" "<dir>"` pairs in libraryfolders.vdf.
+        let vdf_file_path = steam_directory_path
+            .join("steamapps")
+            .join("libraryfolders.vdf"); // A (1.a.) let vdf_file_path =; B (2.a.) .join()
+        if let Ok(contents) = std::fs::read_to_string(vdf_file_path) {
+            // A (1.d.) if let Ok(); B (2.a.) ::read_to_string()
+            for line in contents.lines() {
+                // A (1.a.) let line =; B (2.a.) .lines()
+                let parts: Vec<&str> = line.split('"').collect(); // A (1.a.) let parts =; B (2.a.) .split(); B (2.a.) .collect()
+                if parts.len() >= 4 && parts[1] == "path" {
+                    // C (3.a.) parts.len() >= 4; C (3.a.) parts[1] == "path"; B (2.a.) .len()
+                    candidates.push(std::path::PathBuf::from(parts[3].replace("\\\\", "\\")));
+                    // A (1.c.) .push(); B (2.a.) ::from(); B (2.a.) .replace()
+                }
+            }
+        }
+        for candidate in candidates {
+            // A (1.a.) let candidate =;
+            let canonical_path = std::fs::canonicalize(&candidate).unwrap_or(candidate); // A (1.a.) let canonical_path =; B (2.a.) ::canonicalize(); B (2.a.) .unwrap_or()
+            if canonical_path.is_dir() && !library_directory_paths.contains(&canonical_path) {
+                // C (3.a.) canonical_path.is_dir() == true; C (3.a.) library_directory_paths.contains() != true; B (2.a.) .is_dir(); B (2.a.) .contains()
+                library_directory_paths.push(canonical_path); // A (1.c.) .push()
+            }
+        }
+    }
+    library_directory_paths
+}
+
+/// ID: FSU_034
+/// ABC analīzes rezultāts:1,5,1
+pub fn find_steam_blender_executables() -> Vec<std::path::PathBuf> {
+    get_steam_library_directory_paths()
+        .into_iter() // B (2.a.) get_steam_library_directory_paths(); B (2.a.) .into_iter()
+        .filter_map(|val| {
+            // B (2.a.) .filter_map()
+            find_blender_executable(&val.join("steamapps").join("common").join("Blender"))
+            // B (2.a.) find_blender_executable(); B (2.a.) .join()
+        })
+        .collect() // A (1.a.) .collect()
+}
+
+/// ID: FSU_035
+/// ABC analīzes rezultāts:4,9,6
+pub fn get_glob_set(patterns: &str) -> Result<GlobSet, String> {
+    let mut builder = GlobSetBuilder::new(); // A (1.a.) let mut builder =; B (2.a.) ...::new()
+    for pattern in patterns.split(';').map(|val| val.trim()) {
+        // A (1.a.) let pattern =; B (2.a.) .split(); B (2.a.) .map(); B (2.a.) .trim()
+        if pattern.is_empty() {
+            // C (3.a.) pattern.is_empty() == true; B (2.a.) .is_empty()
+            continue; // B (2.b.) continue
+        }
+        match Glob::new(pattern) {
+            // C (3.b) match; B (2.a.) ...::new()
+            Ok(val) => builder.add(val), // C (3.c.) Ok(); B (2.a.) .add()
+            Err(err) => return Err(format!("Failed to parse glob pattern: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+        };
+    }
+    match builder.build() {
+        // C (3.b) match; B (2.a.) .build()
+        Ok(val) => Ok(val), // C (3.c.) Ok()
+        Err(err) => Err(format!("Failed to parse glob pattern: {:?}", err)), // C (3.c) Err()
+    }
+}
+
+/// ID: FSU_036
+/// ABC analīzes rezultāts:14,27,18
+pub fn find_blender_executables_recursively(
+    directory_path: &std::path::Path,
+    scan_depth: i64,
+    include_patterns: &GlobSet,
+    exclude_patterns: &GlobSet,
+) -> Result<Vec<std::path::PathBuf>, String> {
+    let mut executable_file_paths = Vec::new(); // A (1.a.) let mut executable_file_paths =; B (2.a.) ...::new()
+    let mut visited_directory_paths = std::collections::HashSet::new(); // A (1.a.) let mut visited_directory_paths =; B (2.a.) ...::new()
+    let mut pending_directories = vec![(directory_path.to_path_buf(), 0)]; // A (1.a.) let mut pending_directories =; B (2.a.) .to_path_buf()
+    while let Some((current_directory_path, depth)) = pending_directories.pop() {
+        // A (1.d.) while let Some(); B (2.a.) .pop()
+        // Symlinked directories are followed, canonical paths keep loops from being walked twice.
+        let canonical_path = match std::fs::canonicalize(&current_directory_path) {
+            // A (1.a.) let canonical_path =; C (3.b) match; B (2.a.) ::canonicalize()
+            Ok(val) => val,     // C (3.c.) Ok()
+            Err(_) => continue, // C (3.c) Err(); B (2.b.) continue
+        };
+        if !visited_directory_paths.insert(canonical_path) {
+            // C (3.a.) visited_directory_paths.insert() != true; B (2.a.) .insert()
+            continue; // B (2.b.) continue
+        }
+        let directory_entries = match std::fs::read_dir(&current_directory_path) {
+            // A (1.a.) let directory_entries =; C (3.b) match; B (2.a.) ::read_dir()
+            Ok(val) => val, // C (3.c.) Ok()
+            Err(err) if depth == 0 => {
+                // C (3.c) Err(); C (3.a.) depth == 0
+                return Err(format!("Failed to read directory: {:?}", err)); // B (2.b.) priekšlaicīgs return
+            }
+            Err(_) => continue, // C (3.c) Err(); B (2.b.) continue
+        };
+        for entry in directory_entries.flatten() {
+            // A (1.a.) let entry =; B (2.a.) .flatten()
+            let entry_path = entry.path(); // A (1.a.) let entry_path =; B (2.a.) .path()
+            let is_hidden = entry.file_name().to_string_lossy().starts_with('.'); // A (1.a.) let is_hidden =; B (2.a.) .file_name(); B (2.a.) .to_string_lossy(); B (2.a.) .starts_with()
+            if is_hidden || !entry_path.is_dir() {
+                // C (3.a.) is_hidden == true; C (3.a.) entry_path.is_dir() != true; B (2.a.) .is_dir()
+                continue; // B (2.b.) continue
+            }
+            let relative_path = match entry_path.strip_prefix(directory_path) {
+                // A (1.a.) let relative_path =; C (3.b) match; B (2.a.) .strip_prefix()
+                Ok(val) => val.to_string_lossy().replace('\\', "/"), // C (3.c.) Ok(); B (2.a.) .to_string_lossy(); B (2.a.) .replace()
+                Err(_) => continue, // C (3.c) Err(); B (2.b.) continue
+            };
+            if exclude_patterns.is_match(&relative_path) {
+                // C (3.a.) exclude_patterns.is_match() == true; B (2.a.) .is_match()
+                continue; // B (2.b.) continue
+            }
+            if let Some(val) = find_blender_executable(&entry_path) {
+                // A (1.d.) if let Some(); B (2.a.) find_blender_executable()
+                if include_patterns.is_empty() || include_patterns.is_match(&relative_path) {
+                    // C (3.a.) include_patterns.is_empty() == true; C (3.a.) include_patterns.is_match() == true; B (2.a.) .is_empty(); B (2.a.) .is_match()
+                    executable_file_paths.push(val); // A (1.c.) .push()
+                }
+                continue; // B (2.b.) continue
+            }
+            if depth + 1 < scan_depth {
+                // C (3.a.) depth + 1 < scan_depth
+                pending_directories.push((entry_path, depth + 1)); // A (1.c.) .push()
+            }
+        }
+    }
+    Ok(executable_file_paths)
+}
+
+/// ID: FSU_045
+/// ABC analīzes rezultāts:6,20,10
+pub fn find_blender_executable_for_changed_path(
+    repo_directory_path: &std::path::Path,
+    changed_path: &std::path::Path,
+    scan_depth: i64,
+    include_patterns: &GlobSet,
+    exclude_patterns: &GlobSet,
+) -> Option<std::path::PathBuf> {
+    let relative_path = changed_path.strip_prefix(repo_directory_path).ok()?; // A (1.a.) let relative_path =; B (2.a.) .strip_prefix(); B (2.a.) .ok()
+    let mut directory_path = repo_directory_path.to_path_buf(); // A (1.a.) let mut directory_path =; B (2.a.) .to_path_buf()
+
+    // Walks down the changed path with the rules of `find_blender_executables_recursively`, the
+    // first directory holding an executable is the installation the change belongs to.
+    for (depth, component) in relative_path.components().enumerate() {
+        // A (1.a.) let (depth, component) =; B (2.a.) .components(); B (2.a.) .enumerate()
+        if depth > 0 && depth as i64 >= scan_depth {
+            // C (3.a.) depth > 0; C (3.a.) depth >= scan_depth
+            return None; // B (2.b.) priekšlaicīgs return
+        }
+        directory_path.push(component); // A (1.c.) .push()
+        let is_hidden = component.as_os_str().to_string_lossy().starts_with('.'); // A (1.a.) let is_hidden =; B (2.a.) .as_os_str(); B (2.a.) .to_string_lossy(); B (2.a.) .starts_with()
+        if is_hidden || !directory_path.is_dir() {
+            // C (3.a.) is_hidden == true; C (3.a.) directory_path.is_dir() != true; B (2.a.) .is_dir()
+            return None; // B (2.b.) priekšlaicīgs return
+        }
+        let relative_directory_path = relative_path
+            .components()
+            .take(depth + 1)
+            .map(|val| val.as_os_str().to_string_lossy().to_string())
+            .collect::<Vec<String>>()
+            .join("/"); // A (1.a.) let relative_directory_path =; B (2.a.) .components(); B (2.a.) .take(); B (2.a.) .map(); B (2.a.) .as_os_str(); B (2.a.) .to_string_lossy(); B (2.a.) .to_string(); B (2.a.) .collect(); B (2.a.) .join()
+        if exclude_patterns.is_match(&relative_directory_path) {
+            // C (3.a.) exclude_patterns.is_match() == true; B (2.a.) .is_match()
+            return None; // B (2.b.) priekšlaicīgs return
+        }
+        if let Some(val) = find_blender_executable(&directory_path) {
+            // A (1.d.) if let Some(); B (2.a.) find_blender_executable()
+            return match include_patterns.is_empty()
+                || include_patterns.is_match(&relative_directory_path)
+            {
+                // C (3.b) match; C (3.a.) include_patterns.is_empty() == true; C (3.a.) include_patterns.is_match() == true; B (2.a.) .is_empty(); B (2.a.) .is_match(); B (2.b.) priekšlaicīgs return
+                true => Some(val), // C (3.c) true =>
+                false => None,     // C (3.c) false =>
+            };
+        }
+    }
+    None
+}
+
+/// ID: FSU_046
+/// ABC analīzes rezultāts:0,7,4
+pub fn is_package_owned_path(file_path: &std::path::Path) -> bool {
+    // Each query exits with 0 only when an installed package owns the file, a package manager
+    // that isn't installed fails to start and the next one is asked.
+    [("dpkg-query", "-S"), ("rpm", "-qf"), ("pacman", "-Qo")]
+        .iter()
+        .any(|(program, flag)| {
+            std::process::Command::new(program)
+                .arg(flag)
+                .arg(file_path)
+                .stdout(std::process::Stdio::null())
+                .stderr(std::process::Stdio::null())
+                .status()
+                .is_ok_and(|val| val.success()) // B (2.a.) ::new(); B (2.a.) .arg(); B (2.a.) .arg(); B (2.a.) .stdout(); B (2.a.) .stderr(); B (2.a.) .status(); B (2.a.) .is_ok_and(); C (3.a.) .success() == true
+        }) // B (2.a.) .iter(); B (2.a.) .any()
+}
+
+/// ID: FSU_037
+/// ABC analīzes rezultāts:2,5,4
+pub fn get_launch_log_directory_path() -> Result<std::path::PathBuf, String> {
+    let log_directory_path = match dirs::data_dir() {
+        // A (1.a.) let log_directory_path =; C (3.b) match; B (2.a.) ::data_dir()
+        Some(val) => val.join("com.bakalaurs.blendio-tauri").join("logs"), // C (3.c) Some(); B (2.a.) .join()
+        None => return Err(format!("Failed to get data directory")), // C (3.c) None =>; B (2.b.) priekšlaicīgs return
+    };
+    match std::fs::create_dir_all(&log_directory_path) {
+        // C (3.b) match; B (2.a.) ::create_dir_all()
+        Ok(_) => Ok(log_directory_path), // C (3.c.) Ok()
+        Err(err) => Err(format!("Failed to create log directory: {:?}", err)), // C (3.c) Err()
+    }
+}
+
+/// ID: FSU_038
+/// ABC analīzes rezultāts:2,5,4
+pub fn get_python_script_snapshot_directory_path() -> Result<std::path::PathBuf, String> {
+    let snapshot_directory_path = match dirs::data_dir() {
+        // A (1.a.) let snapshot_directory_path =; C (3.b) match; B (2.a.) ::data_dir()
+        Some(val) => val
+            .join("com.bakalaurs.blendio-tauri")
+            .join("script_snapshots"), // C (3.c) Some(); B (2.a.) .join()
+        None => return Err(format!("Failed to get data directory")), // C (3.c) None =>; B (2.b.) priekšlaicīgs return
+    };
+    match std::fs::create_dir_all(&snapshot_directory_path) {
+        // C (3.b) match; B (2.a.) ::create_dir_all()
+        Ok(_) => Ok(snapshot_directory_path), // C (3.c.) Ok()
+        Err(err) => Err(format!("Failed to create snapshot directory: {:?}", err)), // C (3.c) Err()
+    }
+}
+
+/// ID: FSU_039
+/// ABC analīzes rezultāts:7,17,11
+pub fn find_python_scripts_recursively(
+    directory_path: &std::path::Path,
+) -> Result<Vec<std::path::PathBuf>, String> {
+    let mut script_file_paths = Vec::new(); // A (1.a.) let mut script_file_paths =; B (2.a.) ...::new()
+    let mut pending_directories = vec![directory_path.to_path_buf()]; // A (1.a.) let mut pending_directories =; B (2.a.) .to_path_buf()
+    while let Some(current_directory_path) = pending_directories.pop() {
+        // A (1.d.) while let Some(); B (2.a.) .pop()
+        let directory_entries = match std::fs::read_dir(&current_directory_path) {
+            // A (1.a.) let directory_entries =; C (3.b) match; B (2.a.) ::read_dir()
+            Ok(val) => val, // C (3.c.) Ok()
+            Err(err) if current_directory_path == directory_path => {
+                // C (3.c) Err(); C (3.a.) current_directory_path == directory_path
+                return Err(format!("Failed to read directory: {:?}", err)); // B (2.b.) priekšlaicīgs return
+            }
+            Err(_) => continue, // C (3.c) Err(); B (2.b.) continue
+        };
+        for entry in directory_entries.flatten() {
+            // A (1.a.) let entry =; B (2.a.) .flatten()
+            let entry_path = entry.path(); // A (1.a.) let entry_path =; B (2.a.) .path()
+            let file_name = entry.file_name().to_string_lossy().to_string(); // A (1.a.) let file_name =; B (2.a.) .file_name(); B (2.a.) .to_string_lossy(); B (2.a.) .to_string()
+
+            // Symlinks are not followed so a link back to a parent can't make the scan loop.
+            let file_type = match entry.file_type() {
+                // A (1.a.) let file_type =; C (3.b) match; B (2.a.) .file_type()
+                Ok(val) => val,     // C (3.c.) Ok()
+                Err(_) => continue, // C (3.c) Err(); B (2.b.) continue
+            };
+            if file_name.starts_with('.') || file_name == "__pycache__" {
+                // C (3.a.) .starts_with() == true; C (3.a.) file_name == "__pycache__"; B (2.a.) .starts_with()
+                continue; // B (2.b.) continue
+            }
+            if file_type.is_dir() {
+                // C (3.a.) file_type.is_dir() == true; B (2.a.) .is_dir()
+                pending_directories.push(entry_path); // A (1.c.) .push()
+            } else if file_type.is_file()
+                && entry_path
+                    .extension()
+                    .map(|val| val == "py")
+                    .unwrap_or(false)
+            {
+                // C (3.a.) .is_file() == true; C (3.a.) val == "py"; B (2.a.) .is_file(); B (2.a.) .extension(); B (2.a.) .map(); B (2.a.) .unwrap_or()
+                script_file_paths.push(entry_path); // A (1.c.) .push()
+            }
+        }
+    }
+    script_file_paths.sort(); // A (1.c.) .sort()
+    Ok(script_file_paths)
+}
+
+/// ID: FSU_040
+/// ABC analīzes rezultāts:2,5,4
+pub fn get_thumbnail_cache_directory_path() -> Result<std::path::PathBuf, String> {
+    let thumbnail_directory_path = match dirs::data_dir() {
+        // A (1.a.) let thumbnail_directory_path =; C (3.b) match; B (2.a.) ::data_dir()
+        Some(val) => val.join("com.bakalaurs.blendio-tauri").join("thumbnails"), // C (3.c) Some(); B (2.a.) .join()
+        None => return Err(format!("Failed to get data directory")), // C (3.c) None =>; B (2.b.) priekšlaicīgs return
+    };
+    match std::fs::create_dir_all(&thumbnail_directory_path) {
+        // C (3.b) match; B (2.a.) ::create_dir_all()
+        Ok(_) => Ok(thumbnail_directory_path), // C (3.c.) Ok()
+        Err(err) => Err(format!("Failed to create thumbnail directory: {:?}", err)), // C (3.c) Err()
+    }
+}
+
+/// ID: FSU_041
+/// ABC analīzes rezultāts:3,8,3
+pub async fn get_save_file_path_from_file_explorer(
+    app: AppHandle,
+    file_name: String,
+) -> Result<Option<std::path::PathBuf>, String> {
+    let file_path_option = app // A (1.a.) let file_path_option =;
+        .dialog() // B (2.a.) .dialog()
+        .file() // B (2.a.) .file()
+        .add_filter("Zip Archives", &["zip"]) // B (2.a.) .add_filter()
+        .set_file_name(file_name) // B (2.a.) .set_file_name()
+        .blocking_save_file(); // B (2.a.) .blocking_save_file()
+    let file_path_string = match file_path_option {
+        // A (1.a.) let file_path_string =; C (3.b) match
+        Some(val) => val.to_string(), // C (3.c) Some(); B (2.a.) .to_string()
+        None => return Err(format!("Failed to get save file path from file explorer")), // C (3.c) None =>; B (2.b.) priekšlaicīgs return
+    };
+    let file_path = std::path::PathBuf::from(file_path_string); // A (1.a.) let file_path =; B (2.a.) ::from()
+    return Ok(Some(file_path));
+}
+
+/// ID: FSU_044
+/// ABC analīzes rezultāts:0,3,2
+pub fn is_plain_file_name(file_name: &str) -> bool {
+    // Separators, `..` and absolute paths all change what `file_name()` returns.
+    std::path::Path::new(file_name).file_name() == Some(std::ffi::OsStr::new(file_name))
+    // C (3.a.) .file_name() == Some(); B (2.a.) ::new(); B (2.a.) .file_name(); B (2.a.) ::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_directory() -> std::path::PathBuf {
+        let directory_path =
+            std::env::temp_dir().join(format!("blendio-test-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(directory_path.join("extract")).unwrap();
+        directory_path
+    }
+
+    fn create_test_zip(
+        zip_file_path: &std::path::Path,
+        entries: &[(&str, Option<&str>)],
+    ) -> std::fs::File {
+        let mut zip_writer = zip::ZipWriter::new(std::fs::File::create(zip_file_path).unwrap());
+        let options: zip::write::FileOptions<()> = zip::write::FileOptions::default();
+        for (name, link_target) in entries {
+            match link_target {
+                Some(val) => zip_writer.add_symlink(*name, *val, options).unwrap(),
+                None => {
+                    zip_writer.start_file(*name, options).unwrap();
+                    zip_writer.write_all(b"payload").unwrap();
+                }
+            }
+        }
+        zip_writer.finish().unwrap();
+        std::fs::File::open(zip_file_path).unwrap()
+    }
+
+    fn create_test_tar(entries: &[(&str, tar::EntryType, &str, u32)]) -> Vec<u8> {
+        let mut tar_builder = tar::Builder::new(Vec::new());
+        for (name, entry_type, content, mode) in entries {
+            // Names are written into the header directly, `set_path` refuses the unsafe ones.
+            let mut header = tar::Header::new_gnu();
+            header.as_gnu_mut().unwrap().name[..name.len()].copy_from_slice(name.as_bytes());
+            header.set_entry_type(*entry_type);
+            header.set_mode(*mode);
+            let data = if entry_type.is_symlink() || entry_type.is_hard_link() {
+                header.as_gnu_mut().unwrap().linkname[..content.len()]
+                    .copy_from_slice(content.as_bytes());
+                &[][..]
+            } else {
+                content.as_bytes()
+            };
+            header.set_size(data.len() as u64);
+            header.set_cksum();
+            tar_builder.append(&header, data).unwrap();
+        }
+        tar_builder.into_inner().unwrap()
+    }
+
+    fn create_test_compressed_tar(archive_file_path: &std::path::Path, tar_data: &[u8]) {
+        let file = std::fs::File::create(archive_file_path).unwrap();
+        let file_name = archive_file_path.to_string_lossy().to_string();
+        if file_name.ends_with(".tar.xz") {
+            let mut encoder = xz2::write::XzEncoder::new(file, 6);
+            encoder.write_all(tar_data).unwrap();
+            encoder.finish().unwrap();
+        } else if file_name.ends_with(".tar.gz") {
+            let mut encoder = flate2::write::GzEncoder::new(file, flate2::Compression::default());
+            encoder.write_all(tar_data).unwrap();
+            encoder.finish().unwrap();
+        } else {
+            let mut encoder = bzip2::write::BzEncoder::new(file, bzip2::Compression::default());
+            encoder.write_all(tar_data).unwrap();
+            encoder.finish().unwrap();
+        }
+    }
+
+    #[test]
+    fn extract_zip_archive_keeps_regular_entries() {
+        let directory_path = create_test_directory();
+        let file = create_test_zip(
+            &directory_path.join("test.zip"),
+            &[
+                ("blender/blender", None),
+                ("blender/lib/link", Some("../blender")),
+            ],
+        );
+        let top_level_entries = extract_zip_archive(file, &directory_path.join("extract")).unwrap();
+        assert_eq!(
+            top_level_entries.into_iter().collect::<Vec<_>>(),
+            vec!["blender"]
+        );
+        assert!(directory_path.join("extract/blender/blender").is_file());
+        std::fs::remove_dir_all(directory_path).unwrap();
+    }
+
+    #[test]
+    fn extract_zip_archive_rejects_parent_directory_paths() {
+        let directory_path = create_test_directory();
+        let file = create_test_zip(
+            &directory_path.join("test.zip"),
+            &[("top/../../evil", None)],
+        );
+        assert!(extract_zip_archive(file, &directory_path.join("extract")).is_err());
+        assert!(!directory_path.join("evil").exists());
+        std::fs::remove_dir_all(directory_path).unwrap();
+    }
+
+    #[test]
+    fn extract_zip_archive_rejects_absolute_paths() {
+        let directory_path = create_test_directory();
+        let absolute_path = directory_path.join("evil"); // Stays inside the test directory if written.
+        let file = create_test_zip(
+            &directory_path.join("test.zip"),
+            &[(absolute_path.to_str().unwrap(), None)],
+        );
+        assert!(extract_zip_archive(file, &directory_path.join("extract")).is_err());
+        assert!(!absolute_path.exists());
+        std::fs::remove_dir_all(directory_path).unwrap();
+    }
+
+    #[test]
+    fn extract_zip_archive_rejects_escaping_symlinks() {
+        let directory_path = create_test_directory();
+        let file = create_test_zip(
+            &directory_path.join("test.zip"),
+            &[("top/link", Some("../.."))],
+        );
+        assert!(extract_zip_archive(file, &directory_path.join("extract")).is_err());
+        std::fs::remove_dir_all(directory_path).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn extract_zip_archive_rejects_symlink_chains() {
+        let directory_path = create_test_directory();
+        let file = create_test_zip(
+            &directory_path.join("test.zip"),
+            &[
+                ("top/a", Some("..")),
+                ("top/a/b", Some("..")),
+                ("top/a/b/evil", None),
+            ],
+        );
+        assert!(extract_zip_archive(file, &directory_path.join("extract")).is_err());
+        assert!(!directory_path.join("evil").exists());
+        assert!(!directory_path.join("extract/evil").exists());
+        std::fs::remove_dir_all(directory_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn extract_archive_round_trips_tar_archives() {
+        let tar_data = create_test_tar(&[
+            (
+                "blender-4.2.3-linux-x64/",
+                tar::EntryType::Directory,
+                "",
+                0o755,
+            ),
+            (
+                "blender-4.2.3-linux-x64/blender",
+                tar::EntryType::Regular,
+                "binary",
+                0o755,
+            ),
+            (
+                "blender-4.2.3-linux-x64/4.2/scripts/startup.py",
+                tar::EntryType::Regular,
+                "import bpy",
+                0o644,
+            ),
+            (
+                "blender-4.2.3-linux-x64/lib/link",
+                tar::EntryType::Symlink,
+                "../blender",
+                0o777,
+            ),
+        ]);
+        for archive_file_name in ["test.tar.xz", "test.tar.gz", "test.tar.bz2"] {
+            let directory_path = create_test_directory();
+            let archive_file_path = directory_path.join(archive_file_name);
+            create_test_compressed_tar(&archive_file_path, &tar_data);
+            let archive_dir = extract_archive(archive_file_path, directory_path.join("extract"))
+                .await
+                .unwrap();
+            assert_eq!(
+                archive_dir,
+                directory_path.join("extract/blender-4.2.3-linux-x64")
+            );
+            assert_eq!(
+                std::fs::read_to_string(archive_dir.join("blender")).unwrap(),
+                "binary"
+            );
+            assert_eq!(
+                std::fs::read_to_string(archive_dir.join("4.2/scripts/startup.py")).unwrap(),
+                "import bpy"
+            );
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                let get_mode = |path: std::path::PathBuf| {
+                    std::fs::metadata(path).unwrap().permissions().mode() & 0o111
+                };
+                assert_eq!(get_mode(archive_dir.join("blender")), 0o111);
+                assert_eq!(get_mode(archive_dir.join("4.2/scripts/startup.py")), 0);
+                assert_eq!(
+                    std::fs::read_link(archive_dir.join("lib/link")).unwrap(),
+                    std::path::Path::new("../blender")
+                );
+            }
+            std::fs::remove_dir_all(directory_path).unwrap();
+        }
+    }
+
+    #[tokio::test]
+    async fn extract_archive_requires_single_top_level_directory() {
+        let directory_path = create_test_directory();
+        let archive_file_path = directory_path.join("test.tar.gz");
+        create_test_compressed_tar(
+            &archive_file_path,
+            &create_test_tar(&[
+                ("first/blender", tar::EntryType::Regular, "binary", 0o755),
+                ("second/blender", tar::EntryType::Regular, "binary", 0o755),
+            ]),
+        );
+        assert!(
+            extract_archive(archive_file_path, directory_path.join("extract"))
+                .await
+                .is_err()
+        );
+
+        let archive_file_path = directory_path.join("test.tar.bz2");
+        create_test_compressed_tar(
+            &archive_file_path,
+            &create_test_tar(&[(
+                "./blender/blender",
+                tar::EntryType::Regular,
+                "binary",
+                0o755,
+            )]),
+        );
+        let archive_dir = extract_archive(archive_file_path, directory_path.join("extract"))
+            .await
+            .unwrap();
+        assert_eq!(archive_dir, directory_path.join("extract/blender"));
+
+        let archive_file_path = directory_path.join("test.tar.xz");
+        create_test_compressed_tar(&archive_file_path, &create_test_tar(&[]));
+        assert!(
+            extract_archive(archive_file_path, directory_path.join("extract"))
+                .await
+                .is_err()
+        );
+        std::fs::remove_dir_all(directory_path).unwrap();
+    }
+
+    #[test]
+    fn extract_tar_archive_rejects_parent_directory_paths() {
+        let directory_path = create_test_directory();
+        let tar_data = create_test_tar(&[("top/../../evil", tar::EntryType::Regular, "", 0o644)]);
+        assert!(extract_tar_archive(&tar_data[..], &directory_path.join("extract")).is_err());
+        assert!(!directory_path.join("evil").exists());
+        std::fs::remove_dir_all(directory_path).unwrap();
+    }
+
+    #[test]
+    fn extract_tar_archive_rejects_absolute_paths() {
+        let directory_path = create_test_directory();
+        let absolute_path = directory_path.join("evil"); // Stays inside the test directory if written.
+        let tar_data = create_test_tar(&[(
+            absolute_path.to_str().unwrap(),
+            tar::EntryType::Regular,
+            "",
+            0o644,
+        )]);
+        assert!(extract_tar_archive(&tar_data[..], &directory_path.join("extract")).is_err());
+        assert!(!absolute_path.exists());
+        std::fs::remove_dir_all(directory_path).unwrap();
+    }
+
+    #[test]
+    fn extract_tar_archive_rejects_escaping_symlinks() {
+        let directory_path = create_test_directory();
+        let tar_data = create_test_tar(&[("top/link", tar::EntryType::Symlink, "../..", 0o777)]);
+        assert!(extract_tar_archive(&tar_data[..], &directory_path.join("extract")).is_err());
+        assert!(!directory_path.join("extract/top/link").exists());
+        std::fs::remove_dir_all(directory_path).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn extract_tar_archive_rejects_symlink_chains() {
+        let directory_path = create_test_directory();
+        let tar_data = create_test_tar(&[
+            ("top/a", tar::EntryType::Symlink, "..", 0o777),
+            ("top/a/b", tar::EntryType::Symlink, "..", 0o777),
+            ("top/a/b/evil", tar::EntryType::Regular, "", 0o644),
+        ]);
+        assert!(extract_tar_archive(&tar_data[..], &directory_path.join("extract")).is_err());
+        assert!(!directory_path.join("evil").exists());
+        assert!(!directory_path.join("extract/evil").exists());
+        assert!(!directory_path.join("extract/b").exists());
+        std::fs::remove_dir_all(directory_path).unwrap();
+    }
+
+    #[test]
+    fn extract_tar_archive_rejects_escaping_hard_links() {
+        let directory_path = create_test_directory();
+        std::fs::write(directory_path.join("secret"), "secret").unwrap();
+        let tar_data = create_test_tar(&[("top/link", tar::EntryType::Link, "../secret", 0o644)]);
+        assert!(extract_tar_archive(&tar_data[..], &directory_path.join("extract")).is_err());
+        assert!(!directory_path.join("extract/top/link").exists());
+        std::fs::remove_dir_all(directory_path).unwrap();
+    }
+
+    #[test]
+    fn replace_directory_keeps_backup_until_restored() {
+        let directory_path = create_test_directory();
+        let source_directory_path = directory_path.join("source");
+        let target_directory_path = directory_path.join("target");
+        std::fs::create_dir_all(&source_directory_path).unwrap();
+        std::fs::create_dir_all(&target_directory_path).unwrap();
+        std::fs::write(source_directory_path.join("new"), b"new").unwrap();
+        std::fs::write(target_directory_path.join("old"), b"old").unwrap();
+        let backup_directory_path =
+            replace_directory(&source_directory_path, &target_directory_path)
+                .unwrap()
+                .unwrap();
+        assert!(target_directory_path.join("new").is_file());
+        assert!(backup_directory_path.join("old").is_file());
+        restore_directory_backup(&backup_directory_path, &target_directory_path).unwrap();
+        assert!(target_directory_path.join("old").is_file());
+        assert!(!target_directory_path.join("new").exists());
+        assert!(!backup_directory_path.exists());
+        std::fs::remove_dir_all(directory_path).unwrap();
+    }
+
+    #[test]
+    fn replace_directory_without_target_has_no_backup() {
+        let directory_path = create_test_directory();
+        let source_directory_path = directory_path.join("source");
+        std::fs::create_dir_all(&source_directory_path).unwrap();
+        let backup_directory_path =
+            replace_directory(&source_directory_path, &directory_path.join("target")).unwrap();
+        assert!(backup_directory_path.is_none());
+        assert!(directory_path.join("target").is_dir());
+        std::fs::remove_dir_all(directory_path).unwrap();
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn get_blender_executable_candidates_for_linux() {
+        assert_eq!(
+            get_blender_executable_candidates(),
+            vec![std::path::PathBuf::from("blender")]
+        );
+    }
+
+    #[cfg(target_os = "windows")]
+    #[test]
+    fn get_blender_executable_candidates_for_windows() {
+        assert_eq!(
+            get_blender_executable_candidates(),
+            vec![
+                std::path::PathBuf::from("blender-launcher.exe"),
+                std::path::PathBuf::from("blender.exe"),
+            ]
+        );
+    }
+
+    #[cfg(target_os = "macos")]
+    #[test]
+    fn get_blender_executable_candidates_for_macos() {
+        assert_eq!(
+            get_blender_executable_candidates(),
+            vec![std::path::PathBuf::from(
+                "Blender.app/Contents/MacOS/Blender"
+            )]
+        );
+    }
+
+    #[test]
+    fn find_blender_executable_finds_platform_executable() {
+        let directory_path = create_test_directory();
+        assert_eq!(find_blender_executable(&directory_path), None);
+        let executable_file_path =
+            directory_path.join(get_blender_executable_candidates().remove(0));
+        std::fs::create_dir_all(executable_file_path.parent().unwrap()).unwrap();
+        std::fs::write(&executable_file_path, b"").unwrap();
+        assert_eq!(
+            find_blender_executable(&directory_path),
+            Some(executable_file_path)
+        );
+        std::fs::remove_dir_all(directory_path).unwrap();
+    }
+
+    #[test]
+    fn get_installation_directory_path_skips_app_bundles() {
+        assert_eq!(
+            get_installation_directory_path(std::path::Path::new(
+                "/Applications/Blender 4.5/Blender.app/Contents/MacOS/Blender"
+            )),
+            Some(std::path::PathBuf::from("/Applications/Blender 4.5"))
+        );
+        assert_eq!(
+            get_installation_directory_path(std::path::Path::new(
+                "/opt/blender-4.5.0-linux-x64/blender"
+            )),
+            Some(std::path::PathBuf::from("/opt/blender-4.5.0-linux-x64"))
+        );
+    }
+
+    async fn start_test_http_server(body: Vec<u8>, is_range_supported: bool) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let body = body.clone();
+                tokio::spawn(async move {
+                    let mut request = Vec::new();
+                    let mut buffer = [0u8; 1024];
+                    while !request.windows(4).any(|val| val == b"\r\n\r\n") {
+                        let length = stream.read(&mut buffer).await.unwrap();
+                        if length == 0 {
+                            return;
+                        }
+                        request.extend_from_slice(&buffer[..length]);
+                    }
+                    let request = String::from_utf8_lossy(&request).to_lowercase();
+                    let range_start = request
+                        .lines()
+                        .find_map(|val| val.strip_prefix("range: bytes="))
+                        .and_then(|val| val.trim_end_matches('-').parse::<usize>().ok())
+                        .filter(|_| is_range_supported);
+                    let (status, content) = match range_start {
+                        Some(val) if val >= body.len() => ("416 Range Not Satisfiable", &[][..]),
+                        Some(val) => ("206 Partial Content", &body[val..]),
+                        None => ("200 OK", &body[..]),
+                    };
+                    let head = format!(
+                        "HTTP/1.1 {}\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+                        status,
+                        content.len()
+                    );
+                    let _ = stream.write_all(head.as_bytes()).await;
+                    let _ = stream.write_all(content).await;
+                    let _ = stream.shutdown().await;
+                });
+            }
+        });
+        format!("http://{}/blender.tar.xz", address)
+    }
+
+    fn create_test_body() -> Vec<u8> {
+        (0..4 * 1024 * 1024).map(|val| (val % 251) as u8).collect()
+    }
+
+    fn get_part_file_path(destination_file_path: &std::path::Path) -> std::path::PathBuf {
+        std::path::PathBuf::from(format!("{}.part", destination_file_path.to_string_lossy()))
+    }
+
+    #[tokio::test]
+    async fn download_file_downloads_whole_file() {
+        let directory_path = create_test_directory();
+        let destination_file_path = directory_path.join("blender.tar.xz");
+        let body = create_test_body();
+        let url = start_test_http_server(body.clone(), true).await;
+        let cancel_flag = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let mut last_progress = None;
+        download_file(&url, &destination_file_path, "test", cancel_flag, |val| {
+            last_progress = Some(val)
+        })
+        .await
+        .unwrap();
+        assert_eq!(std::fs::read(&destination_file_path).unwrap(), body);
+        assert!(!get_part_file_path(&destination_file_path).exists());
+        let last_progress = last_progress.unwrap();
+        assert!(last_progress.is_finished);
+        assert_eq!(last_progress.downloaded_bytes, body.len() as u64);
+        assert_eq!(last_progress.total_bytes, Some(body.len() as u64));
+        std::fs::remove_dir_all(directory_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn verify_file_sha256_checksum_deletes_mismatching_download() {
+        let directory_path = create_test_directory();
+        let destination_file_path = directory_path.join("blender.tar.xz");
+        let body = create_test_body();
+        let url = start_test_http_server(body.clone(), true).await;
+        let cancel_flag = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        download_file(&url, &destination_file_path, "test", cancel_flag, |_| {})
+            .await
+            .unwrap();
+        let checksum = get_file_sha256_checksum(&destination_file_path).unwrap();
+        assert!(
+            verify_file_sha256_checksum(&destination_file_path, &checksum.to_uppercase()).is_ok()
+        );
+        assert!(destination_file_path.exists());
+        let error =
+            verify_file_sha256_checksum(&destination_file_path, &"0".repeat(64)).unwrap_err();
+        assert!(error.contains("checksum mismatch"));
+        assert!(!destination_file_path.exists());
+        assert!(!get_part_file_path(&destination_file_path).exists());
+        std::fs::remove_dir_all(directory_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn download_file_cancels_and_resumes() {
+        let directory_path = create_test_directory();
+        let destination_file_path = directory_path.join("blender.tar.xz");
+        let body = create_test_body();
+        let url = start_test_http_server(body.clone(), true).await;
+        let cancel_flag = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let progress_cancel_flag = cancel_flag.clone();
+        let result = download_file(&url, &destination_file_path, "test", cancel_flag, |_| {
+            progress_cancel_flag.store(true, std::sync::atomic::Ordering::SeqCst)
+        })
+        .await;
+        assert!(result.is_err());
+        assert!(!destination_file_path.exists());
+        let part_file_length = std::fs::metadata(get_part_file_path(&destination_file_path))
+            .unwrap()
+            .len();
+        assert!(part_file_length > 0 && part_file_length < body.len() as u64);
+        let cancel_flag = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let mut first_progress = None;
+        download_file(&url, &destination_file_path, "test", cancel_flag, |val| {
+            first_progress.get_or_insert(val);
+        })
+        .await
+        .unwrap();
+        assert_eq!(std::fs::read(&destination_file_path).unwrap(), body);
+        assert!(first_progress.unwrap().downloaded_bytes > part_file_length);
+        std::fs::remove_dir_all(directory_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn download_file_restarts_when_range_is_ignored() {
+        let directory_path = create_test_directory();
+        let destination_file_path = directory_path.join("blender.tar.xz");
+        let body = create_test_body();
+        std::fs::write(get_part_file_path(&destination_file_path), b"stale").unwrap();
+        let url = start_test_http_server(body.clone(), false).await;
+        let cancel_flag = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        download_file(&url, &destination_file_path, "test", cancel_flag, |_| {})
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read(&destination_file_path).unwrap(), body);
+        std::fs::remove_dir_all(directory_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn download_file_finishes_complete_part_file_on_range_not_satisfiable() {
+        let directory_path = create_test_directory();
+        let destination_file_path = directory_path.join("blender.tar.xz");
+        let body = create_test_body();
+        std::fs::write(get_part_file_path(&destination_file_path), &body).unwrap();
+        let url = start_test_http_server(body.clone(), true).await;
+        let cancel_flag = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let mut last_progress = None;
+        download_file(&url, &destination_file_path, "test", cancel_flag, |val| {
+            last_progress = Some(val)
+        })
+        .await
+        .unwrap();
+        assert_eq!(std::fs::read(&destination_file_path).unwrap(), body);
+        assert!(last_progress.unwrap().is_finished);
+        std::fs::remove_dir_all(directory_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn download_file_fails_on_http_errors() {
+        let directory_path = create_test_directory();
+        let cancel_flag = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let result = download_file(
+            "http://127.0.0.1:1/blender.tar.xz",
+            &directory_path.join("blender.tar.xz"),
+            "test",
+            cancel_flag,
+            |_| {},
+        )
+        .await;
+        assert!(result.is_err());
+        std::fs::remove_dir_all(directory_path).unwrap();
+    }
+
+    #[test]
+    fn is_plain_file_name_rejects_paths() {
+        assert!(is_plain_file_name("blender-4.5.0-linux-x64.tar.xz"));
+        assert!(!is_plain_file_name(""));
+        assert!(!is_plain_file_name(".."));
+        assert!(!is_plain_file_name("../blender.tar.xz"));
+        assert!(!is_plain_file_name("builds/blender.tar.xz"));
+        assert!(!is_plain_file_name("/tmp/blender.tar.xz"));
+        assert!(!is_plain_file_name("blender.tar.xz/"));
+    }
+
+    #[test]
+    fn find_blender_executable_for_changed_path_follows_scan_rules() {
+        let directory_path = create_test_directory();
+        let executable_candidate = get_blender_executable_candidates().remove(0);
+        create_test_installations(
+            &directory_path,
+            &["blender-4.2", "nested/blender-4.5", ".staging/blender-5.0"],
+        );
+        let no_patterns = get_glob_set("").unwrap();
+        let find = |changed_path: &str, scan_depth: i64, exclude_patterns: &GlobSet| {
+            find_blender_executable_for_changed_path(
+                &directory_path,
+                &directory_path.join(changed_path),
+                scan_depth,
+                &no_patterns,
+                exclude_patterns,
+            )
+        };
+
+        assert_eq!(
+            find("blender-4.2/4.2/scripts/startup.py", 1, &no_patterns),
+            Some(
+                directory_path
+                    .join("blender-4.2")
+                    .join(&executable_candidate)
+            )
+        );
+        assert_eq!(
+            find("nested/blender-4.5", 2, &no_patterns),
+            Some(
+                directory_path
+                    .join("nested/blender-4.5")
+                    .join(&executable_candidate)
+            )
+        );
+        assert_eq!(find("nested/blender-4.5", 1, &no_patterns), None);
+        assert_eq!(
+            find("nested/blender-4.5", 2, &get_glob_set("nested").unwrap()),
+            None
+        );
+        assert_eq!(find(".staging/blender-5.0", 2, &no_patterns), None);
+        assert_eq!(find("blender-3.6", 1, &no_patterns), None);
+        std::fs::remove_dir_all(directory_path).unwrap();
+    }
+
+    fn create_test_installations(directory_path: &std::path::Path, installation_paths: &[&str]) {
+        let executable_candidate = get_blender_executable_candidates().remove(0);
+        for val in installation_paths {
+            let executable_file_path = directory_path.join(val).join(&executable_candidate);
+            std::fs::create_dir_all(executable_file_path.parent().unwrap()).unwrap();
+            std::fs::write(&executable_file_path, b"").unwrap();
+        }
+    }
+
+    fn find_installation_paths(
+        directory_path: &std::path::Path,
+        scan_depth: i64,
+        include_patterns: &str,
+        exclude_patterns: &str,
+    ) -> Vec<String> {
+        let executable_candidate = get_blender_executable_candidates().remove(0);
+        let mut installation_paths: Vec<String> = find_blender_executables_recursively(
+            directory_path,
+            scan_depth,
+            &get_glob_set(include_patterns).unwrap(),
+            &get_glob_set(exclude_patterns).unwrap(),
+        )
+        .unwrap()
+        .into_iter()
+        .map(|val| {
+            let installation_directory_path = val
+                .ancestors()
+                .nth(executable_candidate.components().count())
+                .unwrap();
+            let relative_path = installation_directory_path
+                .strip_prefix(directory_path)
+                .unwrap();
+            relative_path.to_string_lossy().replace('\\', "/")
+        })
+        .collect();
+        installation_paths.sort();
+        installation_paths
+    }
+
+    #[test]
+    fn find_blender_executables_recursively_stops_at_scan_depth() {
+        let directory_path = create_test_directory();
+        create_test_installations(
+            &directory_path,
+            &[
+                "blender-4.2",
+                "nested/blender-4.5",
+                "nested/deeper/blender-5.0",
+            ],
+        );
+
+        assert_eq!(
+            find_installation_paths(&directory_path, 1, "", ""),
+            vec!["blender-4.2"]
+        );
+        assert_eq!(
+            find_installation_paths(&directory_path, 2, "", ""),
+            vec!["blender-4.2", "nested/blender-4.5"]
+        );
+        assert_eq!(
+            find_installation_paths(&directory_path, 3, "", ""),
+            vec![
+                "blender-4.2",
+                "nested/blender-4.5",
+                "nested/deeper/blender-5.0"
+            ]
+        );
+        std::fs::remove_dir_all(directory_path).unwrap();
+    }
+
+    #[test]
+    fn find_blender_executables_recursively_applies_glob_patterns() {
+        let directory_path = create_test_directory();
+        create_test_installations(
+            &directory_path,
+            &[
+                "blender-4.2",
+                "blender-5.0",
+                "nested/blender-4.5",
+                "old/blender-3.6",
+            ],
+        );
+
+        assert_eq!(
+            find_installation_paths(&directory_path, 2, "blender-4.*; nested/*", ""),
+            vec!["blender-4.2", "nested/blender-4.5"]
+        );
+        assert_eq!(
+            find_installation_paths(&directory_path, 2, "", "old; blender-5.0"),
+            vec!["blender-4.2", "nested/blender-4.5"]
+        );
+        assert_eq!(
+            find_installation_paths(&directory_path, 2, "**/blender-*", "nested"),
+            vec!["blender-4.2", "blender-5.0", "old/blender-3.6"]
+        );
+        std::fs::remove_dir_all(directory_path).unwrap();
+    }
+
+    #[test]
+    fn find_blender_executables_recursively_skips_hidden_and_staging_directories() {
+        let directory_path = create_test_directory();
+        let staging_directory_name = format!(".blendio-staging-{}", uuid::Uuid::new_v4());
+        create_test_installations(
+            &directory_path,
+            &[
+                "blender-4.2",
+                ".blender-4.5",
+                ".hidden/blender-5.0",
+                &format!("{}/blender-5.1", staging_directory_name),
+            ],
+        );
+
+        assert_eq!(
+            find_installation_paths(&directory_path, 3, "", ""),
+            vec!["blender-4.2"]
+        );
+        std::fs::remove_dir_all(directory_path).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn find_blender_executables_recursively_terminates_on_symlink_loops() {
+        let directory_path = create_test_directory();
+        create_test_installations(&directory_path, &["blender-4.2", "nested/blender-4.5"]);
+        std::os::unix::fs::symlink(&directory_path, directory_path.join("nested/root")).unwrap();
+        std::os::unix::fs::symlink(
+            directory_path.join("nested"),
+            directory_path.join("nested/self"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            find_installation_paths(&directory_path, 64, "", ""),
+            vec!["blender-4.2", "nested/blender-4.5"]
+        );
+        std::fs::remove_dir_all(directory_path).unwrap();
+    }
+}
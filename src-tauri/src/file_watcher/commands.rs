@@ -0,0 +1,205 @@
+use notify_debouncer_mini::{new_debouncer, notify::RecursiveMode, DebounceEventResult};
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::{
+    blender_version::refresh_installed_blender_versions_for_paths,
+    db_repo::{BlenderRepoPathRepository, PythonScriptLibraryFolderRepository},
+    project_file::refresh_blend_file_entries,
+    python_script::sync_python_script_library_folder_scripts,
+    AppState,
+};
+
+/// ID: FW_001
+/// ABC analīzes rezultāts:13,27,15
+#[tauri::command]
+pub async fn restart_file_watcher(
+    app: AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<(), String> {
+    let repository = BlenderRepoPathRepository::new(&state.pool); // A (1.a.) let repository =; B (2.a.) ...::new()
+    let blender_repo_paths = match repository.fetch(None, None, None).await {
+        // A (1.a.) let blender_repo_paths =; C (3.b) match; B (2.a.) .fetch()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => return Err(format!("Failed to fetch Blender repo paths: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    };
+    let library_folder_repository = PythonScriptLibraryFolderRepository::new(&state.pool); // A (1.a.) let library_folder_repository =; B (2.a.) ...::new()
+    let library_folders = match library_folder_repository.fetch(None, None).await {
+        // A (1.a.) let library_folders =; C (3.b) match; B (2.a.) .fetch()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => return Err(format!("Failed to fetch script library folders: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    };
+    let (sender, receiver) = tokio::sync::mpsc::unbounded_channel(); // A (1.a.) let (sender, receiver) =; B (2.a.) ::unbounded_channel()
+    let debouncer_result = new_debouncer(
+        // A (1.a.) let debouncer_result =; B (2.a.) new_debouncer()
+        std::time::Duration::from_secs(2), // B (2.a.) ::from_secs()
+        move |result: DebounceEventResult| {
+            if let Ok(events) = result {
+                // A (1.d.) if let Ok()
+                let paths = events.into_iter().map(|event| event.path).collect(); // A (1.a.) let paths =; B (2.a.) .into_iter(); B (2.a.) .map(); B (2.a.) .collect()
+                let _ = sender.send(paths); // A (1.a.) let _ =; B (2.a.) .send()
+            }
+        },
+    );
+    let mut debouncer = match debouncer_result {
+        // A (1.a.) let mut debouncer =; C (3.b) match
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => return Err(format!("Failed to start file watcher: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    };
+    let mut repo_directory_paths = Vec::new(); // A (1.a.) let mut repo_directory_paths =; B (2.a.) ...::new()
+    for repo_path in blender_repo_paths {
+        // A (1.a.) let repo_path =;
+        let directory_path = std::path::PathBuf::from(&repo_path.repo_directory_path); // A (1.a.) let directory_path =; B (2.a.) ::from()
+        let recursive_mode = if repo_path.scan_depth > 1 {
+            // A (1.a.) let recursive_mode =; C (3.a.) repo_path.scan_depth > 1
+            RecursiveMode::Recursive
+        } else {
+            // C (3.b.) else
+            RecursiveMode::NonRecursive
+        };
+        // An offline network drive must not keep the other repo paths from being watched.
+        if debouncer
+            .watcher()
+            .watch(&directory_path, recursive_mode)
+            .is_ok()
+        {
+            // C (3.a.) .is_ok() == true; B (2.a.) .watcher(); B (2.a.) .watch(); B (2.a.) .is_ok()
+            repo_directory_paths.push(directory_path); // A (1.c.) .push()
+        }
+    }
+    let mut library_directory_paths = Vec::new(); // A (1.a.) let mut library_directory_paths =; B (2.a.) ...::new()
+    for library_folder in library_folders {
+        // A (1.a.) let library_folder =;
+        let directory_path = std::path::PathBuf::from(&library_folder.folder_path); // A (1.a.) let directory_path =; B (2.a.) ::from()
+        if debouncer
+            .watcher()
+            .watch(&directory_path, RecursiveMode::Recursive)
+            .is_ok()
+        {
+            // C (3.a.) .is_ok() == true; B (2.a.) .watcher(); B (2.a.) .watch(); B (2.a.) .is_ok()
+            library_directory_paths.push(directory_path); // A (1.c.) .push()
+        }
+    }
+    if let Some(config_directory) = dirs::config_dir() {
+        // A (1.d.) if let Some(); B (2.a.) ::config_dir()
+        let blender_foundation_directory =
+            config_directory.join("Blender Foundation").join("Blender"); // A (1.a.) let blender_foundation_directory =; B (2.a.) .join()
+        let _ = debouncer
+            .watcher()
+            .watch(&blender_foundation_directory, RecursiveMode::Recursive); // A (1.a.) let _ =; B (2.a.) .watcher(); B (2.a.) .watch()
+    }
+    // Replacing the previous debouncer stops it, which also ends its event loop below.
+    match state.file_watcher.lock() {
+        // C (3.b) match; B (2.a.) .lock()
+        Ok(mut file_watcher) => *file_watcher = Some(debouncer), // C (3.c.) Ok(); A (1.a.) *file_watcher =
+        Err(err) => return Err(format!("Failed to start file watcher: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    }
+    tauri::async_runtime::spawn(handle_file_watcher_events(
+        // B (2.a.) ::spawn(); B (2.a.) handle_file_watcher_events(); B (2.a.) app.clone()
+        app.clone(),
+        repo_directory_paths,
+        library_directory_paths,
+        receiver,
+    ));
+    Ok(())
+}
+
+/// ID: FW_002
+/// ABC analīzes rezultāts:6,21,8
+pub async fn handle_file_watcher_events(
+    app: AppHandle,
+    repo_directory_paths: Vec<std::path::PathBuf>,
+    library_directory_paths: Vec<std::path::PathBuf>,
+    mut receiver: tokio::sync::mpsc::UnboundedReceiver<Vec<std::path::PathBuf>>,
+) {
+    let state = app.state::<AppState>(); // A (1.a.) let state =; B (2.a.) .state()
+    while let Some(paths) = receiver.recv().await {
+        // A (1.d.) while let Some(); B (2.a.) .recv()
+        let repo_changed_paths: Vec<std::path::PathBuf> = paths
+            .iter()
+            .filter(|path| is_repo_directory_change(path, &repo_directory_paths))
+            .cloned()
+            .collect(); // A (1.a.) let repo_changed_paths =; B (2.a.) .iter(); B (2.a.) .filter(); B (2.a.) is_repo_directory_change(); B (2.a.) .cloned(); B (2.a.) .collect()
+        let is_project_files_changed = paths.iter().any(|path| {
+            // A (1.a.) let is_project_files_changed =; B (2.a.) .iter(); B (2.a.) .any()
+            path.file_name()
+                .map(|val| val == "recent-files.txt")
+                .unwrap_or(false) // B (2.a.) .file_name(); B (2.a.) .map(); C (3.a.) val == "recent-files.txt"; B (2.a.) .unwrap_or()
+        });
+        let is_python_scripts_changed = paths
+            .iter()
+            .any(|path| is_library_directory_change(path, &library_directory_paths)); // A (1.a.) let is_python_scripts_changed =; B (2.a.) .iter(); B (2.a.) .any(); B (2.a.) is_library_directory_change()
+
+        // Changes arrive while the user is doing something else, so failures are reported to the
+        // frontend as events instead of opening dialogs.
+        if !repo_changed_paths.is_empty() {
+            // C (3.a.) repo_changed_paths.is_empty() != true; B (2.a.) .is_empty()
+            let result =
+                refresh_installed_blender_versions_for_paths(&state.pool, &repo_changed_paths)
+                    .await; // A (1.a.) let result =; B (2.a.) refresh_installed_blender_versions_for_paths()
+            emit_file_watcher_result(&app, "installed-blender-versions-changed", result);
+            // B (2.a.) emit_file_watcher_result()
+        }
+        if is_project_files_changed {
+            // C (3.a.) is_project_files_changed == true
+            let result = refresh_blend_file_entries(&state.pool).await; // A (1.a.) let result =; B (2.a.) refresh_blend_file_entries()
+            emit_file_watcher_result(&app, "project-files-changed", result); // B (2.a.) emit_file_watcher_result()
+        }
+        if is_python_scripts_changed {
+            // C (3.a.) is_python_scripts_changed == true
+            let result = sync_python_script_library_folder_scripts(&state.pool).await; // A (1.a.) let result =; B (2.a.) sync_python_script_library_folder_scripts()
+            emit_file_watcher_result(&app, "python-scripts-changed", result); // B (2.a.) emit_file_watcher_result()
+        }
+    }
+}
+
+/// ID: FW_005
+/// ABC analīzes rezultāts:0,2,2
+pub fn emit_file_watcher_result(app: &AppHandle, event: &str, result: Result<(), String>) {
+    let _ = match result {
+        // A (1.a.) let _ =; C (3.b) match
+        Ok(_) => app.emit(event, ()), // C (3.c.) Ok(); B (2.a.) .emit()
+        Err(err) => app.emit("file-watcher-refresh-failed", err), // C (3.c) Err(); B (2.a.) .emit()
+    };
+}
+
+/// ID: FW_003
+/// ABC analīzes rezultāts:2,9,6
+pub fn is_repo_directory_change(
+    path: &std::path::Path,
+    repo_directory_paths: &[std::path::PathBuf],
+) -> bool {
+    // Partial downloads and the hidden staging and backup directories of an install in
+    // progress change constantly and never affect the list of installed versions.
+    repo_directory_paths.iter().any(|repo_directory_path| {
+        // B (2.a.) .iter(); B (2.a.) .any()
+        let relative_path = match path.strip_prefix(repo_directory_path) {
+            // A (1.a.) let relative_path =; C (3.b) match; B (2.a.) .strip_prefix()
+            Ok(val) => val,         // C (3.c.) Ok()
+            Err(_) => return false, // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+        };
+        let is_hidden = relative_path
+            .components()
+            .any(|val| val.as_os_str().to_string_lossy().starts_with('.')); // A (1.a.) let is_hidden =; B (2.a.) .components(); B (2.a.) .any(); B (2.a.) .as_os_str(); B (2.a.) .to_string_lossy(); B (2.a.) .starts_with()
+        let is_partial_download = relative_path
+            .extension()
+            .map(|val| val == "part")
+            .unwrap_or(false); // A (1.a.) let is_partial_download =; B (2.a.) .extension(); B (2.a.) .map(); C (3.a.) val == "part"; B (2.a.) .unwrap_or()
+        !is_hidden && !is_partial_download // C (3.a.) is_hidden != true; C (3.a.) is_partial_download != true
+    })
+}
+
+/// ID: FW_004
+/// ABC analīzes rezultāts:1,8,6
+pub fn is_library_directory_change(
+    path: &std::path::Path,
+    library_directory_paths: &[std::path::PathBuf],
+) -> bool {
+    // Only script files matter, a path that no longer exists may be a removed subfolder of scripts.
+    let is_script_change =
+        path.extension().map(|val| val == "py").unwrap_or(false) || !path.exists(); // A (1.a.) let is_script_change =; B (2.a.) .extension(); B (2.a.) .map(); C (3.a.) val == "py"; B (2.a.) .unwrap_or(); C (3.a.) path.exists() != true; B (2.a.) .exists()
+    is_script_change
+        && library_directory_paths
+            .iter()
+            .any(|library_directory_path| path.starts_with(library_directory_path))
+    // B (2.a.) .iter(); B (2.a.) .any(); C (3.a.) path.starts_with() == true; B (2.a.) .starts_with()
+}
@@ -1,13 +1,18 @@
 use crate::{
     db_repo::LaunchArgumentRepository,
     file_system_utility::{self, show_ok_notification},
-    models::LaunchArgument,
+    models::{
+        BlenderCliOption, BlenderCliValueType, LaunchArgument, LaunchArgumentOption,
+        LaunchArgumentPreset, LaunchEnvironment, LaunchEnvironmentVariable,
+    },
     AppState,
 };
+
+use super::BLENDER_CLI_OPTIONS;
 use tauri::AppHandle;
 
 /// ID: KP_001
-/// ABC analīzes rezultāts:6,28,10
+/// ABC analīzes rezultāts:10,35,14
 #[tauri::command]
 pub async fn insert_launch_argument(
     app: AppHandle,
@@ -15,7 +20,22 @@ pub async fn insert_launch_argument(
     argument_string: String,
     project_file_id: Option<String>,
     python_script_id: Option<String>,
+    preset: Option<LaunchArgumentPreset>,
 ) -> Result<String, String> {
+    // Stored in canonical quoting so equal argument vectors are found as the same entry.
+    let (argument_string, preset_json) = match preset {
+        // A (1.a.) let (argument_string, preset_json) =; C (3.b) match
+        Some(preset) => {
+            // C (3.c) Some()
+            let (argument_string, preset_json) =
+                normalize_launch_argument_preset(app.clone(), &preset)?; // A (1.a.) let (argument_string, preset_json) =; B (2.a.) normalize_launch_argument_preset(); B (2.a.) app.clone()
+            (argument_string, Some(preset_json))
+        }
+        None => (
+            normalize_argument_string(app.clone(), &argument_string)?,
+            None,
+        ), // C (3.c) None =>; B (2.a.) normalize_argument_string(); B (2.a.) app.clone()
+    };
     let repository = LaunchArgumentRepository::new(&state.pool); // A (1.a.) let repository =; B (2.a.) ...::new()
     let mut results = match repository.fetch(None, None, Some(&argument_string)).await {
         // A (1.a.) let mut results =; C (3.b.) match; B (2.a.) repository.fetch();
@@ -36,6 +56,10 @@ pub async fn insert_launch_argument(
         let mut existing_entry = results.remove(0); // A (1.a.) let mut existing_entry =; B (2.a.) results.remove();
         existing_entry.accessed = chrono::Utc::now().to_rfc3339(); // A (1.a.) existing_entry.accessed =; B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
         existing_entry.modified = chrono::Utc::now().to_rfc3339(); // A (1.a.) existing_entry.modified =; B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
+        if preset_json.is_some() {
+            // C (3.a) preset_json.is_some() == true; B (2.a.) .is_some()
+            existing_entry.preset_json = preset_json; // A (1.a.) existing_entry.preset_json =
+        }
         match repository.update(&existing_entry).await {
             // C (3.b.) match; B (2.a.) repository.update()
             Ok(_) => return Ok(existing_entry.id), // C (3.c) Ok(); B (2.b.) priekšlaicīgs return
@@ -59,6 +83,9 @@ pub async fn insert_launch_argument(
         id: uuid::Uuid::new_v4().to_string(), // B (2.a.) uuid::Uuid::new_v4(); B (2.a.) .to_string();
         is_default: false,
         argument_string: argument_string,
+        preset_json: preset_json,
+        environment_json: "[]".to_string(), // B (2.a.) .to_string()
+        working_directory: None,
         last_used_project_file_id: project_file_id,
         last_used_python_script_id: python_script_id,
         created: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
@@ -82,13 +109,15 @@ pub async fn insert_launch_argument(
 }
 
 /// ID: KP_002
-/// ABC analīzes rezultāts:8,21,17
+/// ABC analīzes rezultāts:15,36,24
 #[tauri::command]
 pub async fn update_launch_argument(
     app: AppHandle,
     state: tauri::State<'_, AppState>,
     id: String,
-    is_default: bool,
+    is_default: Option<bool>,
+    argument_string: Option<String>,
+    preset: Option<LaunchArgumentPreset>,
 ) -> Result<(), String> {
     let repository = LaunchArgumentRepository::new(&state.pool); // A (1.a.) let repository =; B (2.a.) ...::new()
     let mut results = match repository.fetch(Some(&id), None, None).await {
@@ -116,6 +145,46 @@ pub async fn update_launch_argument(
         return Err(format!("Failed to fetch launch arguments by ID")); // B (2.b.) priekšlaicīgs return
     }
     let mut entry = results.remove(0); // A (1.a.) let mut entry =; B (2.a.) results.remove();
+    if preset.is_some() || argument_string.is_some() {
+        // C (3.a) preset.is_some() == true; C (3.a) argument_string.is_some() == true; B (2.a.) .is_some(); B (2.a.) .is_some()
+        // A raw argument string replaces the structured preset, the preset would no longer match it.
+        (entry.argument_string, entry.preset_json) = match (preset, argument_string) {
+            // A (1.a.) (entry.argument_string, entry.preset_json) =; C (3.b) match
+            (Some(preset), _) => {
+                // C (3.c) Some()
+                let (argument_string, preset_json) =
+                    normalize_launch_argument_preset(app.clone(), &preset)?; // A (1.a.) let (argument_string, preset_json) =; B (2.a.) normalize_launch_argument_preset(); B (2.a.) app.clone()
+                (argument_string, Some(preset_json))
+            }
+            (None, argument_string) => (
+                // C (3.c) None =>
+                normalize_argument_string(app.clone(), &argument_string.unwrap_or_default())?,
+                None,
+            ), // B (2.a.) normalize_argument_string(); B (2.a.) app.clone(); B (2.a.) .unwrap_or_default()
+        };
+        entry.modified = chrono::Utc::now().to_rfc3339(); // A (1.a.) entry.modified =; B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
+        match repository.update(&entry).await {
+            // C (3.b.) match; B (2.a.) repository.update()
+            Ok(_) => {} // C (3.c) Ok();
+            Err(err) => {
+                // C (3.c) Err();
+                show_ok_notification(
+                    // B (2.a.) show_ok_notification()
+                    app.clone(), // B (2.a.) app.clone();
+                    format!("Failed to update existing launch arguments: {:?}", err),
+                    tauri_plugin_dialog::MessageDialogKind::Error,
+                );
+                return Err(format!(
+                    "Failed to update existing launch arguments: {:?}",
+                    err
+                )); // B (2.b.) priekšlaicīgs return
+            }
+        }
+    }
+    let Some(is_default) = is_default else {
+        // A (1.d.) let Some() else
+        return Ok(()); // B (2.b.) priekšlaicīgs return
+    };
     if is_default == true {
         // C (3.a) is_default == true
         entry.is_default = false; // A (1.a.) entry.is_default =;
@@ -248,3 +317,848 @@ pub async fn delete_launch_argument(
         }
     }
 }
+
+/// ID: KP_005
+/// ABC analīzes rezultāts:15,10,28
+pub fn parse_argument_string(argument_string: &str) -> Result<Vec<String>, String> {
+    // Follows POSIX shell word splitting without expansions, positions in errors are 1-based characters.
+    let mut arguments: Vec<String> = vec![]; // A (1.a.) let mut arguments =
+    let mut current: Option<String> = None; // A (1.a.) let mut current =
+    let mut characters = argument_string.chars().enumerate().peekable(); // A (1.a.) let mut characters =; B (2.a.) .chars(); B (2.a.) .enumerate(); B (2.a.) .peekable()
+    while let Some((position, character)) = characters.next() {
+        // A (1.d.) while let Some(); B (2.a.) .next()
+        match character {
+            // C (3.b) match
+            ' ' | '\t' | '\n' => {
+                // C (3.c) ' ' | '\t' | '\n' =>
+                if let Some(argument) = current.take() {
+                    // A (1.d.) if let Some(); B (2.a.) .take()
+                    arguments.push(argument); // A (1.c.) .push()
+                }
+            }
+            // Outside quotes a backslash only escapes quotes, whitespace and itself, so Windows
+            // paths such as C:\scripts\a.py keep their separators.
+            '\\' => match characters.peek() {
+                // C (3.c) '\\' =>; C (3.b) match; B (2.a.) .peek()
+                // An escaped newline is a line continuation and is removed entirely.
+                Some((_, '\n')) => {
+                    // C (3.c) Some()
+                    characters.next(); // B (2.a.) .next()
+                }
+                Some((_, escaped @ ('\'' | '"' | ' ' | '\t' | '\\'))) => {
+                    // C (3.c) Some()
+                    current.get_or_insert_with(String::new).push(*escaped); // A (1.c.) .push(); B (2.a.) .get_or_insert_with()
+                    characters.next(); // B (2.a.) .next()
+                }
+                _ => current.get_or_insert_with(String::new).push('\\'), // C (3.c) _ =>; A (1.c.) .push(); B (2.a.) .get_or_insert_with()
+            },
+            '\'' => {
+                // C (3.c) '\'' =>
+                let argument = current.get_or_insert_with(String::new); // A (1.a.) let argument =; B (2.a.) .get_or_insert_with()
+                loop {
+                    match characters.next() {
+                        // C (3.b) match; B (2.a.) .next()
+                        Some((_, '\'')) => break, // C (3.c) Some(); B (2.b.) break
+                        Some((_, quoted)) => argument.push(quoted), // C (3.c) Some(); A (1.c.) .push()
+                        None => {
+                            // C (3.c) None =>
+                            return Err(format!(
+                                "Unbalanced single quote at position {}",
+                                position + 1
+                            )); // B (2.b.) priekšlaicīgs return
+                        }
+                    }
+                }
+            }
+            '"' => {
+                // C (3.c) '"' =>
+                let argument = current.get_or_insert_with(String::new); // A (1.a.) let argument =; B (2.a.) .get_or_insert_with()
+                loop {
+                    match characters.next() {
+                        // C (3.b) match; B (2.a.) .next()
+                        Some((_, '"')) => break, // C (3.c) Some(); B (2.b.) break
+                        // Inside double quotes a backslash only escapes $, `, ", \ and newline.
+                        Some((_, '\\')) => match characters.peek() {
+                            // C (3.c) Some(); C (3.b) match; B (2.a.) .peek()
+                            Some((_, '\n')) => {
+                                // C (3.c) Some()
+                                characters.next(); // B (2.a.) .next()
+                            }
+                            Some((_, escaped @ ('$' | '`' | '"' | '\\'))) => {
+                                // C (3.c) Some()
+                                argument.push(*escaped); // A (1.c.) .push()
+                                characters.next(); // B (2.a.) .next()
+                            }
+                            _ => argument.push('\\'), // C (3.c) _ =>; A (1.c.) .push()
+                        },
+                        Some((_, quoted)) => argument.push(quoted), // C (3.c) Some(); A (1.c.) .push()
+                        None => {
+                            // C (3.c) None =>
+                            return Err(format!(
+                                "Unbalanced double quote at position {}",
+                                position + 1
+                            )); // B (2.b.) priekšlaicīgs return
+                        }
+                    }
+                }
+            }
+            _ => current.get_or_insert_with(String::new).push(character), // C (3.c) _ =>; A (1.c.) .push(); B (2.a.) .get_or_insert_with()
+        }
+    }
+    if let Some(argument) = current {
+        // A (1.d.) if let Some()
+        arguments.push(argument); // A (1.c.) .push()
+    }
+    Ok(arguments)
+}
+
+/// ID: KP_006
+/// ABC analīzes rezultāts:1,7,4
+pub fn quote_argument(argument: &str) -> String {
+    let is_safe = !argument.is_empty()
+        && argument
+            .chars()
+            .all(|val| val.is_ascii_alphanumeric() || "-_./=:,+@%".contains(val)); // A (1.a.) let is_safe =; C (3.a.) .is_empty() != true; B (2.a.) .is_empty(); B (2.a.) .chars(); B (2.a.) .all(); B (2.a.) .is_ascii_alphanumeric(); B (2.a.) .contains()
+    if is_safe {
+        // C (3.a.) is_safe == true
+        return argument.to_string(); // B (2.b.) priekšlaicīgs return
+    }
+    // Single quotes keep everything literal, an embedded quote closes, escapes and reopens them.
+    format!("'{}'", argument.replace('\'', "'\\''")) // B (2.a.) .replace()
+}
+
+/// ID: KP_007
+/// ABC analīzes rezultāts:0,4,0
+pub fn join_argument_vector(arguments: &[String]) -> String {
+    arguments
+        .iter()
+        .map(|val| quote_argument(val))
+        .collect::<Vec<String>>()
+        .join(" ") // B (2.a.) .iter(); B (2.a.) .map(); B (2.a.) quote_argument(); B (2.a.) .collect(); B (2.a.) .join()
+}
+
+/// ID: KP_008
+/// ABC analīzes rezultāts:2,6,3
+pub fn normalize_argument_string(app: AppHandle, argument_string: &str) -> Result<String, String> {
+    match parse_argument_string(argument_string) {
+        // C (3.b) match; B (2.a.) parse_argument_string()
+        Ok(val) => Ok(join_argument_vector(&val)), // C (3.c.) Ok(); B (2.a.) join_argument_vector()
+        Err(err) => {
+            // C (3.c) Err()
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to parse launch argument: {}", err),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            Err(format!("Failed to parse launch argument: {}", err))
+        }
+    }
+}
+
+/// ID: KP_009
+/// ABC analīzes rezultāts:0,3,2
+pub fn find_blender_cli_option(name: &str) -> Option<&'static BlenderCliOption> {
+    BLENDER_CLI_OPTIONS
+        .iter()
+        .find(|val| val.name == name || val.short_name == Some(name)) // B (2.a.) .iter(); B (2.a.) .find(); C (3.a.) val.name == name; C (3.a.) val.short_name == Some(name)
+}
+
+/// ID: KP_010
+/// ABC analīzes rezultāts:6,9,2
+pub fn get_edit_distance(left: &str, right: &str) -> usize {
+    let right: Vec<char> = right.chars().collect(); // A (1.a.) let right =; B (2.a.) .chars(); B (2.a.) .collect()
+    let mut previous_row: Vec<usize> = (0..=right.len()).collect(); // A (1.a.) let mut previous_row =; B (2.a.) .len(); B (2.a.) .collect()
+    for (left_index, left_character) in left.chars().enumerate() {
+        // A (1.a.) let (left_index, left_character) =; B (2.a.) .chars(); B (2.a.) .enumerate()
+        let mut current_row = vec![left_index + 1]; // A (1.a.) let mut current_row =
+        for (right_index, right_character) in right.iter().enumerate() {
+            // A (1.a.) let (right_index, right_character) =; B (2.a.) .iter(); B (2.a.) .enumerate()
+            let substitution_cost = usize::from(left_character != *right_character); // A (1.a.) let substitution_cost =; C (3.a.) left_character != right_character; B (2.a.) ::from()
+            current_row.push(
+                // A (1.c.) .push()
+                (previous_row[right_index] + substitution_cost)
+                    .min(previous_row[right_index + 1] + 1)
+                    .min(current_row[right_index] + 1), // B (2.a.) .min(); B (2.a.) .min()
+            );
+        }
+        previous_row = current_row; // A (1.a.) previous_row =
+    }
+    previous_row[right.len()] // B (2.a.) .len()
+}
+
+/// ID: KP_011
+/// ABC analīzes rezultāts:1,6,3
+pub fn find_similar_blender_cli_option_name(name: &str) -> Option<&'static str> {
+    // Catches typos such as --factory_startup, short options are too short to compare meaningfully.
+    let (distance, similar_name) = BLENDER_CLI_OPTIONS // A (1.a.) let (distance, similar_name) =
+        .iter()
+        .map(|val| (get_edit_distance(name, val.name), val.name))
+        .min()?; // B (2.a.) .iter(); B (2.a.) .map(); B (2.a.) get_edit_distance(); B (2.a.) .min()
+    if distance <= 3 {
+        // C (3.a.) distance <= 3
+        return Some(similar_name); // B (2.b.) priekšlaicīgs return
+    }
+    None
+}
+
+/// ID: KP_012
+/// ABC analīzes rezultāts:2,14,11
+pub fn is_valid_blender_cli_value(value_type: &BlenderCliValueType, value: &str) -> bool {
+    let is_frame = |frame: &str| {
+        // A (1.a.) let is_frame =
+        let digits = frame.strip_prefix(['+', '-']).unwrap_or(frame); // A (1.a.) let digits =; B (2.a.) .strip_prefix(); B (2.a.) .unwrap_or()
+        !digits.is_empty() && digits.chars().all(|val| val.is_ascii_digit()) // C (3.a.) .is_empty() != true; B (2.a.) .is_empty(); B (2.a.) .chars(); B (2.a.) .all(); B (2.a.) .is_ascii_digit()
+    };
+    match value_type {
+        // C (3.b) match
+        BlenderCliValueType::Integer => value.parse::<i64>().is_ok(), // C (3.c) Integer =>; B (2.a.) .parse(); B (2.a.) .is_ok()
+        BlenderCliValueType::Frame => is_frame(value), // C (3.c) Frame =>; B (2.a.) is_frame()
+        // Frame lists are comma separated frames or ranges, for example 1,3,5..10.
+        BlenderCliValueType::FrameList => value.split(',').all(|val| match val.split_once("..") {
+            // C (3.c) FrameList =>; B (2.a.) .split(); B (2.a.) .all(); C (3.b) match; B (2.a.) .split_once()
+            Some((start, end)) => is_frame(start) && is_frame(end), // C (3.c) Some(); B (2.a.) is_frame(); B (2.a.) is_frame()
+            None => is_frame(val), // C (3.c) None =>; B (2.a.) is_frame()
+        }),
+        BlenderCliValueType::Path
+        | BlenderCliValueType::Text
+        | BlenderCliValueType::SuggestedText(_) => !value.is_empty(), // C (3.c) Path | Text | SuggestedText() =>; C (3.a.) .is_empty() != true; B (2.a.) .is_empty()
+        BlenderCliValueType::Choice(choices) => choices.contains(&value), // C (3.c) Choice() =>; B (2.a.) .contains()
+    }
+}
+
+/// ID: KP_013
+/// ABC analīzes rezultāts:9,17,14
+pub fn validate_launch_argument_preset(preset: &LaunchArgumentPreset) -> Result<(), String> {
+    let mut used_option_names: Vec<&'static str> = vec![]; // A (1.a.) let mut used_option_names =
+    for option in &preset.options {
+        // A (1.a.) let option =;
+        let Some(cli_option) = find_blender_cli_option(&option.name) else {
+            // A (1.d.) let Some() else; B (2.a.) find_blender_cli_option()
+            return match find_similar_blender_cli_option_name(&option.name) {
+                // C (3.b) match; B (2.a.) find_similar_blender_cli_option_name(); B (2.b.) priekšlaicīgs return
+                Some(val) => Err(format!(
+                    "Unknown Blender option \"{}\", did you mean \"{}\"?",
+                    option.name, val
+                )), // C (3.c) Some()
+                None => Err(format!("Unknown Blender option \"{}\"", option.name)), // C (3.c) None =>
+            };
+        };
+        if option.values.len() != cli_option.value_types.len() {
+            // C (3.a.) option.values.len() != cli_option.value_types.len(); B (2.a.) .len(); B (2.a.) .len()
+            return Err(format!(
+                "Option \"{}\" expects {} value(s), got {}",
+                cli_option.name,
+                cli_option.value_types.len(),
+                option.values.len()
+            )); // B (2.b.) priekšlaicīgs return; B (2.a.) .len(); B (2.a.) .len()
+        }
+        for (value_type, value) in cli_option.value_types.iter().zip(&option.values) {
+            // A (1.a.) let (value_type, value) =; B (2.a.) .iter(); B (2.a.) .zip()
+            if !is_valid_blender_cli_value(value_type, value) {
+                // C (3.a.) is_valid_blender_cli_value() != true; B (2.a.) is_valid_blender_cli_value()
+                return Err(format!(
+                    "Invalid value \"{}\" for option \"{}\", expected {:?}",
+                    value, cli_option.name, value_type
+                )); // B (2.b.) priekšlaicīgs return
+            }
+        }
+        if !cli_option.is_repeatable && used_option_names.contains(&cli_option.name) {
+            // C (3.a.) is_repeatable != true; C (3.a.) .contains() == true; B (2.a.) .contains()
+            return Err(format!(
+                "Option \"{}\" is specified more than once",
+                cli_option.name
+            )); // B (2.b.) priekšlaicīgs return
+        }
+        if let Some(conflicting_name) = cli_option
+            .conflicts_with
+            .iter()
+            .find(|val| used_option_names.contains(val))
+        {
+            // A (1.d.) if let Some(); B (2.a.) .iter(); B (2.a.) .find(); B (2.a.) .contains()
+            return Err(format!(
+                "Option \"{}\" conflicts with \"{}\"",
+                cli_option.name, conflicting_name
+            )); // B (2.b.) priekšlaicīgs return
+        }
+        used_option_names.push(cli_option.name); // A (1.c.) .push()
+    }
+    Ok(())
+}
+
+/// ID: KP_014
+/// ABC analīzes rezultāts:8,23,3
+pub fn build_launch_argument_vector(preset: &LaunchArgumentPreset) -> Vec<String> {
+    let mut arguments: Vec<String> = vec![]; // A (1.a.) let mut arguments =
+    let mut render_action_arguments: Vec<String> = vec![]; // A (1.a.) let mut render_action_arguments =
+    let options: Vec<(&'static BlenderCliOption, &LaunchArgumentOption)> = preset // A (1.a.) let options =
+        .options
+        .iter()
+        .filter_map(|val| find_blender_cli_option(&val.name).map(|cli_option| (cli_option, val)))
+        .collect(); // B (2.a.) .iter(); B (2.a.) .filter_map(); B (2.a.) find_blender_cli_option(); B (2.a.) .map(); B (2.a.) .collect()
+    for (cli_option, option) in options {
+        // A (1.a.) let (cli_option, option) =;
+        let target_arguments = if cli_option.is_render_action {
+            // A (1.a.) let target_arguments =; C (3.a.) cli_option.is_render_action == true
+            &mut render_action_arguments
+        } else {
+            // C (3.b.) else
+            &mut arguments
+        };
+        target_arguments.push(cli_option.name.to_string()); // A (1.c.) .push(); B (2.a.) .to_string()
+        target_arguments.extend(option.values.iter().cloned()); // A (1.c.) .extend(); B (2.a.) .iter(); B (2.a.) .cloned()
+    }
+
+    // Blender applies arguments in order, so render actions go after every render setting,
+    // including the passthrough ones. Arguments after `--` belong to Python and stay last.
+    let separator_index = preset
+        .passthrough_arguments
+        .iter()
+        .position(|val| val == "--")
+        .unwrap_or(preset.passthrough_arguments.len()); // A (1.a.) let separator_index =; B (2.a.) .iter(); B (2.a.) .position(); B (2.a.) .unwrap_or(); B (2.a.) .len()
+    let (passthrough_arguments, script_arguments) =
+        preset.passthrough_arguments.split_at(separator_index); // A (1.a.) let (passthrough_arguments, script_arguments) =; B (2.a.) .split_at()
+    arguments.extend(passthrough_arguments.iter().cloned()); // A (1.c.) .extend(); B (2.a.) .iter(); B (2.a.) .cloned()
+    arguments.extend(render_action_arguments); // A (1.c.) .extend()
+    arguments.extend(script_arguments.iter().cloned()); // A (1.c.) .extend(); B (2.a.) .iter(); B (2.a.) .cloned()
+    arguments
+}
+
+/// ID: KP_015
+/// ABC analīzes rezultāts:3,12,4
+pub fn normalize_launch_argument_preset(
+    app: AppHandle,
+    preset: &LaunchArgumentPreset,
+) -> Result<(String, String), String> {
+    if let Err(err) = validate_launch_argument_preset(preset) {
+        // A (1.d.) if let Err(); B (2.a.) validate_launch_argument_preset()
+        show_ok_notification(
+            // B (2.a.) show_ok_notification()
+            app.clone(), // B (2.a.) app.clone();
+            format!("Failed to validate launch argument preset: {}", err),
+            tauri_plugin_dialog::MessageDialogKind::Error,
+        );
+        return Err(format!(
+            "Failed to validate launch argument preset: {}",
+            err
+        )); // B (2.b.) priekšlaicīgs return
+    }
+    let warnings = get_launch_argument_preset_warnings(preset); // A (1.a.) let warnings =; B (2.a.) get_launch_argument_preset_warnings()
+    if !warnings.is_empty() {
+        // C (3.a.) warnings.is_empty() == false; B (2.a.) .is_empty()
+        show_ok_notification(
+            // B (2.a.) show_ok_notification()
+            app.clone(),         // B (2.a.) app.clone();
+            warnings.join("\n"), // B (2.a.) .join()
+            tauri_plugin_dialog::MessageDialogKind::Warning,
+        );
+    }
+    let argument_string = join_argument_vector(&build_launch_argument_vector(preset)); // A (1.a.) let argument_string =; B (2.a.) join_argument_vector(); B (2.a.) build_launch_argument_vector()
+    match serde_json::to_string(preset) {
+        // C (3.b) match; B (2.a.) ::to_string()
+        Ok(val) => Ok((argument_string, val)), // C (3.c.) Ok()
+        Err(err) => Err(format!(
+            "Failed to serialize launch argument preset: {:?}",
+            err
+        )), // C (3.c) Err()
+    }
+}
+
+/// ID: KP_016
+/// ABC analīzes rezultāts:0,1,0
+#[tauri::command]
+pub async fn fetch_blender_cli_options() -> Result<Vec<BlenderCliOption>, String> {
+    Ok(BLENDER_CLI_OPTIONS.to_vec()) // B (2.a.) .to_vec()
+}
+
+/// ID: KP_017
+/// ABC analīzes rezultāts:0,4,2
+#[tauri::command]
+pub async fn preview_launch_argument_preset(
+    preset: LaunchArgumentPreset,
+) -> Result<String, String> {
+    match validate_launch_argument_preset(&preset) {
+        // C (3.b) match; B (2.a.) validate_launch_argument_preset()
+        Ok(_) => Ok(join_argument_vector(&build_launch_argument_vector(&preset))), // C (3.c.) Ok(); B (2.a.) join_argument_vector(); B (2.a.) build_launch_argument_vector()
+        Err(err) => Err(err),                                                      // C (3.c) Err()
+    }
+}
+
+/// ID: KP_018
+/// ABC analīzes rezultāts:5,11,7
+pub fn expand_launch_variables(
+    value: &str,
+    resolve_variable: &dyn Fn(&str) -> Option<String>,
+) -> Result<String, String> {
+    let mut expanded_value = String::new(); // A (1.a.) let mut expanded_value =; B (2.a.) ...::new()
+    let mut remaining_value = value; // A (1.a.) let mut remaining_value =
+    while let Some(start) = remaining_value.find("${") {
+        // A (1.d.) while let Some(); B (2.a.) .find()
+        expanded_value.push_str(&remaining_value[..start]); // A (1.c.) .push_str()
+        let Some(length) = remaining_value[start..].find('}') else {
+            // A (1.d.) let Some() else; B (2.a.) .find()
+            return Err(format!(
+                "Unterminated variable at position {} in \"{}\"",
+                value.len() - remaining_value.len() + start + 1,
+                value
+            )); // B (2.b.) priekšlaicīgs return; B (2.a.) .len(); B (2.a.) .len()
+        };
+        let name = &remaining_value[start + 2..start + length]; // A (1.a.) let name =
+        match resolve_variable(name) {
+            // C (3.b) match; B (2.a.) resolve_variable()
+            Some(val) => expanded_value.push_str(&val), // C (3.c) Some(); A (1.c.) .push_str()
+            None => return Err(format!("Unknown variable ${{{}}} in \"{}\"", name, value)), // C (3.c) None =>; B (2.b.) priekšlaicīgs return
+        }
+        remaining_value = &remaining_value[start + length + 1..]; // A (1.a.) remaining_value =
+    }
+    expanded_value.push_str(remaining_value); // A (1.c.) .push_str()
+    Ok(expanded_value)
+}
+
+/// ID: KP_019
+/// ABC analīzes rezultāts:1,9,7
+pub fn validate_launch_environment(environment: &LaunchEnvironment) -> Result<(), String> {
+    // Values are only checked for syntax, variables resolve when the project file is known.
+    let resolve_any = |_: &str| Some(String::new()); // A (1.a.) let resolve_any =; B (2.a.) ...::new()
+    for variable in &environment.variables {
+        // A (1.a.) let variable =;
+        if variable.name.is_empty() || variable.name.contains(['=', '\0']) {
+            // C (3.a.) .is_empty() == true; C (3.a.) .contains() == true; B (2.a.) .is_empty(); B (2.a.) .contains()
+            return Err(format!(
+                "Invalid environment variable name \"{}\"",
+                variable.name
+            )); // B (2.b.) priekšlaicīgs return
+        }
+        expand_launch_variables(&variable.value, &resolve_any)?; // B (2.a.) expand_launch_variables()
+    }
+    if let Some(working_directory) = &environment.working_directory {
+        // A (1.d.) if let Some()
+        expand_launch_variables(working_directory, &resolve_any)?; // B (2.a.) expand_launch_variables()
+    }
+    Ok(())
+}
+
+/// ID: KP_020
+/// ABC analīzes rezultāts:6,20,9
+pub fn get_launch_environment(
+    entry: &LaunchArgument,
+    project_file_path: Option<&str>,
+) -> Result<LaunchEnvironment, String> {
+    let variables: Vec<LaunchEnvironmentVariable> =
+        match serde_json::from_str(&entry.environment_json) {
+            // A (1.a.) let variables =; C (3.b) match; B (2.a.) ::from_str()
+            Ok(val) => val, // C (3.c.) Ok()
+            Err(err) => return Err(format!("Failed to parse launch environment: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+        };
+    let project_file_path = project_file_path.map(std::path::Path::new); // A (1.a.) let project_file_path =; B (2.a.) .map()
+
+    // Project variables take precedence over variables of the launcher process environment.
+    let resolve_variable = |name: &str| match (name, project_file_path) {
+        // A (1.a.) let resolve_variable =; C (3.b) match
+        ("PROJECT_DIR", Some(path)) => path.parent().map(|val| val.to_string_lossy().to_string()), // C (3.c) PROJECT_DIR =>; B (2.a.) .parent(); B (2.a.) .map(); B (2.a.) .to_string_lossy(); B (2.a.) .to_string()
+        ("PROJECT_FILE", Some(path)) => Some(path.to_string_lossy().to_string()), // C (3.c) PROJECT_FILE =>; B (2.a.) .to_string_lossy(); B (2.a.) .to_string()
+        ("PROJECT_NAME", Some(path)) => path
+            .file_stem()
+            .map(|val| val.to_string_lossy().to_string()), // C (3.c) PROJECT_NAME =>; B (2.a.) .file_stem(); B (2.a.) .map(); B (2.a.) .to_string_lossy(); B (2.a.) .to_string()
+        _ => std::env::var(name).ok(), // C (3.c) _ =>; B (2.a.) ::var(); B (2.a.) .ok()
+    };
+    let mut environment = LaunchEnvironment::default(); // A (1.a.) let mut environment =; B (2.a.) ::default()
+    for mut variable in variables {
+        // A (1.a.) let mut variable =;
+        variable.value = expand_launch_variables(&variable.value, &resolve_variable)?; // A (1.a.) variable.value =; B (2.a.) expand_launch_variables()
+        environment.variables.push(variable); // A (1.c.) .push()
+    }
+    if let Some(working_directory) = &entry.working_directory {
+        // A (1.d.) if let Some()
+        environment.working_directory = Some(expand_launch_variables(
+            working_directory,
+            &resolve_variable,
+        )?); // A (1.a.) environment.working_directory =; B (2.a.) expand_launch_variables()
+    }
+    Ok(environment)
+}
+
+/// ID: KP_021
+/// ABC analīzes rezultāts:4,16,6
+#[tauri::command]
+pub async fn update_launch_argument_environment(
+    app: AppHandle,
+    state: tauri::State<'_, AppState>,
+    id: String,
+    environment: LaunchEnvironment,
+) -> Result<(), String> {
+    if let Err(err) = validate_launch_environment(&environment) {
+        // A (1.d.) if let Err(); B (2.a.) validate_launch_environment()
+        show_ok_notification(
+            // B (2.a.) show_ok_notification()
+            app.clone(), // B (2.a.) app.clone();
+            format!("Failed to validate launch environment: {}", err),
+            tauri_plugin_dialog::MessageDialogKind::Error,
+        );
+        return Err(format!("Failed to validate launch environment: {}", err)); // B (2.b.) priekšlaicīgs return
+    }
+    let repository = LaunchArgumentRepository::new(&state.pool); // A (1.a.) let repository =; B (2.a.) ...::new()
+    let mut results = match repository.fetch(Some(&id), None, None).await {
+        // A (1.a.) let mut results =; C (3.b.) match; B (2.a.) repository.fetch();
+        Ok(val) => val, // C (3.c) Ok()
+        Err(err) => {
+            // C (3.c) Err();
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to fetch launch arguments: {:?}", err),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            return Err(format!("Failed to fetch launch arguments: {:?}", err)); // B (2.b.) priekšlaicīgs return
+        }
+    };
+    if results.is_empty() {
+        // B (2.a.) results.is_empty(); C (3.a) results.is_empty() == true
+        return Err(format!("Failed to fetch launch arguments by ID")); // B (2.b.) priekšlaicīgs return
+    }
+    let mut entry = results.remove(0); // A (1.a.) let mut entry =; B (2.a.) results.remove();
+    entry.environment_json = serde_json::to_string(&environment.variables).unwrap_or_default(); // A (1.a.) entry.environment_json =; B (2.a.) ::to_string(); B (2.a.) .unwrap_or_default()
+    entry.working_directory = environment.working_directory.filter(|val| !val.is_empty()); // A (1.a.) entry.working_directory =; B (2.a.) .filter(); B (2.a.) .is_empty()
+    match repository.update(&entry).await {
+        // C (3.b.) match; B (2.a.) repository.update()
+        Ok(_) => Ok(()), // C (3.c) Ok();
+        Err(err) => {
+            // C (3.c) Err();
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to update existing launch arguments: {:?}", err),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            Err(format!(
+                "Failed to update existing launch arguments: {:?}",
+                err
+            ))
+        }
+    }
+}
+
+/// ID: KP_022
+/// ABC analīzes rezultāts:6,22,9
+pub fn build_python_launch_arguments(
+    launch_arguments: Vec<String>,
+    python_script_paths: Vec<String>,
+    python_expressions: Vec<String>,
+    script_arguments: Vec<String>,
+) -> Vec<String> {
+    let mut launch_arguments = launch_arguments; // A (1.a.) let mut launch_arguments =
+
+    // Everything after -- is passed to the scripts, Blender itself never reads it.
+    let mut trailing_arguments = match launch_arguments.iter().position(|val| val == "--") {
+        // A (1.a.) let mut trailing_arguments =; C (3.b) match; B (2.a.) .iter(); B (2.a.) .position(); C (3.a.) val == "--"
+        Some(val) => launch_arguments.split_off(val + 1), // C (3.c) Some(); B (2.a.) .split_off()
+        None => vec![],                                   // C (3.c) None =>
+    };
+    if launch_arguments
+        .last()
+        .map(|val| val == "--")
+        .unwrap_or(false)
+    {
+        // C (3.a.) val == "--"; B (2.a.) .last(); B (2.a.) .map(); B (2.a.) .unwrap_or()
+        launch_arguments.pop(); // B (2.a.) .pop()
+    }
+    // Older launch argument strings end with a bare --python that expected the selected script.
+    if launch_arguments
+        .last()
+        .map(|val| val == "--python" || val == "-P")
+        .unwrap_or(false)
+    {
+        // C (3.a.) val == "--python"; C (3.a.) val == "-P"; B (2.a.) .last(); B (2.a.) .map(); B (2.a.) .unwrap_or()
+        launch_arguments.pop(); // B (2.a.) .pop()
+    }
+    let mut python_arguments: Vec<String> = vec![]; // A (1.a.) let mut python_arguments =
+    for python_script_path in python_script_paths {
+        // A (1.a.) let python_script_path =;
+        python_arguments.push("--python".to_string()); // A (1.c.) .push(); B (2.a.) .to_string()
+        python_arguments.push(python_script_path); // A (1.c.) .push()
+    }
+    for python_expression in python_expressions {
+        // A (1.a.) let python_expression =;
+        python_arguments.push("--python-expr".to_string()); // A (1.c.) .push(); B (2.a.) .to_string()
+        python_arguments.push(python_expression); // A (1.c.) .push()
+    }
+    // Blender handles arguments in order, scripts have to run before a render action starts rendering.
+    let render_action_index = launch_arguments
+        .iter()
+        .position(|val| {
+            find_blender_cli_option(val)
+                .map(|cli_option| cli_option.is_render_action)
+                .unwrap_or(false)
+        })
+        .unwrap_or(launch_arguments.len()); // A (1.a.) let render_action_index =; B (2.a.) .iter(); B (2.a.) .position(); B (2.a.) find_blender_cli_option(); B (2.a.) .map(); B (2.a.) .unwrap_or(); B (2.a.) .unwrap_or(); B (2.a.) .len()
+    launch_arguments.splice(render_action_index..render_action_index, python_arguments); // B (2.a.) .splice()
+    trailing_arguments.extend(script_arguments); // A (1.c.) .extend()
+    if !trailing_arguments.is_empty() {
+        // C (3.a.) trailing_arguments.is_empty() != true; B (2.a.) .is_empty()
+        launch_arguments.push("--".to_string()); // A (1.c.) .push(); B (2.a.) .to_string()
+        launch_arguments.extend(trailing_arguments); // A (1.c.) .extend()
+    }
+    launch_arguments
+}
+
+/// ID: KP_023
+/// ABC analīzes rezultāts:3,8,4
+pub fn get_launch_argument_preset_warnings(preset: &LaunchArgumentPreset) -> Vec<String> {
+    let mut warnings = Vec::new(); // A (1.a.) let mut warnings =; B (2.a.) ::new()
+    for option in &preset.options {
+        // A (1.a.) let option =;
+        let Some(cli_option) = find_blender_cli_option(&option.name) else {
+            // A (1.d.) let Some() else; B (2.a.) find_blender_cli_option()
+            continue; // B (2.b.) continue
+        };
+        for (value_type, value) in cli_option.value_types.iter().zip(&option.values) {
+            // A (1.a.) let (value_type, value) =; B (2.a.) .iter(); B (2.a.) .zip()
+            if let BlenderCliValueType::SuggestedText(suggestions) = value_type {
+                // A (1.d.) if let SuggestedText()
+                if !suggestions.contains(&value.as_str()) {
+                    // C (3.a.) .contains() != true; B (2.a.) .contains(); B (2.a.) .as_str()
+                    warnings.push(format!(
+                        "Unknown value \"{}\" for option \"{}\", known values are {}",
+                        value,
+                        cli_option.name,
+                        suggestions.join(", ")
+                    )); // A (1.c.) .push(); B (2.a.) .join()
+                }
+            }
+        }
+    }
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_strings(arguments: &[&str]) -> Vec<String> {
+        arguments.iter().map(|val| val.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_argument_string_splits_shell_words() {
+        assert_eq!(
+            parse_argument_string("  -b  scene.blend\t-a\n").unwrap(),
+            to_strings(&["-b", "scene.blend", "-a"])
+        );
+        assert_eq!(
+            parse_argument_string(r#"--python "my script.py" -o '//render/frame_####'"#).unwrap(),
+            to_strings(&["--python", "my script.py", "-o", "//render/frame_####"])
+        );
+        assert_eq!(
+            parse_argument_string(r#"a\ b "c\"d" "e\f" 'g\h' x"y"'z' '' "#).unwrap(),
+            to_strings(&["a b", "c\"d", "e\\f", "g\\h", "xyz", ""])
+        );
+        assert_eq!(
+            parse_argument_string("-b \\\nscene.blend").unwrap(),
+            to_strings(&["-b", "scene.blend"])
+        );
+        assert!(parse_argument_string("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn parse_argument_string_keeps_windows_paths() {
+        assert_eq!(
+            parse_argument_string(r"--python C:\scripts\a.py -o D:\renders\").unwrap(),
+            to_strings(&["--python", r"C:\scripts\a.py", "-o", r"D:\renders\"])
+        );
+        assert_eq!(
+            parse_argument_string(r#"C:\my\ dir\\x \"q\" \'s\' "C:\new folder\n.py""#).unwrap(),
+            to_strings(&[r"C:\my dir\x", "\"q\"", "'s'", r"C:\new folder\n.py"])
+        );
+    }
+
+    #[test]
+    fn parse_argument_string_reports_error_positions() {
+        assert_eq!(
+            parse_argument_string("-b 'scene.blend").unwrap_err(),
+            "Unbalanced single quote at position 4"
+        );
+        assert_eq!(
+            parse_argument_string("--python \"script.py").unwrap_err(),
+            "Unbalanced double quote at position 10"
+        );
+        // Positions count characters, not bytes.
+        assert_eq!(
+            parse_argument_string("ščēne \"x").unwrap_err(),
+            "Unbalanced double quote at position 7"
+        );
+    }
+
+    #[test]
+    fn join_argument_vector_round_trips() {
+        let cases = [
+            to_strings(&["-b", "scene.blend", "-a"]),
+            to_strings(&["--python", "my script.py", "--", "--name=it's"]),
+            to_strings(&["", "\"quoted\"", "back\\slash", "tab\there", "new\nline"]),
+            to_strings(&["$HOME", "`cmd`", "*.blend", "a;b", "ščēne"]),
+        ];
+        for arguments in cases {
+            let argument_string = join_argument_vector(&arguments);
+            assert_eq!(parse_argument_string(&argument_string).unwrap(), arguments);
+        }
+        assert_eq!(
+            join_argument_vector(&to_strings(&["-b", "my file.blend", "it's"])),
+            r#"-b 'my file.blend' 'it'\''s'"#
+        );
+    }
+
+    #[test]
+    fn unknown_engine_ids_are_warned_about() {
+        let preset = |engine: &str| LaunchArgumentPreset {
+            options: vec![LaunchArgumentOption {
+                name: String::from("--engine"),
+                values: vec![engine.to_string()],
+            }],
+            ..Default::default()
+        };
+        assert!(validate_launch_argument_preset(&preset("CYCLES")).is_ok());
+        assert!(get_launch_argument_preset_warnings(&preset("CYCLES")).is_empty());
+        assert!(validate_launch_argument_preset(&preset("OCTANE")).is_ok());
+        assert_eq!(
+            get_launch_argument_preset_warnings(&preset("OCTANE")).len(),
+            1
+        );
+        assert!(validate_launch_argument_preset(&preset("")).is_err());
+    }
+
+    #[test]
+    fn build_launch_argument_vector_puts_render_actions_last() {
+        let option = |name: &str, values: &[&str]| LaunchArgumentOption {
+            name: name.to_string(),
+            values: to_strings(values),
+        };
+        let preset = LaunchArgumentPreset {
+            options: vec![
+                option("--render-frame", &["1..10"]),
+                option("--background", &[]),
+                option("--engine", &["CYCLES"]),
+                option("--unknown", &["ignored"]),
+            ],
+            passthrough_arguments: to_strings(&["-o", "//frames/####", "--", "--seed=1"]),
+        };
+        assert_eq!(
+            build_launch_argument_vector(&preset),
+            to_strings(&[
+                "--background",
+                "--engine",
+                "CYCLES",
+                "-o",
+                "//frames/####",
+                "--render-frame",
+                "1..10",
+                "--",
+                "--seed=1",
+            ])
+        );
+    }
+
+    #[test]
+    fn build_python_launch_arguments_keeps_script_order() {
+        assert_eq!(
+            build_python_launch_arguments(
+                to_strings(&["-b", "scene.blend"]),
+                to_strings(&["first.py", "second.py", "third.py"]),
+                to_strings(&["print(1)"]),
+                vec![],
+            ),
+            to_strings(&[
+                "-b",
+                "scene.blend",
+                "--python",
+                "first.py",
+                "--python",
+                "second.py",
+                "--python",
+                "third.py",
+                "--python-expr",
+                "print(1)",
+            ])
+        );
+    }
+
+    #[test]
+    fn build_python_launch_arguments_keeps_arguments_after_separator() {
+        assert_eq!(
+            build_python_launch_arguments(
+                to_strings(&["-b", "--", "--seed=1", "--", "-a"]),
+                to_strings(&["script.py"]),
+                vec![],
+                to_strings(&["--frames=10"]),
+            ),
+            to_strings(&[
+                "-b",
+                "--python",
+                "script.py",
+                "--",
+                "--seed=1",
+                "--",
+                "-a",
+                "--frames=10",
+            ])
+        );
+        assert_eq!(
+            build_python_launch_arguments(to_strings(&["-b", "--"]), vec![], vec![], vec![]),
+            to_strings(&["-b"])
+        );
+    }
+
+    #[test]
+    fn build_python_launch_arguments_strips_legacy_bare_python() {
+        for legacy_argument in ["--python", "-P"] {
+            assert_eq!(
+                build_python_launch_arguments(
+                    to_strings(&["-b", legacy_argument]),
+                    to_strings(&["script.py"]),
+                    vec![],
+                    vec![],
+                ),
+                to_strings(&["-b", "--python", "script.py"])
+            );
+            assert_eq!(
+                build_python_launch_arguments(
+                    to_strings(&["-b", legacy_argument, "--", "--seed=1"]),
+                    to_strings(&["script.py"]),
+                    vec![],
+                    vec![],
+                ),
+                to_strings(&["-b", "--python", "script.py", "--", "--seed=1"])
+            );
+        }
+    }
+
+    #[test]
+    fn build_python_launch_arguments_runs_scripts_before_render_actions() {
+        assert_eq!(
+            build_python_launch_arguments(
+                to_strings(&["-b", "scene.blend", "-E", "CYCLES", "-f", "1", "-a"]),
+                to_strings(&["script.py"]),
+                vec![],
+                vec![],
+            ),
+            to_strings(&[
+                "-b",
+                "scene.blend",
+                "-E",
+                "CYCLES",
+                "--python",
+                "script.py",
+                "-f",
+                "1",
+                "-a",
+            ])
+        );
+        assert_eq!(
+            build_python_launch_arguments(
+                to_strings(&["-b", "--render-anim"]),
+                vec![],
+                to_strings(&["print(1)"]),
+                vec![],
+            ),
+            to_strings(&["-b", "--python-expr", "print(1)", "--render-anim"])
+        );
+    }
+}
@@ -0,0 +1,512 @@
+use crate::models::{BlenderCliOption, BlenderCliValueType};
+
+const NO_VALUES: &[BlenderCliValueType] = &[];
+const INTEGER_VALUE: &[BlenderCliValueType] = &[BlenderCliValueType::Integer];
+const FRAME_VALUE: &[BlenderCliValueType] = &[BlenderCliValueType::Frame];
+const FRAME_LIST_VALUE: &[BlenderCliValueType] = &[BlenderCliValueType::FrameList];
+const PATH_VALUE: &[BlenderCliValueType] = &[BlenderCliValueType::Path];
+const TEXT_VALUE: &[BlenderCliValueType] = &[BlenderCliValueType::Text];
+const WINDOW_GEOMETRY_VALUES: &[BlenderCliValueType] = &[BlenderCliValueType::Integer; 4];
+const BOOLEAN_VALUE: &[BlenderCliValueType] = &[BlenderCliValueType::Choice(&["0", "1"])];
+// Add-ons register render engines of their own, so unknown engine ids are only warned about.
+const ENGINE_VALUE: &[BlenderCliValueType] = &[BlenderCliValueType::SuggestedText(&[
+    "BLENDER_EEVEE_NEXT",
+    "BLENDER_EEVEE",
+    "BLENDER_WORKBENCH",
+    "CYCLES",
+])];
+const RENDER_FORMAT_VALUE: &[BlenderCliValueType] = &[BlenderCliValueType::Choice(&[
+    "TGA",
+    "RAWTGA",
+    "JPEG",
+    "IRIS",
+    "AVIRAW",
+    "AVIJPEG",
+    "PNG",
+    "BMP",
+    "HDR",
+    "TIFF",
+    "OPEN_EXR",
+    "OPEN_EXR_MULTILAYER",
+    "FFMPEG",
+    "CINEON",
+    "DPX",
+    "JP2",
+    "WEBP",
+])];
+const GPU_BACKEND_VALUE: &[BlenderCliValueType] =
+    &[BlenderCliValueType::Choice(&["vulkan", "opengl", "metal"])];
+const AUDIO_DEVICE_VALUE: &[BlenderCliValueType] = &[BlenderCliValueType::Choice(&[
+    "None",
+    "SDL",
+    "OpenAL",
+    "CoreAudio",
+    "JACK",
+    "PulseAudio",
+    "WASAPI",
+])];
+
+/// Command line options of Blender 4.x, render actions have to follow the render settings they use.
+pub const BLENDER_CLI_OPTIONS: &[BlenderCliOption] = &[
+    BlenderCliOption {
+        name: "--background",
+        short_name: Some("-b"),
+        category: "general",
+        value_types: NO_VALUES,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Run in background, often used for UI-less rendering",
+    },
+    BlenderCliOption {
+        name: "--factory-startup",
+        short_name: None,
+        category: "general",
+        value_types: NO_VALUES,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Skip reading the startup file and user preferences",
+    },
+    BlenderCliOption {
+        name: "--app-template",
+        short_name: None,
+        category: "general",
+        value_types: TEXT_VALUE,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Set the application template, use 'default' for none",
+    },
+    BlenderCliOption {
+        name: "--addons",
+        short_name: None,
+        category: "general",
+        value_types: TEXT_VALUE,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Comma separated list of add-ons to enable",
+    },
+    BlenderCliOption {
+        name: "--open-last",
+        short_name: None,
+        category: "general",
+        value_types: NO_VALUES,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Open the most recently opened blend file",
+    },
+    BlenderCliOption {
+        name: "--scene",
+        short_name: Some("-S"),
+        category: "general",
+        value_types: TEXT_VALUE,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Switch to the named scene before rendering",
+    },
+    BlenderCliOption {
+        name: "--enable-autoexec",
+        short_name: Some("-y"),
+        category: "python",
+        value_types: NO_VALUES,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &["--disable-autoexec"],
+        description: "Enable automatic Python script execution",
+    },
+    BlenderCliOption {
+        name: "--disable-autoexec",
+        short_name: Some("-Y"),
+        category: "python",
+        value_types: NO_VALUES,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &["--enable-autoexec"],
+        description: "Disable automatic Python script execution",
+    },
+    BlenderCliOption {
+        name: "--python",
+        short_name: Some("-P"),
+        category: "python",
+        value_types: PATH_VALUE,
+        is_repeatable: true,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Run the given Python script file",
+    },
+    BlenderCliOption {
+        name: "--python-text",
+        short_name: None,
+        category: "python",
+        value_types: TEXT_VALUE,
+        is_repeatable: true,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Run the given Python script text block",
+    },
+    BlenderCliOption {
+        name: "--python-expr",
+        short_name: None,
+        category: "python",
+        value_types: TEXT_VALUE,
+        is_repeatable: true,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Run the given expression as a Python script",
+    },
+    BlenderCliOption {
+        name: "--python-console",
+        short_name: None,
+        category: "python",
+        value_types: NO_VALUES,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Run Blender with an interactive console",
+    },
+    BlenderCliOption {
+        name: "--python-exit-code",
+        short_name: None,
+        category: "python",
+        value_types: INTEGER_VALUE,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Exit code used when a Python script raises an exception",
+    },
+    BlenderCliOption {
+        name: "--python-use-system-env",
+        short_name: None,
+        category: "python",
+        value_types: NO_VALUES,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Allow Python to use system environment variables",
+    },
+    BlenderCliOption {
+        name: "--render-output",
+        short_name: Some("-o"),
+        category: "render",
+        value_types: PATH_VALUE,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Set the render path and file name",
+    },
+    BlenderCliOption {
+        name: "--engine",
+        short_name: Some("-E"),
+        category: "render",
+        value_types: ENGINE_VALUE,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Specify the render engine",
+    },
+    BlenderCliOption {
+        name: "--render-format",
+        short_name: Some("-F"),
+        category: "render",
+        value_types: RENDER_FORMAT_VALUE,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Set the render format",
+    },
+    BlenderCliOption {
+        name: "--use-extension",
+        short_name: Some("-x"),
+        category: "render",
+        value_types: BOOLEAN_VALUE,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Set option to add the file extension to the end of the file",
+    },
+    BlenderCliOption {
+        name: "--threads",
+        short_name: Some("-t"),
+        category: "render",
+        value_types: INTEGER_VALUE,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description:
+            "Use amount of threads for rendering and other operations, 0 for system processor count",
+    },
+    BlenderCliOption {
+        name: "--frame-start",
+        short_name: Some("-s"),
+        category: "render",
+        value_types: FRAME_VALUE,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Set start frame to render",
+    },
+    BlenderCliOption {
+        name: "--frame-end",
+        short_name: Some("-e"),
+        category: "render",
+        value_types: FRAME_VALUE,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Set end frame to render",
+    },
+    BlenderCliOption {
+        name: "--frame-jump",
+        short_name: Some("-j"),
+        category: "render",
+        value_types: INTEGER_VALUE,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Set number of frames to step forward after each rendered frame",
+    },
+    BlenderCliOption {
+        name: "--render-frame",
+        short_name: Some("-f"),
+        category: "render",
+        value_types: FRAME_LIST_VALUE,
+        is_repeatable: true,
+        is_render_action: true,
+        conflicts_with: &["--render-anim"],
+        description: "Render frame(s) and save them",
+    },
+    BlenderCliOption {
+        name: "--render-anim",
+        short_name: Some("-a"),
+        category: "render",
+        value_types: NO_VALUES,
+        is_repeatable: false,
+        is_render_action: true,
+        conflicts_with: &["--render-frame"],
+        description: "Render frames from start to end",
+    },
+    BlenderCliOption {
+        name: "--window-border",
+        short_name: Some("-w"),
+        category: "window",
+        value_types: NO_VALUES,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &["--window-fullscreen", "--window-maximized"],
+        description: "Force opening with borders",
+    },
+    BlenderCliOption {
+        name: "--window-fullscreen",
+        short_name: Some("-W"),
+        category: "window",
+        value_types: NO_VALUES,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &["--window-border", "--window-maximized"],
+        description: "Force opening in fullscreen mode",
+    },
+    BlenderCliOption {
+        name: "--window-maximized",
+        short_name: Some("-M"),
+        category: "window",
+        value_types: NO_VALUES,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &["--window-border", "--window-fullscreen"],
+        description: "Force opening maximized",
+    },
+    BlenderCliOption {
+        name: "--window-geometry",
+        short_name: Some("-p"),
+        category: "window",
+        value_types: WINDOW_GEOMETRY_VALUES,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Open with lower left corner at x, y and width and height",
+    },
+    BlenderCliOption {
+        name: "--start-console",
+        short_name: Some("-con"),
+        category: "window",
+        value_types: NO_VALUES,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Start with the console window open, Windows only",
+    },
+    BlenderCliOption {
+        name: "--no-native-pixels",
+        short_name: None,
+        category: "window",
+        value_types: NO_VALUES,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Do not use native pixel size for high resolution screens",
+    },
+    BlenderCliOption {
+        name: "--no-window-focus",
+        short_name: None,
+        category: "window",
+        value_types: NO_VALUES,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Open behind other windows and without taking focus",
+    },
+    BlenderCliOption {
+        name: "--gpu-backend",
+        short_name: None,
+        category: "window",
+        value_types: GPU_BACKEND_VALUE,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Force to use a specific GPU backend",
+    },
+    BlenderCliOption {
+        name: "--online-mode",
+        short_name: None,
+        category: "network",
+        value_types: NO_VALUES,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &["--offline-mode"],
+        description: "Allow internet access, overriding the preference",
+    },
+    BlenderCliOption {
+        name: "--offline-mode",
+        short_name: None,
+        category: "network",
+        value_types: NO_VALUES,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &["--online-mode"],
+        description: "Disallow internet access, overriding the preference",
+    },
+    BlenderCliOption {
+        name: "-noaudio",
+        short_name: None,
+        category: "misc",
+        value_types: NO_VALUES,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &["-setaudio"],
+        description: "Force sound system to none",
+    },
+    BlenderCliOption {
+        name: "-setaudio",
+        short_name: None,
+        category: "misc",
+        value_types: AUDIO_DEVICE_VALUE,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &["-noaudio"],
+        description: "Force sound system to a specific device",
+    },
+    BlenderCliOption {
+        name: "--debug",
+        short_name: Some("-d"),
+        category: "debug",
+        value_types: NO_VALUES,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Turn debugging on",
+    },
+    BlenderCliOption {
+        name: "--debug-value",
+        short_name: None,
+        category: "debug",
+        value_types: INTEGER_VALUE,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Set debug value on startup",
+    },
+    BlenderCliOption {
+        name: "--log",
+        short_name: None,
+        category: "debug",
+        value_types: TEXT_VALUE,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Enable logging categories, taking a comma separated list",
+    },
+    BlenderCliOption {
+        name: "--log-level",
+        short_name: None,
+        category: "debug",
+        value_types: INTEGER_VALUE,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Set the logging verbosity level",
+    },
+    BlenderCliOption {
+        name: "--log-file",
+        short_name: None,
+        category: "debug",
+        value_types: PATH_VALUE,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Set a file to output the log to",
+    },
+    BlenderCliOption {
+        name: "--env-system-datafiles",
+        short_name: None,
+        category: "misc",
+        value_types: PATH_VALUE,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Set the BLENDER_SYSTEM_DATAFILES environment variable",
+    },
+    BlenderCliOption {
+        name: "--env-system-scripts",
+        short_name: None,
+        category: "misc",
+        value_types: PATH_VALUE,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Set the BLENDER_SYSTEM_SCRIPTS environment variable",
+    },
+    BlenderCliOption {
+        name: "--env-system-python",
+        short_name: None,
+        category: "misc",
+        value_types: PATH_VALUE,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &[],
+        description: "Set the BLENDER_SYSTEM_PYTHON environment variable",
+    },
+    BlenderCliOption {
+        name: "--register",
+        short_name: Some("-r"),
+        category: "misc",
+        value_types: NO_VALUES,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &["--unregister"],
+        description: "Register blend-file extension for current user",
+    },
+    BlenderCliOption {
+        name: "--unregister",
+        short_name: None,
+        category: "misc",
+        value_types: NO_VALUES,
+        is_repeatable: false,
+        is_render_action: false,
+        conflicts_with: &["--register"],
+        description: "Unregister blend-file extension for current user",
+    },
+];
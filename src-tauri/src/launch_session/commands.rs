@@ -0,0 +1,188 @@
+use crate::{
+    db_repo::LaunchSessionRepository,
+    file_system_utility::show_ok_notification,
+    models::{BlenderCrashReport, LaunchSession},
+    AppState,
+};
+use tauri::AppHandle;
+
+/// ID: LS_001
+/// ABC analīzes rezultāts:2,8,3
+#[tauri::command]
+pub async fn fetch_launch_sessions(
+    app: AppHandle,
+    state: tauri::State<'_, AppState>,
+    id: Option<String>,
+    limit: Option<i64>,
+    installed_blender_version_id: Option<String>,
+    project_file_id: Option<String>,
+    only_failed: Option<bool>,
+) -> Result<Vec<LaunchSession>, String> {
+    let repository = LaunchSessionRepository::new(&state.pool); // A (1.a.) let repository =; B (2.a.) ...::new()
+    match repository
+        .fetch(
+            id.as_deref(),
+            limit,
+            installed_blender_version_id.as_deref(),
+            project_file_id.as_deref(),
+            only_failed.unwrap_or(false),
+        ) // C (3.b.) match; B (2.a.) repository.fetch(); B (2.a.) .as_deref(); B (2.a.) .as_deref(); B (2.a.) .as_deref(); B (2.a.) .unwrap_or()
+        .await
+    {
+        Ok(val) => Ok(val), // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c) Err()
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to fetch launch sessions: {:?}", err),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            return Err(format!("Failed to fetch launch sessions: {:?}", err)); // B (2.b.) priekšlaicīgs return
+        }
+    }
+}
+
+/// ID: LS_002
+/// ABC analīzes rezultāts:4,12,7
+#[tauri::command]
+pub async fn read_launch_session_log(
+    app: AppHandle,
+    state: tauri::State<'_, AppState>,
+    id: String,
+) -> Result<String, String> {
+    let repository = LaunchSessionRepository::new(&state.pool); // A (1.a.) let repository =; B (2.a.) ...::new()
+    let mut results = match repository.fetch(Some(&id), None, None, None, false).await {
+        // A (1.a.) let mut results =; C (3.b) match; B (2.a.) .fetch()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c) Err()
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to fetch launch sessions: {:?}", err),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            return Err(format!("Failed to fetch launch sessions: {:?}", err)); // B (2.b.) priekšlaicīgs return
+        }
+    };
+    if results.is_empty() {
+        // B (2.a.) .is_empty(); C (3.a) results.is_empty() == true
+        return Err(format!("Failed to fetch launch session by ID")); // B (2.b.) priekšlaicīgs return
+    }
+    let entry = results.remove(0); // A (1.a.) let entry =; B (2.a.) .remove()
+    let log_file_path = match entry.log_file_path {
+        // A (1.a.) let log_file_path =; C (3.b) match
+        Some(val) => val, // C (3.c) Some()
+        None => return Err(format!("Failed to read launch session log: log was purged")), // C (3.c) None =>; B (2.b.) priekšlaicīgs return
+    };
+    match std::fs::read_to_string(log_file_path) {
+        // C (3.b) match; B (2.a.) ::read_to_string()
+        Ok(val) => Ok(val), // C (3.c.) Ok()
+        Err(err) => Err(format!("Failed to read launch session log: {:?}", err)), // C (3.c) Err()
+    }
+}
+
+/// ID: LS_003
+/// ABC analīzes rezultāts:9,19,9
+#[tauri::command]
+pub async fn purge_launch_session_logs(
+    app: AppHandle,
+    state: tauri::State<'_, AppState>,
+    older_than_days: i64,
+) -> Result<i64, String> {
+    let cutoff = (chrono::Utc::now() - chrono::Duration::days(older_than_days)).to_rfc3339(); // A (1.a.) let cutoff =; B (2.a.) chrono::Utc::now(); B (2.a.) ::days(); B (2.a.) .to_rfc3339()
+    let repository = LaunchSessionRepository::new(&state.pool); // A (1.a.) let repository =; B (2.a.) ...::new()
+    let results = match repository.fetch_ended_before(&cutoff).await {
+        // A (1.a.) let results =; C (3.b) match; B (2.a.) .fetch_ended_before()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c) Err()
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to fetch launch sessions: {:?}", err),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            return Err(format!("Failed to fetch launch sessions: {:?}", err)); // B (2.b.) priekšlaicīgs return
+        }
+    };
+    let mut purged_count = 0; // A (1.a.) let mut purged_count =
+    for mut entry in results {
+        // A (1.a.) let mut entry =;
+        if let Some(log_file_path) = entry.log_file_path.take() {
+            // A (1.d.) if let Some(); B (2.a.) .take()
+            match std::fs::remove_file(&log_file_path) {
+                // C (3.b) match; B (2.a.) ::remove_file()
+                Ok(_) => {} // C (3.c.) Ok()
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {} // C (3.c) Err(); C (3.a.) err.kind() == NotFound; B (2.a.) .kind()
+                Err(err) => return Err(format!("Failed to delete launch session log: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+            }
+        }
+        if let Some(crash_report_file_path) = entry.crash_report_file_path.take() {
+            // A (1.d.) if let Some(); B (2.a.) .take()
+            let _ = std::fs::remove_file(&crash_report_file_path); // A (1.a.) let _ =; B (2.a.) ::remove_file()
+        }
+        match repository.update(&entry).await {
+            // C (3.b) match; B (2.a.) .update()
+            Ok(_) => purged_count += 1, // C (3.c.) Ok(); A (1.b.) purged_count +=
+            Err(err) => {
+                // C (3.c) Err()
+                show_ok_notification(
+                    // B (2.a.) show_ok_notification()
+                    app.clone(), // B (2.a.) app.clone();
+                    format!("Failed to update launch session: {:?}", err),
+                    tauri_plugin_dialog::MessageDialogKind::Error,
+                );
+                return Err(format!("Failed to update launch session: {:?}", err));
+                // B (2.b.) priekšlaicīgs return
+            }
+        }
+    }
+    Ok(purged_count)
+}
+
+/// ID: LS_004
+/// ABC analīzes rezultāts:5,16,6
+#[tauri::command]
+pub async fn read_launch_session_crash_report(
+    app: AppHandle,
+    state: tauri::State<'_, AppState>,
+    id: String,
+) -> Result<BlenderCrashReport, String> {
+    let repository = LaunchSessionRepository::new(&state.pool); // A (1.a.) let repository =; B (2.a.) ...::new()
+    let mut results = match repository.fetch(Some(&id), None, None, None, false).await {
+        // A (1.a.) let mut results =; C (3.b) match; B (2.a.) .fetch()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c) Err()
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to fetch launch sessions: {:?}", err),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            return Err(format!("Failed to fetch launch sessions: {:?}", err)); // B (2.b.) priekšlaicīgs return
+        }
+    };
+    if results.is_empty() {
+        // B (2.a.) .is_empty(); C (3.a) results.is_empty() == true
+        return Err(format!("Failed to fetch launch session by ID")); // B (2.b.) priekšlaicīgs return
+    }
+    let entry = results.remove(0); // A (1.a.) let entry =; B (2.a.) .remove()
+    Ok(BlenderCrashReport {
+        launch_session_id: entry.id,
+        installed_blender_version_id: entry.installed_blender_version_id,
+        exit_code: entry.exit_code.map(|val| val as i32), // B (2.a.) .map()
+        exit_signal: entry.exit_signal.map(|val| val as i32), // B (2.a.) .map()
+        crash_report: entry
+            .crash_report_file_path
+            .as_ref()
+            .and_then(|val| std::fs::read_to_string(val).ok()), // B (2.a.) .as_ref(); B (2.a.) .and_then(); B (2.a.) ::read_to_string(); B (2.a.) .ok()
+        crash_report_file_path: entry.crash_report_file_path,
+        stderr_tail: entry
+            .stderr_tail
+            .map(|val| val.lines().map(String::from).collect())
+            .unwrap_or_default(), // B (2.a.) .map(); B (2.a.) .lines(); B (2.a.) .map(); B (2.a.) .collect(); B (2.a.) .unwrap_or_default()
+    })
+}
@@ -3,21 +3,38 @@ use tauri::Manager;
 mod db_repo;
 mod models;
 
+mod blend_file;
 mod blender_version;
 mod file_system_utility;
+mod file_watcher;
 mod launch_argument;
+mod launch_session;
+mod process_supervisor;
 mod project_file;
 mod python_script;
 
 use crate::blender_version::*;
 use crate::file_system_utility::*;
+use crate::file_watcher::*;
 use crate::launch_argument::*;
+use crate::launch_session::*;
+use crate::process_supervisor::*;
 use crate::project_file::*;
 use crate::python_script::*;
 
 #[derive(Debug)]
 pub struct AppState {
     pub pool: sqlx::SqlitePool,
+    pub active_downloads: std::sync::Mutex<
+        std::collections::HashMap<String, std::sync::Arc<std::sync::atomic::AtomicBool>>,
+    >,
+    pub running_instances:
+        std::sync::Mutex<std::collections::HashMap<String, process_supervisor::SupervisedProcess>>,
+    pub file_watcher: std::sync::Mutex<
+        Option<
+            notify_debouncer_mini::Debouncer<notify_debouncer_mini::notify::RecommendedWatcher>,
+        >,
+    >,
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -42,12 +59,31 @@ pub async fn run() {
         .await
         .expect("Failed to run database migrations");
 
-    let app_state = AppState { pool };
+    let app_state = AppState {
+        pool,
+        active_downloads: std::sync::Mutex::new(std::collections::HashMap::new()),
+        running_instances: std::sync::Mutex::new(std::collections::HashMap::new()),
+        file_watcher: std::sync::Mutex::new(None),
+    };
 
     tauri::Builder::default()
         .plugin(tauri_plugin_dialog::init())
         .manage(app_state)
         .plugin(tauri_plugin_upload::init())
+        .setup(|app| {
+            // Reconcile once on startup, afterwards the watcher keeps the database in sync.
+            let app_handle = app.handle().clone();
+            tauri::async_runtime::spawn(async move {
+                let _ = insert_and_refresh_installed_blender_versions(
+                    app_handle.clone(),
+                    app_handle.state(),
+                )
+                .await;
+                let _ = insert_and_refresh_blend_files(app_handle.clone(), app_handle.state()).await;
+                let _ = restart_file_watcher(app_handle.clone(), app_handle.state()).await;
+            });
+            Ok(())
+        })
         // .setup(|app| {
         //     // Opens the developer tools when run in debug.
         //     #[cfg(debug_assertions)]
@@ -69,6 +105,9 @@ pub async fn run() {
             launch_blender_version_with_launch_args,
             get_downloadable_blender_version_data,
             download_and_install_blender_version,
+            download_blender_version,
+            cancel_blender_version_download,
+            insert_system_installed_blender_versions,
             insert_blender_version_installation_location,
             //
             insert_blend_file,
@@ -78,21 +117,45 @@ pub async fn run() {
             create_new_project_file,
             delete_blend_file,
             reveal_project_file_in_local_file_system,
-            create_project_file_archive_file,
+            pack_project_file,
+            fetch_project_file_thumbnail,
+            fetch_project_file_dependencies,
+            fetch_project_file_default_blender_version,
             //
             insert_python_script,
             fetch_python_scripts,
             delete_python_script,
+            update_python_script,
+            fetch_python_script_snapshots,
+            read_python_script_snapshot,
+            insert_python_script_library_folder,
+            fetch_python_script_library_folders,
+            delete_python_script_library_folder,
+            sync_python_script_library_folders,
             //
             insert_blender_version_installation_location,
             update_blender_version_installation_location,
+            update_blender_version_installation_location_scan_settings,
             fetch_blender_version_installation_locations,
             delete_blender_version_installation_location,
             //
+            restart_file_watcher,
+            //
+            fetch_running_blender_instances,
+            terminate_blender_instance,
+            //
+            fetch_launch_sessions,
+            read_launch_session_log,
+            purge_launch_session_logs,
+            read_launch_session_crash_report,
+            //
             insert_launch_argument,
             update_launch_argument,
             fetch_launch_arguments,
             delete_launch_argument,
+            fetch_blender_cli_options,
+            preview_launch_argument_preset,
+            update_launch_argument_environment,
             //
             instance_popup_window,
             identify_internet_connection
@@ -0,0 +1,50 @@
+#[derive(Default, Debug, Clone)]
+pub struct BlendFileHeader {
+    pub pointer_size: usize,
+    pub is_little_endian: bool,
+    pub version: u32,
+    pub file_format_version: u32,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct BlendFileBlock {
+    pub code: String,
+    pub old_address: u64,
+    pub data: Vec<u8>,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct BlendFileSdnaStruct {
+    pub type_index: usize,
+    pub fields: Vec<(usize, usize)>,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct BlendFileSdna {
+    pub names: Vec<String>,
+    pub types: Vec<String>,
+    pub type_lengths: Vec<usize>,
+    pub structs: Vec<BlendFileSdnaStruct>,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct BlendFileField {
+    pub offset: usize,
+    pub size: usize,
+    pub type_name: String,
+    pub is_pointer: bool,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct BlendFile {
+    pub header: BlendFileHeader,
+    pub blocks: Vec<BlendFileBlock>,
+    pub sdna: BlendFileSdna,
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct BlendFileThumbnail {
+    pub width: usize,
+    pub height: usize,
+    pub pixels: Vec<u8>,
+}
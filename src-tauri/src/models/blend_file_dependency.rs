@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+pub struct BlendFileDependency {
+    pub dependency_type: String,
+    pub name: String,
+    pub stored_path: String,
+    pub resolved_path: String,
+    pub is_packed: bool,
+    pub is_missing: bool,
+    pub dependencies: Vec<BlendFileDependency>,
+}
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+pub struct BlendFileMetadata {
+    pub saved_blender_version: String,
+    pub scene_names: Vec<String>,
+    pub frame_start: Option<i64>,
+    pub frame_end: Option<i64>,
+    pub render_engine: Option<String>,
+    pub resolution_x: Option<i64>,
+    pub resolution_y: Option<i64>,
+    pub resolution_percentage: Option<i64>,
+}
@@ -0,0 +1,24 @@
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Clone, Copy)]
+pub enum BlenderCliValueType {
+    Integer,
+    Frame,
+    FrameList,
+    Path,
+    Text,
+    Choice(&'static [&'static str]),
+    SuggestedText(&'static [&'static str]),
+}
+
+#[derive(Debug, Serialize, Clone, Copy)]
+pub struct BlenderCliOption {
+    pub name: &'static str,
+    pub short_name: Option<&'static str>,
+    pub category: &'static str,
+    pub value_types: &'static [BlenderCliValueType],
+    pub is_repeatable: bool,
+    pub is_render_action: bool,
+    pub conflicts_with: &'static [&'static str],
+    pub description: &'static str,
+}
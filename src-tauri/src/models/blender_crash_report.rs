@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+pub struct BlenderCrashReport {
+    pub launch_session_id: String,
+    pub installed_blender_version_id: String,
+    pub exit_code: Option<i32>,
+    pub exit_signal: Option<i32>,
+    pub crash_report: Option<String>,
+    pub crash_report_file_path: Option<String>,
+    pub stderr_tail: Vec<String>,
+}
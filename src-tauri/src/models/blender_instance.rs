@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+pub struct BlenderInstance {
+    pub id: String,
+    pub pid: Option<u32>,
+    pub installed_blender_version_id: String,
+    pub launch_argument_id: Option<String>,
+    pub python_script_ids: Vec<String>,
+    pub project_file_id: Option<String>,
+    pub arguments: Vec<String>,
+    pub started: String,
+    pub ended: Option<String>,
+    pub exit_code: Option<i32>,
+    pub exit_signal: Option<i32>,
+    pub is_running: bool,
+    pub log_file_path: Option<String>,
+}
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+pub struct BlenderInstanceOutput {
+    pub instance_id: String,
+    pub stream: String,
+    pub line: String,
+}
@@ -6,6 +6,9 @@ pub struct BlenderRepoPath {
     pub id: String,
     pub repo_directory_path: String,
     pub is_default: bool,
+    pub scan_depth: i64,
+    pub include_patterns: String,
+    pub exclude_patterns: String,
     pub created: String,
     pub modified: String,
     pub accessed: String,
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
+pub struct BlenderVersionInfo {
+    pub version: String,
+    pub variant_type: String,
+    pub build_hash: Option<String>,
+    pub build_date: Option<String>,
+    pub build_branch: Option<String>,
+}
@@ -10,6 +10,11 @@ pub struct InstalledBlenderVersion {
     pub is_default: bool,
     pub installation_directory_path: String,
     pub executable_file_path: String,
+    pub build_hash: Option<String>,
+    pub build_date: Option<String>,
+    pub build_branch: Option<String>,
+    pub install_source: String,
+    pub is_checksum_verified: Option<bool>,
     //  NaiveDateTime,
     pub created: String,
     pub modified: String,
@@ -6,6 +6,9 @@ pub struct LaunchArgument {
     pub id: String,
     pub is_default: bool,
     pub argument_string: String,
+    pub preset_json: Option<String>,
+    pub environment_json: String,
+    pub working_directory: Option<String>,
     pub last_used_project_file_id: Option<String>,
     pub last_used_python_script_id: Option<String>,
     pub created: String,
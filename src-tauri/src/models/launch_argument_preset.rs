@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+pub struct LaunchArgumentPreset {
+    pub options: Vec<LaunchArgumentOption>,
+    pub passthrough_arguments: Vec<String>,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+pub struct LaunchArgumentOption {
+    pub name: String,
+    pub values: Vec<String>,
+}
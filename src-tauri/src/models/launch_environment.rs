@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+pub struct LaunchEnvironment {
+    pub variables: Vec<LaunchEnvironmentVariable>,
+    pub working_directory: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LaunchEnvironmentVariable {
+    pub name: String,
+    pub action: LaunchEnvironmentAction,
+    pub value: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LaunchEnvironmentAction {
+    Set,
+    Unset,
+    PrependPath,
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+
+#[derive(Default, Debug, Serialize, Deserialize, FromRow)]
+pub struct LaunchSession {
+    pub id: String,
+    pub installed_blender_version_id: String,
+    pub blender_version: String,
+    pub launch_argument_id: Option<String>,
+    pub python_script_ids_json: String,
+    pub project_file_id: Option<String>,
+    pub arguments_json: String,
+    pub started: String,
+    pub ended: Option<String>,
+    pub exit_code: Option<i64>,
+    pub exit_signal: Option<i64>,
+    pub log_file_path: Option<String>,
+    pub crash_report_file_path: Option<String>,
+    pub stderr_tail: Option<String>,
+    pub created: String,
+    pub modified: String,
+    pub accessed: String,
+}
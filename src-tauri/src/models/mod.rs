@@ -1,13 +1,54 @@
+mod blend_file;
+mod blend_file_dependency;
+mod blend_file_metadata;
+mod blender_cli_option;
+mod blender_crash_report;
+mod blender_instance;
+mod blender_instance_output;
 mod blender_repo_path;
+mod blender_version_info;
+mod download_progress;
 mod downloadable_blender_version;
 mod installed_blender_version;
 mod launch_argument;
+mod launch_argument_preset;
+mod launch_environment;
+mod launch_session;
 mod project_file;
+mod project_file_archive_manifest;
 mod python_script;
+mod python_script_library_folder;
+mod python_script_metadata;
+mod python_script_safety_warning;
+mod python_script_snapshot;
+mod python_token;
 
+pub use blend_file::{
+    BlendFile, BlendFileBlock, BlendFileField, BlendFileHeader, BlendFileSdna, BlendFileSdnaStruct,
+    BlendFileThumbnail,
+};
+pub use blend_file_dependency::BlendFileDependency;
+pub use blend_file_metadata::BlendFileMetadata;
+pub use blender_cli_option::{BlenderCliOption, BlenderCliValueType};
+pub use blender_crash_report::BlenderCrashReport;
+pub use blender_instance::BlenderInstance;
+pub use blender_instance_output::BlenderInstanceOutput;
 pub use blender_repo_path::BlenderRepoPath;
+pub use blender_version_info::BlenderVersionInfo;
+pub use download_progress::DownloadProgress;
 pub use downloadable_blender_version::DownloadableBlenderVersion;
 pub use installed_blender_version::InstalledBlenderVersion;
 pub use launch_argument::LaunchArgument;
+pub use launch_argument_preset::{LaunchArgumentOption, LaunchArgumentPreset};
+pub use launch_environment::{
+    LaunchEnvironment, LaunchEnvironmentAction, LaunchEnvironmentVariable,
+};
+pub use launch_session::LaunchSession;
 pub use project_file::ProjectFile;
+pub use project_file_archive_manifest::{ProjectFileArchiveEntry, ProjectFileArchiveManifest};
 pub use python_script::PythonScript;
+pub use python_script_library_folder::PythonScriptLibraryFolder;
+pub use python_script_metadata::PythonScriptMetadata;
+pub use python_script_safety_warning::PythonScriptSafetyWarning;
+pub use python_script_snapshot::PythonScriptSnapshot;
+pub use python_token::{PythonToken, PythonTokenKind};
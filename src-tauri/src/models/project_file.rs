@@ -1,13 +1,23 @@
 use serde::{Deserialize, Serialize};
 use sqlx::prelude::FromRow;
 
-#[derive(Clone, Debug, Serialize, Deserialize, FromRow)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize, FromRow)]
 pub struct ProjectFile {
     pub id: String,
     pub file_path: String,
     pub file_name: String,
     pub associated_series_json: String,
     pub last_used_blender_version_id: Option<String>,
+    pub saved_blender_version: Option<String>,
+    pub scene_names_json: String,
+    pub frame_start: Option<i64>,
+    pub frame_end: Option<i64>,
+    pub render_engine: Option<String>,
+    pub resolution_x: Option<i64>,
+    pub resolution_y: Option<i64>,
+    pub resolution_percentage: Option<i64>,
+    pub metadata_file_modified: Option<String>,
+    pub file_hash: Option<String>,
     pub created: String,
     pub modified: String,
     pub accessed: String,
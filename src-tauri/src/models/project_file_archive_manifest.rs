@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+pub struct ProjectFileArchiveEntry {
+    pub dependency_type: String,
+    pub name: String,
+    pub stored_path: String,
+    pub source_path: String,
+    pub archive_path: Option<String>,
+    pub is_packed: bool,
+    pub is_missing: bool,
+    pub needs_relink: bool,
+}
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+pub struct ProjectFileArchiveManifest {
+    pub project_file_name: String,
+    pub saved_blender_version: Option<String>,
+    pub compression_method: String,
+    pub created: String,
+    pub entries: Vec<ProjectFileArchiveEntry>,
+}
@@ -1,10 +1,18 @@
 use serde::{Deserialize, Serialize};
 use sqlx::prelude::FromRow;
 
-#[derive(Debug, Serialize, Deserialize, FromRow)]
+#[derive(Default, Debug, Serialize, Deserialize, FromRow)]
 pub struct PythonScript {
     pub id: String,
     pub script_file_path: String,
+    pub name: String,
+    pub description: String,
+    pub metadata_json: String,
+    pub content_hash: Option<String>,
+    pub library_folder_id: Option<String>,
+    pub tags_json: String,
+    pub is_missing: bool,
+    pub safety_warnings_json: String,
     pub created: String,
     pub modified: String,
     pub accessed: String,
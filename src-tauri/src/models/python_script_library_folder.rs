@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+
+#[derive(Default, Debug, Serialize, Deserialize, FromRow)]
+pub struct PythonScriptLibraryFolder {
+    pub id: String,
+    pub folder_path: String,
+    pub created: String,
+    pub modified: String,
+    pub accessed: String,
+}
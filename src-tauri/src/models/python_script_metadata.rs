@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+pub struct PythonScriptMetadata {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub author: Option<String>,
+    pub version: Option<String>,
+    pub blender: Option<String>,
+    pub category: Option<String>,
+    pub docstring: Option<String>,
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+pub struct PythonScriptSafetyWarning {
+    pub category: String,
+    pub message: String,
+    pub line: usize,
+    pub column: usize,
+}
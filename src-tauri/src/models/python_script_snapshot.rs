@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+
+#[derive(Default, Debug, Serialize, Deserialize, FromRow, Clone)]
+pub struct PythonScriptSnapshot {
+    pub id: String,
+    pub python_script_id: String,
+    pub launch_session_id: Option<String>,
+    pub script_file_path: String,
+    pub content_hash: String,
+    pub snapshot_file_path: String,
+    pub created: String,
+}
@@ -0,0 +1,18 @@
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PythonTokenKind {
+    Name,
+    Number,
+    String,
+    Operator,
+    Newline,
+    Indent,
+    Dedent,
+}
+
+#[derive(Debug, Clone)]
+pub struct PythonToken {
+    pub kind: PythonTokenKind,
+    pub text: String,
+    pub line: usize,
+    pub column: usize,
+}
@@ -0,0 +1,658 @@
+use std::io::Write;
+
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncRead, BufReader};
+
+use crate::{
+    db_repo::LaunchSessionRepository,
+    file_system_utility,
+    models::{
+        BlenderCrashReport, BlenderInstance, BlenderInstanceOutput, InstalledBlenderVersion,
+        LaunchEnvironment, LaunchEnvironmentAction, LaunchSession,
+    },
+    AppState,
+};
+
+use super::{DEFAULT_CRASH_FILE_NAME, STDERR_TAIL_LINE_COUNT};
+
+#[derive(Debug)]
+pub struct SupervisedProcess {
+    pub instance: BlenderInstance,
+    pub terminate: std::sync::Arc<tokio::sync::Notify>,
+    pub stderr_tail: std::sync::Arc<std::sync::Mutex<std::collections::VecDeque<String>>>,
+}
+
+/// ID: PR_001
+/// ABC analīzes rezultāts:16,45,12
+pub async fn launch_supervised_executable(
+    app: AppHandle,
+    installed_blender_version: &InstalledBlenderVersion,
+    args: Vec<String>,
+    launch_argument_id: Option<String>,
+    python_script_ids: Vec<String>,
+    project_file_id: Option<String>,
+    launch_environment: LaunchEnvironment,
+) -> Result<BlenderInstance, String> {
+    let session_id = uuid::Uuid::new_v4().to_string(); // A (1.a.) let session_id =; B (2.a.) uuid::Uuid::new_v4(); B (2.a.) .to_string();
+    let log_file_path = file_system_utility::get_launch_log_directory_path()? // A (1.a.) let log_file_path =; B (2.a.) ::get_launch_log_directory_path()
+        .join(format!("{}.log", session_id)); // B (2.a.) .join()
+    let log_file = match std::fs::File::create(&log_file_path) {
+        // A (1.a.) let log_file =; C (3.b) match; B (2.a.) ::create()
+        Ok(val) => std::sync::Arc::new(std::sync::Mutex::new(val)), // C (3.c.) Ok(); B (2.a.) ...::new(); B (2.a.) ...::new()
+        Err(err) => return Err(format!("Failed to create log file: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    };
+    let mut command = tokio::process::Command::new(&installed_blender_version.executable_file_path); // A (1.a.) let mut command =; B (2.a.) ...::new()
+    command
+        .args(&args) // B (2.a.) .args()
+        .stdin(std::process::Stdio::null()) // B (2.a.) .stdin(); B (2.a.) ::null()
+        .stdout(std::process::Stdio::piped()) // B (2.a.) .stdout(); B (2.a.) ::piped()
+        .stderr(std::process::Stdio::piped()); // B (2.a.) .stderr(); B (2.a.) ::piped()
+    if let Err(err) = apply_launch_environment(&mut command, &launch_environment) {
+        // A (1.d.) if let Err(); B (2.a.) apply_launch_environment()
+        let _ = std::fs::remove_file(&log_file_path); // A (1.a.) let _ =; B (2.a.) ::remove_file()
+        return Err(err); // B (2.b.) priekšlaicīgs return
+    }
+    let mut child = match command.spawn() {
+        // A (1.a.) let mut child =; C (3.b) match; B (2.a.) .spawn()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c) Err()
+            let _ = std::fs::remove_file(&log_file_path); // A (1.a.) let _ =; B (2.a.) ::remove_file()
+            return Err(format!("Failed to launch executable: {:?}", err)); // B (2.b.) priekšlaicīgs return
+        }
+    };
+    let instance = BlenderInstance {
+        // A (1.a.) let instance =;
+        id: session_id,
+        pid: child.id(), // B (2.a.) .id()
+        installed_blender_version_id: installed_blender_version.id.clone(), // B (2.a.) .clone()
+        launch_argument_id: launch_argument_id,
+        python_script_ids: python_script_ids,
+        project_file_id: project_file_id,
+        arguments: args,
+        started: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
+        ended: None,
+        exit_code: None,
+        exit_signal: None,
+        is_running: true,
+        log_file_path: Some(log_file_path.to_string_lossy().to_string()), // B (2.a.) .to_string_lossy(); B (2.a.) .to_string()
+    };
+    let session = LaunchSession {
+        // A (1.a.) let session =;
+        id: instance.id.clone(), // B (2.a.) .clone()
+        installed_blender_version_id: instance.installed_blender_version_id.clone(), // B (2.a.) .clone()
+        blender_version: get_blender_version_label(installed_blender_version), // B (2.a.) get_blender_version_label()
+        launch_argument_id: instance.launch_argument_id.clone(),               // B (2.a.) .clone()
+        python_script_ids_json: serde_json::to_string(&instance.python_script_ids)
+            .unwrap_or_default(), // B (2.a.) ::to_string(); B (2.a.) .unwrap_or_default()
+        project_file_id: instance.project_file_id.clone(),                     // B (2.a.) .clone()
+        arguments_json: serde_json::to_string(&instance.arguments).unwrap_or_default(), // B (2.a.) ::to_string(); B (2.a.) .unwrap_or_default()
+        started: instance.started.clone(), // B (2.a.) .clone()
+        log_file_path: instance.log_file_path.clone(), // B (2.a.) .clone()
+        ..Default::default()               // B (2.a.) ::default()
+    };
+    let state = app.state::<AppState>(); // A (1.a.) let state =; B (2.a.) .state()
+    match LaunchSessionRepository::new(&state.pool)
+        .insert(&session)
+        .await
+    {
+        // C (3.b) match; B (2.a.) ...::new(); B (2.a.) .insert()
+        Ok(_) => {} // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c) Err()
+            let _ = child.start_kill(); // A (1.a.) let _ =; B (2.a.) .start_kill()
+            return Err(format!("Failed to insert launch session: {:?}", err)); // B (2.b.) priekšlaicīgs return
+        }
+    }
+    let terminate = std::sync::Arc::new(tokio::sync::Notify::new()); // A (1.a.) let terminate =; B (2.a.) ...::new(); B (2.a.) ...::new()
+    let stderr_tail = std::sync::Arc::new(std::sync::Mutex::new(std::collections::VecDeque::new())); // A (1.a.) let stderr_tail =; B (2.a.) ...::new(); B (2.a.) ...::new(); B (2.a.) ...::new()
+    match state.running_instances.lock() {
+        // C (3.b) match; B (2.a.) .lock()
+        Ok(mut running_instances) => {
+            // C (3.c.) Ok()
+            running_instances.insert(
+                // A (1.c.) .insert(); B (2.a.) .clone(); B (2.a.) .clone(); B (2.a.) .clone()
+                instance.id.clone(),
+                SupervisedProcess {
+                    instance: instance.clone(),
+                    terminate: terminate.clone(),
+                    stderr_tail: stderr_tail.clone(),
+                },
+            );
+        }
+        Err(err) => {
+            // C (3.c) Err()
+            let _ = child.start_kill(); // A (1.a.) let _ =; B (2.a.) .start_kill()
+            return Err(format!("Failed to launch executable: {:?}", err)); // B (2.b.) priekšlaicīgs return
+        }
+    }
+    let mut output_readers = Vec::new(); // A (1.a.) let mut output_readers =; B (2.a.) ...::new()
+    if let Some(stdout) = child.stdout.take() {
+        // A (1.d.) if let Some(); B (2.a.) .take()
+        output_readers.push(tauri::async_runtime::spawn(read_output_lines(
+            // A (1.c.) .push(); B (2.a.) ::spawn(); B (2.a.) read_output_lines(); B (2.a.) .clone(); B (2.a.) .clone(); B (2.a.) .clone()
+            app.clone(),
+            instance.id.clone(),
+            "stdout",
+            stdout,
+            log_file.clone(),
+            None,
+        )));
+    }
+    if let Some(stderr) = child.stderr.take() {
+        // A (1.d.) if let Some(); B (2.a.) .take()
+        output_readers.push(tauri::async_runtime::spawn(read_output_lines(
+            // A (1.c.) .push(); B (2.a.) ::spawn(); B (2.a.) read_output_lines(); B (2.a.) .clone(); B (2.a.) .clone(); B (2.a.) .clone()
+            app.clone(),
+            instance.id.clone(),
+            "stderr",
+            stderr,
+            log_file.clone(),
+            Some(stderr_tail),
+        )));
+    }
+    tauri::async_runtime::spawn(supervise_child(
+        // B (2.a.) ::spawn(); B (2.a.) supervise_child(); B (2.a.) .clone(); B (2.a.) .clone()
+        app.clone(),
+        child,
+        instance.id.clone(),
+        terminate,
+        output_readers,
+    ));
+    Ok(instance)
+}
+
+/// ID: PR_002
+/// ABC analīzes rezultāts:16,31,17
+pub async fn supervise_child(
+    app: AppHandle,
+    mut child: tokio::process::Child,
+    instance_id: String,
+    terminate: std::sync::Arc<tokio::sync::Notify>,
+    output_readers: Vec<tauri::async_runtime::JoinHandle<()>>,
+) {
+    let (status, is_terminated) = tokio::select! {
+        // A (1.a.) let (status, is_terminated) =; B (2.a.) .wait(); B (2.a.) .notified()
+        status = child.wait() => (status, false),
+        _ = terminate.notified() => {
+            let _ = child.start_kill(); // A (1.a.) let _ =; B (2.a.) .start_kill()
+            (child.wait().await, true) // B (2.a.) .wait()
+        }
+    };
+    // Wait for the remaining output so exit listeners see the complete stdout and stderr.
+    for output_reader in output_readers {
+        // A (1.a.) let output_reader =;
+        let _ = output_reader.await; // A (1.a.) let _ =
+    }
+    let state = app.state::<AppState>(); // A (1.a.) let state =; B (2.a.) .state()
+    let supervised_process = match state.running_instances.lock() {
+        // A (1.a.) let supervised_process =; C (3.b) match; B (2.a.) .lock()
+        Ok(mut running_instances) => running_instances.remove(&instance_id), // C (3.c.) Ok(); B (2.a.) .remove()
+        Err(_) => None,                                                      // C (3.c) Err()
+    };
+    let Some(mut supervised_process) = supervised_process else {
+        // A (1.d.) let Some() else
+        return; // B (2.b.) priekšlaicīgs return
+    };
+    supervised_process.instance.ended = Some(chrono::Utc::now().to_rfc3339()); // A (1.a.) .ended =; B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
+    supervised_process.instance.is_running = false; // A (1.a.) .is_running =
+    if let Ok(status) = status {
+        // A (1.d.) if let Ok()
+        supervised_process.instance.exit_code = status.code(); // A (1.a.) .exit_code =; B (2.a.) .code()
+        supervised_process.instance.exit_signal = get_exit_signal(&status); // A (1.a.) .exit_signal =; B (2.a.) get_exit_signal()
+    }
+    // A user requested termination is an expected exit, not a crash.
+    let is_abnormal_exit = !is_terminated
+        && (supervised_process.instance.exit_signal.is_some()
+            || supervised_process.instance.exit_code != Some(0)); // A (1.a.) let is_abnormal_exit =; C (3.a.) is_terminated != true; C (3.a.) .is_some() == true; C (3.a.) exit_code != Some(0); B (2.a.) .is_some()
+    let crash_report = if is_abnormal_exit {
+        // A (1.a.) let crash_report =; C (3.a.) is_abnormal_exit == true
+        Some(collect_crash_report(
+            // B (2.a.) collect_crash_report()
+            &supervised_process.instance,
+            &supervised_process.stderr_tail,
+        ))
+    } else {
+        // C (3.b.) else
+        None
+    };
+    let repository = LaunchSessionRepository::new(&state.pool); // A (1.a.) let repository =; B (2.a.) ...::new()
+    if let Ok(mut sessions) = repository
+        .fetch(Some(&instance_id), None, None, None, false)
+        .await
+    {
+        // A (1.d.) if let Ok(); B (2.a.) .fetch()
+        if !sessions.is_empty() {
+            // C (3.a.) sessions.is_empty() != true; B (2.a.) .is_empty()
+            let mut session = sessions.remove(0); // A (1.a.) let mut session =; B (2.a.) .remove()
+            session.ended = supervised_process.instance.ended.clone(); // A (1.a.) session.ended =; B (2.a.) .clone()
+            session.exit_code = supervised_process.instance.exit_code.map(i64::from); // A (1.a.) session.exit_code =; B (2.a.) .map()
+            session.exit_signal = supervised_process.instance.exit_signal.map(i64::from); // A (1.a.) session.exit_signal =; B (2.a.) .map()
+            if let Some(crash_report) = &crash_report {
+                // A (1.d.) if let Some()
+                session.crash_report_file_path = crash_report.crash_report_file_path.clone(); // A (1.a.) session.crash_report_file_path =; B (2.a.) .clone()
+                session.stderr_tail = Some(crash_report.stderr_tail.join("\n"));
+                // A (1.a.) session.stderr_tail =; B (2.a.) .join()
+            }
+            let _ = repository.update(&session).await; // A (1.a.) let _ =; B (2.a.) .update()
+        }
+    }
+    let _ = app.emit("blender-instance-exited", supervised_process.instance); // A (1.a.) let _ =; B (2.a.) .emit()
+    if let Some(crash_report) = crash_report {
+        // A (1.d.) if let Some()
+        let _ = app.emit("blender-instance-crashed", crash_report); // A (1.a.) let _ =; B (2.a.) .emit()
+    }
+}
+
+/// ID: PR_003
+/// ABC analīzes rezultāts:6,16,5
+pub async fn read_output_lines<R: AsyncRead + Unpin>(
+    app: AppHandle,
+    instance_id: String,
+    stream: &'static str,
+    reader: R,
+    log_file: std::sync::Arc<std::sync::Mutex<std::fs::File>>,
+    tail: Option<std::sync::Arc<std::sync::Mutex<std::collections::VecDeque<String>>>>,
+) {
+    let mut reader = BufReader::new(reader); // A (1.a.) let mut reader =; B (2.a.) ...::new()
+    let mut buffer = Vec::new(); // A (1.a.) let mut buffer =; B (2.a.) ...::new()
+    while let Some(line) = read_output_line(&mut reader, &mut buffer).await {
+        // A (1.d.) while let Some(); B (2.a.) read_output_line()
+        if let Ok(mut file) = log_file.lock() {
+            // A (1.d.) if let Ok(); B (2.a.) .lock()
+            let _ = writeln!(file, "[{}] {}", stream, line); // A (1.a.) let _ =; B (2.a.) writeln!()
+        }
+        if let Some(Ok(mut tail)) = tail.as_ref().map(|val| val.lock()) {
+            // A (1.d.) if let Some(Ok()); B (2.a.) .as_ref(); B (2.a.) .map(); B (2.a.) .lock()
+            if tail.len() >= STDERR_TAIL_LINE_COUNT {
+                // C (3.a.) tail.len() >= STDERR_TAIL_LINE_COUNT; B (2.a.) .len()
+                tail.pop_front(); // B (2.a.) .pop_front()
+            }
+            tail.push_back(line.clone()); // A (1.c.) .push_back(); B (2.a.) .clone()
+        }
+        let output = BlenderInstanceOutput {
+            // A (1.a.) let output =;
+            instance_id: instance_id.clone(), // B (2.a.) .clone()
+            stream: stream.to_string(),       // B (2.a.) .to_string()
+            line: line,
+        };
+        let _ = app.emit("blender-instance-output", output); // A (1.a.) let _ =; B (2.a.) .emit()
+    }
+}
+
+/// ID: PR_011
+/// ABC analīzes rezultāts:2,6,4
+pub async fn read_output_line<R: AsyncBufRead + Unpin>(
+    reader: &mut R,
+    buffer: &mut Vec<u8>,
+) -> Option<String> {
+    buffer.clear(); // B (2.a.) .clear()
+
+    // Blender and add-ons print text that isn't valid UTF-8, such lines are kept readable instead
+    // of ending the loop that drains the pipe. Only the end of the stream or an I/O error does.
+    match reader.read_until(b'\n', buffer).await {
+        // C (3.b) match; B (2.a.) .read_until()
+        Ok(0) | Err(_) => None, // C (3.c.) Ok(); C (3.c) Err()
+        Ok(_) => {
+            // C (3.c.) Ok()
+            while matches!(buffer.last(), Some(b'\n' | b'\r')) {
+                // C (3.a.) matches!(); B (2.a.) .last()
+                buffer.pop(); // B (2.a.) .pop()
+            }
+            Some(String::from_utf8_lossy(buffer).to_string()) // B (2.a.) ::from_utf8_lossy(); B (2.a.) .to_string()
+        }
+    }
+}
+
+/// ID: PR_004
+/// ABC analīzes rezultāts:1,1,1
+pub fn get_exit_signal(status: &std::process::ExitStatus) -> Option<i32> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+        return status.signal(); // B (2.b.) priekšlaicīgs return; B (2.a.) .signal()
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = status; // A (1.a.) let _ =
+        return None; // B (2.b.) priekšlaicīgs return
+    }
+}
+
+/// ID: PR_005
+/// ABC analīzes rezultāts:1,5,3
+#[tauri::command]
+pub async fn fetch_running_blender_instances(
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<BlenderInstance>, String> {
+    let running_instances = match state.running_instances.lock() {
+        // A (1.a.) let running_instances =; C (3.b) match; B (2.a.) .lock()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => {
+            return Err(format!(
+                "Failed to fetch running Blender instances: {:?}",
+                err
+            ))
+        } // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    };
+    let mut results: Vec<BlenderInstance> = running_instances
+        .values() // B (2.a.) .values()
+        .map(|val| val.instance.clone()) // B (2.a.) .map(); B (2.a.) .clone()
+        .collect(); // A (1.a.) let mut results =; B (2.a.) .collect()
+                    // Sort DESC
+    results.sort_by(|a, b| b.started.cmp(&a.started)); // A (1.c.) .sort_by(); B (2.a) |a, b| b.started.cmp(&a.started)
+    Ok(results)
+}
+
+/// ID: PR_006
+/// ABC analīzes rezultāts:1,5,4
+#[tauri::command]
+pub async fn terminate_blender_instance(
+    state: tauri::State<'_, AppState>,
+    id: String,
+) -> Result<(), String> {
+    let running_instances = match state.running_instances.lock() {
+        // A (1.a.) let running_instances =; C (3.b) match; B (2.a.) .lock()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => return Err(format!("Failed to terminate Blender instance: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    };
+    match running_instances.get(&id) {
+        // C (3.b) match; B (2.a.) .get()
+        Some(supervised_process) => {
+            // C (3.c) Some()
+            supervised_process.terminate.notify_one(); // B (2.a.) .notify_one()
+            Ok(())
+        }
+        None => Err(format!(
+            "Failed to terminate Blender instance: no running instance with this ID"
+        )), // C (3.c) None =>
+    }
+}
+
+/// ID: PR_007
+/// ABC analīzes rezultāts:1,3,2
+pub fn get_blender_version_label(installed_blender_version: &InstalledBlenderVersion) -> String {
+    match &installed_blender_version.build_hash {
+        // C (3.b) match
+        Some(build_hash) => format!(
+            "{} {} ({})",
+            installed_blender_version.version, installed_blender_version.variant_type, build_hash
+        ), // C (3.c) Some()
+        None => format!(
+            "{} {}",
+            installed_blender_version.version, installed_blender_version.variant_type
+        ), // C (3.c) None =>
+    }
+}
+
+/// ID: PR_008
+/// ABC analīzes rezultāts:8,20,10
+pub fn find_crash_report_file_path(instance: &BlenderInstance) -> Option<std::path::PathBuf> {
+    // Blender names the crash file after the open .blend file, or blender.crash.txt without one.
+    let mut crash_file_names = vec![DEFAULT_CRASH_FILE_NAME.to_string()]; // A (1.a.) let mut crash_file_names =; B (2.a.) .to_string()
+    for argument in &instance.arguments {
+        // A (1.a.) let argument =;
+        let argument_path = std::path::Path::new(argument); // A (1.a.) let argument_path =; B (2.a.) ::new()
+        if argument_path
+            .extension()
+            .map(|val| val == "blend")
+            .unwrap_or(false)
+        {
+            // C (3.a.) val == "blend"; B (2.a.) .extension(); B (2.a.) .map(); B (2.a.) .unwrap_or()
+            if let Some(stem) = argument_path.file_stem() {
+                // A (1.d.) if let Some(); B (2.a.) .file_stem()
+                crash_file_names.insert(0, format!("{}.crash.txt", stem.to_string_lossy()));
+                // A (1.c.) .insert(); B (2.a.) .to_string_lossy()
+            }
+        }
+    }
+    let mut temp_directory_paths = vec![std::env::temp_dir()]; // A (1.a.) let mut temp_directory_paths =; B (2.a.) ::temp_dir()
+    #[cfg(unix)]
+    temp_directory_paths.push(std::path::PathBuf::from("/tmp")); // A (1.c.) .push(); B (2.a.) ::from()
+    let started = chrono::DateTime::parse_from_rfc3339(&instance.started).ok()?; // A (1.a.) let started =; B (2.a.) ::parse_from_rfc3339(); B (2.a.) .ok()
+    for crash_file_name in &crash_file_names {
+        // A (1.a.) let crash_file_name =;
+        for temp_directory_path in &temp_directory_paths {
+            // A (1.a.) let temp_directory_path =;
+            let crash_file_path = temp_directory_path.join(crash_file_name); // A (1.a.) let crash_file_path =; B (2.a.) .join()
+            let modified = match std::fs::metadata(&crash_file_path).and_then(|val| val.modified())
+            {
+                // A (1.a.) let modified =; C (3.b) match; B (2.a.) ::metadata(); B (2.a.) .and_then(); B (2.a.) .modified()
+                Ok(val) => chrono::DateTime::<chrono::Utc>::from(val), // C (3.c.) Ok(); B (2.a.) ::from()
+                Err(_) => continue, // C (3.c) Err(); B (2.b.) continue
+            };
+            // A crash file left behind by an earlier session must not be attached to this one.
+            if modified >= started {
+                // C (3.a.) modified >= started
+                return Some(crash_file_path); // B (2.b.) priekšlaicīgs return
+            }
+        }
+    }
+    None
+}
+
+/// ID: PR_009
+/// ABC analīzes rezultāts:6,15,4
+pub fn collect_crash_report(
+    instance: &BlenderInstance,
+    stderr_tail: &std::sync::Mutex<std::collections::VecDeque<String>>,
+) -> BlenderCrashReport {
+    let stderr_tail = match stderr_tail.lock() {
+        // A (1.a.) let stderr_tail =; C (3.b) match; B (2.a.) .lock()
+        Ok(val) => val.iter().cloned().collect(), // C (3.c.) Ok(); B (2.a.) .iter(); B (2.a.) .cloned(); B (2.a.) .collect()
+        Err(_) => Vec::new(),                     // C (3.c) Err(); B (2.a.) ...::new()
+    };
+    let mut crash_report = None; // A (1.a.) let mut crash_report =
+    let mut crash_report_file_path = None; // A (1.a.) let mut crash_report_file_path =
+    if let Some(source_file_path) = find_crash_report_file_path(instance) {
+        // A (1.d.) if let Some(); B (2.a.) find_crash_report_file_path()
+        crash_report = std::fs::read_to_string(&source_file_path).ok(); // A (1.a.) crash_report =; B (2.a.) ::read_to_string(); B (2.a.) .ok()
+
+        // Keep a copy next to the session log, the next crash overwrites the file in the temp directory.
+        let target_file_path =
+            file_system_utility::get_launch_log_directory_path() // A (1.a.) let target_file_path =; B (2.a.) ::get_launch_log_directory_path()
+                .map(|val| val.join(format!("{}.crash.txt", instance.id))); // B (2.a.) .map(); B (2.a.) .join()
+        crash_report_file_path = match target_file_path {
+            // A (1.a.) crash_report_file_path =; C (3.b) match
+            Ok(val) if std::fs::copy(&source_file_path, &val).is_ok() => {
+                // C (3.c.) Ok(); B (2.a.) ::copy(); B (2.a.) .is_ok()
+                Some(val.to_string_lossy().to_string()) // B (2.a.) .to_string_lossy(); B (2.a.) .to_string()
+            }
+            _ => Some(source_file_path.to_string_lossy().to_string()), // C (3.c) _ =>; B (2.a.) .to_string_lossy(); B (2.a.) .to_string()
+        };
+    }
+    BlenderCrashReport {
+        launch_session_id: instance.id.clone(), // B (2.a.) .clone()
+        installed_blender_version_id: instance.installed_blender_version_id.clone(), // B (2.a.) .clone()
+        exit_code: instance.exit_code,
+        exit_signal: instance.exit_signal,
+        crash_report: crash_report,
+        crash_report_file_path: crash_report_file_path,
+        stderr_tail: stderr_tail,
+    }
+}
+
+/// ID: PR_010
+/// ABC analīzes rezultāts:6,20,9
+pub fn apply_launch_environment(
+    command: &mut tokio::process::Command,
+    launch_environment: &LaunchEnvironment,
+) -> Result<(), String> {
+    // Later entries see the values set by earlier ones, not only the inherited environment.
+    let mut current_values: std::collections::HashMap<&str, Option<std::ffi::OsString>> =
+        std::collections::HashMap::new(); // A (1.a.) let mut current_values =; B (2.a.) ...::new()
+    for variable in &launch_environment.variables {
+        // A (1.a.) let variable =;
+        match variable.action {
+            // C (3.b) match
+            LaunchEnvironmentAction::Set => {
+                // C (3.c) Set =>
+                command.env(&variable.name, &variable.value); // B (2.a.) .env()
+                current_values.insert(&variable.name, Some(variable.value.clone().into()));
+                // A (1.c.) .insert(); B (2.a.) .clone(); B (2.a.) .into()
+            }
+            LaunchEnvironmentAction::Unset => {
+                // C (3.c) Unset =>
+                command.env_remove(&variable.name); // B (2.a.) .env_remove()
+                current_values.insert(&variable.name, None); // A (1.c.) .insert()
+            }
+            LaunchEnvironmentAction::PrependPath => {
+                // C (3.c) PrependPath =>
+                let existing_value = match current_values.get(variable.name.as_str()) {
+                    // A (1.a.) let existing_value =; C (3.b) match; B (2.a.) .get(); B (2.a.) .as_str()
+                    Some(val) => val.clone().unwrap_or_default(), // C (3.c) Some(); B (2.a.) .clone(); B (2.a.) .unwrap_or_default()
+                    None => std::env::var_os(&variable.name).unwrap_or_default(), // C (3.c) None =>; B (2.a.) ::var_os(); B (2.a.) .unwrap_or_default()
+                };
+                // An empty entry would put the working directory on the search path.
+                let paths = std::env::split_paths(&variable.value)
+                    .chain(std::env::split_paths(&existing_value))
+                    .filter(|val| !val.as_os_str().is_empty()); // A (1.a.) let paths =; B (2.a.) ::split_paths(); B (2.a.) .chain(); B (2.a.) ::split_paths(); B (2.a.) .filter(); C (3.a.) .is_empty() != true; B (2.a.) .as_os_str(); B (2.a.) .is_empty()
+                let value = match std::env::join_paths(paths) {
+                    // A (1.a.) let value =; C (3.b) match; B (2.a.) ::join_paths()
+                    Ok(val) => val, // C (3.c.) Ok()
+                    Err(err) => {
+                        // C (3.c) Err()
+                        return Err(format!(
+                            "Failed to prepend to environment variable {}: {}",
+                            variable.name, err
+                        )); // B (2.b.) priekšlaicīgs return
+                    }
+                };
+                command.env(&variable.name, &value); // B (2.a.) .env()
+                current_values.insert(&variable.name, Some(value)); // A (1.c.) .insert()
+            }
+        }
+    }
+    if let Some(working_directory) = &launch_environment.working_directory {
+        // A (1.d.) if let Some()
+        command.current_dir(working_directory); // B (2.a.) .current_dir()
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::LaunchEnvironmentVariable;
+
+    fn create_variable(
+        name: &str,
+        action: LaunchEnvironmentAction,
+        value: &str,
+    ) -> LaunchEnvironmentVariable {
+        LaunchEnvironmentVariable {
+            name: name.to_string(),
+            action,
+            value: value.to_string(),
+        }
+    }
+
+    fn get_command_env(
+        command: &tokio::process::Command,
+        name: &str,
+    ) -> Option<Option<std::ffi::OsString>> {
+        command
+            .as_std()
+            .get_envs()
+            .find(|val| val.0 == name)
+            .map(|val| val.1.map(|val| val.to_os_string()))
+    }
+
+    fn join_test_paths(paths: &[&str]) -> std::ffi::OsString {
+        std::env::join_paths(paths).unwrap()
+    }
+
+    #[test]
+    fn apply_launch_environment_prepends_to_values_set_earlier() {
+        let mut command = tokio::process::Command::new("blender");
+        let launch_environment = LaunchEnvironment {
+            variables: vec![
+                create_variable("BLENDIO_TEST_PATH", LaunchEnvironmentAction::Set, "/base"),
+                create_variable(
+                    "BLENDIO_TEST_PATH",
+                    LaunchEnvironmentAction::PrependPath,
+                    "/first",
+                ),
+                create_variable(
+                    "BLENDIO_TEST_PATH",
+                    LaunchEnvironmentAction::PrependPath,
+                    "/second",
+                ),
+            ],
+            working_directory: Some(String::from("/tmp")),
+        };
+        apply_launch_environment(&mut command, &launch_environment).unwrap();
+        assert_eq!(
+            get_command_env(&command, "BLENDIO_TEST_PATH"),
+            Some(Some(join_test_paths(&["/second", "/first", "/base"])))
+        );
+        assert_eq!(
+            command.as_std().get_current_dir(),
+            Some(std::path::Path::new("/tmp"))
+        );
+    }
+
+    #[test]
+    fn apply_launch_environment_prepends_to_unset_and_missing_values() {
+        let mut command = tokio::process::Command::new("blender");
+        let launch_environment = LaunchEnvironment {
+            variables: vec![
+                create_variable("PATH", LaunchEnvironmentAction::Unset, ""),
+                create_variable("PATH", LaunchEnvironmentAction::PrependPath, "/only"),
+                create_variable(
+                    "BLENDIO_TEST_MISSING_PATH",
+                    LaunchEnvironmentAction::PrependPath,
+                    "/scripts",
+                ),
+                create_variable("BLENDIO_TEST_REMOVED", LaunchEnvironmentAction::Unset, ""),
+            ],
+            working_directory: None,
+        };
+        apply_launch_environment(&mut command, &launch_environment).unwrap();
+        assert_eq!(
+            get_command_env(&command, "PATH"),
+            Some(Some(join_test_paths(&["/only"])))
+        );
+        assert_eq!(
+            get_command_env(&command, "BLENDIO_TEST_MISSING_PATH"),
+            Some(Some(join_test_paths(&["/scripts"])))
+        );
+        assert_eq!(
+            get_command_env(&command, "BLENDIO_TEST_REMOVED"),
+            Some(None)
+        );
+    }
+
+    #[test]
+    fn apply_launch_environment_prepends_to_inherited_values() {
+        let mut command = tokio::process::Command::new("blender");
+        let launch_environment = LaunchEnvironment {
+            variables: vec![create_variable(
+                "PATH",
+                LaunchEnvironmentAction::PrependPath,
+                "/first",
+            )],
+            working_directory: None,
+        };
+        apply_launch_environment(&mut command, &launch_environment).unwrap();
+        let value = get_command_env(&command, "PATH").unwrap().unwrap();
+        let paths: Vec<std::path::PathBuf> = std::env::split_paths(&value).collect();
+        let inherited_paths: Vec<std::path::PathBuf> =
+            std::env::split_paths(&std::env::var_os("PATH").unwrap_or_default())
+                .filter(|val| !val.as_os_str().is_empty())
+                .collect();
+        assert_eq!(paths[0], std::path::PathBuf::from("/first"));
+        assert_eq!(paths[1..], inherited_paths[..]);
+    }
+
+    #[tokio::test]
+    async fn read_output_line_keeps_reading_after_invalid_utf8() {
+        let mut reader = BufReader::new(&b"first\r\n\xff\xfe bad\nlast"[..]);
+        let mut buffer = Vec::new();
+        let mut lines = Vec::new();
+        while let Some(line) = read_output_line(&mut reader, &mut buffer).await {
+            lines.push(line);
+        }
+        assert_eq!(lines, vec!["first", "\u{fffd}\u{fffd} bad", "last"]);
+    }
+}
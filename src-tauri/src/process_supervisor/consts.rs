@@ -0,0 +1,4 @@
+/// How many of the last stderr lines are kept for crash reports.
+pub const STDERR_TAIL_LINE_COUNT: usize = 50;
+/// Crash file Blender writes into the temp directory when no .blend file was open.
+pub const DEFAULT_CRASH_FILE_NAME: &str = "blender.crash.txt";
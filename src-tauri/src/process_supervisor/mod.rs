@@ -0,0 +1,5 @@
+mod commands;
+mod consts;
+
+pub use commands::*;
+pub use consts::*;
\ No newline at end of file
@@ -1,11 +1,16 @@
 use crate::{
+    blend_file, blender_version,
     db_repo::{
         InstalledBlenderVersionRepository, LaunchArgumentRepository, ProjectFileRepository,
         PythonScriptRepository,
     },
     file_system_utility::{self, show_ok_notification},
-    models::ProjectFile,
-    AppState,
+    launch_argument,
+    models::{
+        BlendFileDependency, InstalledBlenderVersion, LaunchEnvironment, ProjectFile,
+        ProjectFileArchiveEntry, ProjectFileArchiveManifest, PythonScript, PythonScriptSnapshot,
+    },
+    process_supervisor, python_script, AppState,
 };
 use tauri::AppHandle;
 
@@ -43,6 +48,8 @@ pub async fn insert_blend_file(
         created: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
         modified: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
         accessed: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
+        scene_names_json: "[]".to_string(),        // B (2.a.) .to_string()
+        ..Default::default()                       // B (2.a.) ::default()
     };
     let repository = ProjectFileRepository::new(&state.pool); // A (1.a.) let repository =; B (2.a.) ...::new()
     match repository.insert(&entry).await {
@@ -62,24 +69,37 @@ pub async fn insert_blend_file(
 }
 
 /// ID: PF_002
-/// ABC analīzes rezultāts:28,101,50
+/// ABC analīzes rezultāts:0,4,2
 #[tauri::command]
 pub async fn insert_and_refresh_blend_files(
     app: AppHandle,
     state: tauri::State<'_, AppState>,
 ) -> Result<(), String> {
-    let repository = ProjectFileRepository::new(&state.pool); // A (1.a.) let repository =; B (2.a.) ...::new()
-    let config_directory = match dirs::config_dir() {
-        // A (1.a.) let config_directory =; C (3.b) match
-        Some(val) => val, // C (3.c) Some()
-        None => {
-            // C (3.c) None =>;
+    match refresh_blend_file_entries(&state.pool).await {
+        // C (3.b) match; B (2.a.) refresh_blend_file_entries()
+        Ok(_) => Ok(()), // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c) Err()
             show_ok_notification(
                 // B (2.a.) show_ok_notification()
                 app.clone(), // B (2.a.) app.clone();
-                format!("Failed to insert and refresh project files: no config directory found"),
+                err.clone(), // B (2.a.) err.clone()
                 tauri_plugin_dialog::MessageDialogKind::Error,
             );
+            Err(err)
+        }
+    }
+}
+
+/// ID: PF_018
+/// ABC analīzes rezultāts:28,90,58
+pub async fn refresh_blend_file_entries(pool: &sqlx::SqlitePool) -> Result<(), String> {
+    let repository = ProjectFileRepository::new(pool); // A (1.a.) let repository =; B (2.a.) ...::new()
+    let config_directory = match dirs::config_dir() {
+        // A (1.a.) let config_directory =; C (3.b) match
+        Some(val) => val, // C (3.c) Some()
+        None => {
+            // C (3.c) None =>;
             return Err(format!(
                 "Failed to insert and refresh project files: no config directory found"
             )); // B (2.b.) priekšlaicīgs return
@@ -92,12 +112,6 @@ pub async fn insert_and_refresh_blend_files(
         Ok(val) => val, // C (3.c.) Ok()
         Err(err) => {
             // C (3.c) Err();
-            show_ok_notification(
-                // B (2.a.) show_ok_notification()
-                app.clone(), // B (2.a.) app.clone();
-                format!("Failed to insert and refresh project files: {:?}", err),
-                tauri_plugin_dialog::MessageDialogKind::Error,
-            );
             return Err(format!(
                 "Failed to insert and refresh project files: {:?}",
                 err
@@ -111,12 +125,6 @@ pub async fn insert_and_refresh_blend_files(
             Ok(val) => val, // C (3.c.) Ok()
             Err(err) => {
                 // C (3.c) Err();
-                show_ok_notification(
-                    // B (2.a.) show_ok_notification()
-                    app.clone(), // B (2.a.) app.clone();
-                    format!("Failed to insert and refresh project files: {:?}", err),
-                    tauri_plugin_dialog::MessageDialogKind::Error,
-                );
                 return Err(format!(
                     "Failed to insert and refresh project files: {:?}",
                     err
@@ -138,12 +146,6 @@ pub async fn insert_and_refresh_blend_files(
             Ok(val) => val, // C (3.c.) Ok()
             Err(err) => {
                 // C (3.c) Err();
-                show_ok_notification(
-                    // B (2.a.) show_ok_notification()
-                    app.clone(), // B (2.a.) app.clone();
-                    format!("Failed to insert and refresh project files: {:?}", err),
-                    tauri_plugin_dialog::MessageDialogKind::Error,
-                );
                 return Err(format!(
                     "Failed to insert and refresh project files: {:?}",
                     err
@@ -165,12 +167,6 @@ pub async fn insert_and_refresh_blend_files(
                     Ok(val) => val, // C (3.c.) Ok()
                     Err(err) => {
                         // C (3.c) Err();
-                        show_ok_notification(
-                            // B (2.a.) show_ok_notification()
-                            app.clone(), // B (2.a.) app.clone();
-                            format!("Failed to insert and refresh project files: {:?}", err),
-                            tauri_plugin_dialog::MessageDialogKind::Error,
-                        );
                         return Err(format!(
                             "Failed to insert and refresh project files: {:?}",
                             err
@@ -185,12 +181,6 @@ pub async fn insert_and_refresh_blend_files(
                         Ok(_) => {} // C (3.c.) Ok()
                         Err(err) => {
                             // C (3.c) Err();
-                            show_ok_notification(
-                                // B (2.a.) show_ok_notification()
-                                app.clone(), // B (2.a.) app.clone();
-                                format!("Failed to delete project file: {:?}", err),
-                                tauri_plugin_dialog::MessageDialogKind::Error,
-                            );
                             return Err(format!("Failed to delete project file: {:?}", err));
                             // B (2.b.) priekšlaicīgs return
                         }
@@ -210,12 +200,6 @@ pub async fn insert_and_refresh_blend_files(
                 Ok(val) => val, // C (3.c.) Ok()
                 Err(err) => {
                     // C (3.c) Err();
-                    show_ok_notification(
-                        // B (2.a.) show_ok_notification()
-                        app.clone(), // B (2.a.) app.clone();
-                        format!("Failed to fetch project files: {:?}", err),
-                        tauri_plugin_dialog::MessageDialogKind::Error,
-                    );
                     return Err(format!("Failed to fetch project files: {:?}", err));
                     // B (2.b.) priekšlaicīgs return
                 }
@@ -227,7 +211,6 @@ pub async fn insert_and_refresh_blend_files(
                     Some(val) => val.to_string_lossy().to_string(), // C (3.c) Some(); B (2.a.) .to_string_loosy(); B (2.a.) .to_string();
                     None => {
                         // C (3.c) None =>;
-                        show_ok_notification(app.clone(), format!("Failed to insert and refresh project file: can't identify file name"), tauri_plugin_dialog::MessageDialogKind::Error); // B (2.a.) show_ok_notification(); B (2.a.) app.clone();
                         return Err(format!(
                             "Failed to insert and refresh project file: can't identify file name"
                         )); // B (2.b.) priekšlaicīgs return
@@ -245,18 +228,14 @@ pub async fn insert_and_refresh_blend_files(
                     created: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
                     modified: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
                     accessed: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
+                    scene_names_json: "[]".to_string(),        // B (2.a.) .to_string()
+                    ..Default::default()                       // B (2.a.) ::default()
                 };
                 match repository.insert(&new_project_file_entry).await {
                     // C (3.b) match; B (2.a.) repository.insert()
                     Ok(_) => {} // C (3.c.) Ok()
                     Err(err) => {
                         // C (3.c) Err();
-                        show_ok_notification(
-                            // B (2.a.) show_ok_notification()
-                            app.clone(), // B (2.a.) app.clone();
-                            format!("Failed to insert project file: {:?}", err),
-                            tauri_plugin_dialog::MessageDialogKind::Error,
-                        );
                         return Err(format!("Failed to insert project file: {:?}", err));
                         // B (2.b.) priekšlaicīgs return
                     }
@@ -268,11 +247,6 @@ pub async fn insert_and_refresh_blend_files(
                     match serde_json::from_str(&existing_entry.associated_series_json) { // C (3.b) match; B (2.a.) ::from_str()
                         Ok(val) => val, // C (3.c.) Ok()
                         Err(err) => { // C (3.c) Err();
-                            show_ok_notification( // B (2.a.) show_ok_notification()
-                                app.clone(), // B (2.a.) app.clone();
-                                format!("Failed to insert and refresh project files: {:?}", err),
-                                tauri_plugin_dialog::MessageDialogKind::Error,
-                            );
                             return Err(format!(
                                 "Failed to insert and refresh project files: {:?}",
                                 err
@@ -290,12 +264,6 @@ pub async fn insert_and_refresh_blend_files(
                         Ok(_) => {} // C (3.c.) Ok()
                         Err(err) => {
                             // C (3.c) Err();
-                            show_ok_notification(
-                                // B (2.a.) show_ok_notification()
-                                app.clone(), // B (2.a.) app.clone();
-                                format!("Failed to insert and refresh project files: {:?}", err),
-                                tauri_plugin_dialog::MessageDialogKind::Error,
-                            );
                             return Err(format!(
                                 "Failed to insert and refresh project files: {:?}",
                                 err
@@ -305,18 +273,17 @@ pub async fn insert_and_refresh_blend_files(
                 }
             }
         }
+        // Unchanged content is not rewritten, otherwise the file watcher would see its own write.
+        if refreshed_recent_files_txt_content == recent_files_txt_content {
+            // C (3.a) refreshed_recent_files_txt_content == recent_files_txt_content
+            continue; // B (2.b.) continue
+        }
         // Write refreshed_recent_files_txt_content to recent-files.txt.
         match std::fs::write(recent_files_txt_path, refreshed_recent_files_txt_content) {
             // C (3.b) match; B (2.a.) ::write()
             Ok(_) => {} // C (3.c.) Ok()
             Err(err) => {
                 // C (3.c) Err();
-                show_ok_notification(
-                    // B (2.a.) show_ok_notification()
-                    app.clone(), // B (2.a.) app.clone();
-                    format!("Failed to insert and refresh project files: {:?}", err),
-                    tauri_plugin_dialog::MessageDialogKind::Error,
-                );
                 return Err(format!(
                     "Failed to insert and refresh project files: {:?}",
                     err
@@ -329,17 +296,11 @@ pub async fn insert_and_refresh_blend_files(
         Ok(val) => val, // C (3.c.) Ok()
         Err(err) => {
             // C (3.c) Err();
-            show_ok_notification(
-                // B (2.a.) show_ok_notification()
-                app.clone(), // B (2.a.) app.clone();
-                format!("Failed to fetch project files: {:?}", err),
-                tauri_plugin_dialog::MessageDialogKind::Error,
-            );
             return Err(format!("Failed to fetch project files: {:?}", err)); // B (2.b.) priekšlaicīgs return
         }
     };
-    for entry in current_entries {
-        // A (1.a.) let entry =;
+    for mut entry in current_entries {
+        // A (1.a.) let mut entry =;
         let path = std::path::Path::new(&entry.file_path); // A (1.a.) let path =; B (2.a.) ...::new()
         if !path.exists() {
             // B (2.a.) .exists(); C (3.a) path.exists() != true
@@ -348,16 +309,25 @@ pub async fn insert_and_refresh_blend_files(
                 Ok(_) => {} // C (3.c.) Ok()
                 Err(err) => {
                     // C (3.c) Err();
-                    show_ok_notification(
-                        // B (2.a.) show_ok_notification()
-                        app.clone(), // B (2.a.) app.clone();
-                        format!("Failed to delete project file entry: {:?}", err),
-                        tauri_plugin_dialog::MessageDialogKind::Error,
-                    );
                     return Err(format!("Failed to delete project file entry: {:?}", err));
                     // B (2.b.) priekšlaicīgs return
                 }
             }
+            continue; // B (2.b.) continue
+        }
+        // Unreadable .blend files keep their old metadata, they don't stop the refresh.
+        if !refresh_project_file_metadata(&mut entry).unwrap_or(false) {
+            // C (3.a) refresh_project_file_metadata() != true; B (2.a.) refresh_project_file_metadata(); B (2.a.) .unwrap_or()
+            continue; // B (2.b.) continue
+        }
+        match repository.update_metadata(&entry).await {
+            // C (3.b) match; B (2.a.) repository.update_metadata()
+            Ok(_) => {} // C (3.c.) Ok()
+            Err(err) => {
+                // C (3.c) Err();
+                return Err(format!("Failed to update project file metadata: {:?}", err));
+                // B (2.b.) priekšlaicīgs return
+            }
         }
     }
     Ok(())
@@ -476,7 +446,7 @@ pub async fn delete_blend_file(
 }
 
 /// ID: PF_005
-/// ABC analīzes rezultāts:19,74,40
+/// ABC analīzes rezultāts:30,97,52
 #[tauri::command]
 pub async fn open_blend_file(
     app: AppHandle,
@@ -484,7 +454,9 @@ pub async fn open_blend_file(
     id: String,
     installed_blender_version_id: String,
     launch_arguments_id: Option<String>,
-    python_script_id: Option<String>,
+    python_script_ids: Vec<String>,
+    python_expressions: Vec<String>,
+    script_arguments: Vec<String>,
 ) -> Result<(), String> {
     // Update project file last used Blender version.
     let project_file_repository = ProjectFileRepository::new(&state.pool); // A (1.a.) let project_file_repository =; B (2.a.) ...::new()
@@ -515,6 +487,46 @@ pub async fn open_blend_file(
         return Err(format!("Failed to fetch project file by ID")); // B (2.b.) priekšlaicīgs return
     }
     let mut project_file_entry = project_file_entry_list.remove(0); // A (1.a.) let mut project_file_entry =; B (2.a.) project_file_entry_list.remove()
+    let all_installed_blender_versions = match installed_blender_version_repository // A (1.a.) let all_installed_blender_versions =; C (3.b) match
+        .fetch(None, None, None) // B (2.a.) installed_blender_version_repository.fetch()
+        .await
+    {
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c) Err();
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to fetch installed Blender versions: {:?}", err),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            return Err(format!(
+                "Failed to fetch installed Blender versions: {:?}",
+                err
+            )); // B (2.b.) priekšlaicīgs return
+        }
+    };
+    let downgrade_warning = all_installed_blender_versions
+        .iter()
+        .find(|val| val.id == installed_blender_version_id)
+        .and_then(|val| {
+            get_project_file_downgrade_warning(
+                std::path::Path::new(&project_file_entry.file_path),
+                val,
+                &all_installed_blender_versions,
+            )
+        }); // A (1.a.) let downgrade_warning =; B (2.a.) .iter(); B (2.a.) .find(); C (3.a.) val.id == installed_blender_version_id; B (2.a.) .and_then(); B (2.a.) get_project_file_downgrade_warning(); B (2.a.) ::new()
+    if let Some(message) = downgrade_warning {
+        // A (1.d.) if let Some()
+        if !file_system_utility::show_ask_notification(
+            app.clone(),
+            message,
+            tauri_plugin_dialog::MessageDialogKind::Warning,
+        ) {
+            // C (3.a.) show_ask_notification() != true; B (2.a.) ::show_ask_notification(); B (2.a.) app.clone()
+            return Ok(()); // B (2.b.) priekšlaicīgs return
+        }
+    }
     project_file_entry.last_used_blender_version_id = Some(installed_blender_version_id.clone()); // A (1.a.) project_file_entry.last_used_blender_version_id =; B (2.a.) installed_blender_version_id.clone();
     match project_file_repository.update(&project_file_entry).await {
         // C (3.b) match; B (2.a.) project_file_repository.update()
@@ -578,8 +590,9 @@ pub async fn open_blend_file(
     }
     let mut final_launch_args: Vec<String> = vec![]; // A (1.a.) let mut final_launch_args =
     final_launch_args.push(project_file_entry.file_path.clone()); // A (1.c.) final_launch_args.push(); B (2.a.) project_file_entry.file_path.clone();
-    match launch_arguments_id {
-        // C (3.b) match
+    let mut launch_environment = LaunchEnvironment::default(); // A (1.a.) let mut launch_environment =; B (2.a.) ::default()
+    match launch_arguments_id.clone() {
+        // C (3.b) match; B (2.a.) .clone()
         Some(arg_id) => {
             // C (3.c) Some()
             let mut launch_argument_entry_list = match launch_argument_repository // A (1.a.) let mut launch_argument_entry_list =; C (3.b) match
@@ -625,79 +638,180 @@ pub async fn open_blend_file(
                     // B (2.b.) priekšlaicīgs return
                 }
             }
-            let parsed_args: Vec<String> = entry // A (1.a.) let parsed_args =
-                .argument_string
-                .split_whitespace() // B (2.a.) .split_whitespace()
-                .map(|s| s.to_string()) // B (2.a.) .map(); B (2.a.) .to_string()
-                .collect(); // B (2.a.) .collect()
-            final_launch_args.extend(parsed_args); // B (2.a.) .extend()
-        }
-        None => {} // C (3.c) None =>;
-    }
-    match python_script_id {
-        // C (3.b) match
-        Some(script_id) => {
-            // C (3.c) Some()
-            let mut python_script_entry_list = match python_script_repository // A (1.a.) let mut python_script_entry_list =; C (3.b) match
-                .fetch(Some(&script_id), None, None) // B (2.a.) python_script_repository.fetch()
-                .await
-            {
+            let parsed_args = match launch_argument::parse_argument_string(&entry.argument_string) {
+                // A (1.a.) let parsed_args =; C (3.b) match; B (2.a.) parse_argument_string()
                 Ok(val) => val, // C (3.c.) Ok()
                 Err(err) => {
-                    // C (3.c) Err();
+                    // C (3.c) Err()
                     show_ok_notification(
                         // B (2.a.) show_ok_notification()
                         app.clone(), // B (2.a.) app.clone();
-                        format!("Failed to fetch python scripts: {:?}", err),
+                        format!("Failed to parse launch argument: {}", err),
                         tauri_plugin_dialog::MessageDialogKind::Error,
                     );
-                    return Err(format!("Failed to fetch python scripts: {:?}", err));
+                    return Err(format!("Failed to parse launch argument: {}", err));
                     // B (2.b.) priekšlaicīgs return
                 }
             };
-            if python_script_entry_list.is_empty() {
-                // C (3.a) python_script_entry_list.is_empty() == true; B (2.a.) .is_empty()
-                show_ok_notification(
-                    // B (2.a.) show_ok_notification()
-                    app.clone(), // B (2.a.) app.clone();
-                    format!("Failed to fetch python script by ID"),
-                    tauri_plugin_dialog::MessageDialogKind::Error,
-                );
-                return Err(format!("Failed to fetch python script by ID")); // B (2.b.) priekšlaicīgs return
-            }
-            let entry = python_script_entry_list.remove(0); // A (1.a.) let entry =; B (2.a.) python_script_entry_list.remove()
-            match python_script_repository.update(&entry).await {
-                // C (3.b) match; B (2.a.) python_script_repository.update()
-                Ok(_) => {} // C (3.c.) Ok()
+            launch_environment = match launch_argument::get_launch_environment(
+                &entry,
+                Some(&project_file_entry.file_path),
+            ) {
+                // A (1.a.) launch_environment =; C (3.b) match; B (2.a.) ::get_launch_environment()
+                Ok(val) => val, // C (3.c.) Ok()
                 Err(err) => {
-                    // C (3.c) Err();
+                    // C (3.c) Err()
                     show_ok_notification(
                         // B (2.a.) show_ok_notification()
                         app.clone(), // B (2.a.) app.clone();
-                        format!("Failed to update python script: {:?}", err),
+                        format!("Failed to resolve launch environment: {}", err),
                         tauri_plugin_dialog::MessageDialogKind::Error,
                     );
-                    return Err(format!("Failed to update python script: {:?}", err));
+                    return Err(format!("Failed to resolve launch environment: {}", err));
                     // B (2.b.) priekšlaicīgs return
                 }
+            };
+            final_launch_args.extend(parsed_args); // B (2.a.) .extend()
+        }
+        None => {} // C (3.c) None =>;
+    }
+    let mut python_script_entries: Vec<PythonScript> = vec![]; // A (1.a.) let mut python_script_entries =
+    let mut python_script_safety_summaries: Vec<String> = vec![]; // A (1.a.) let mut python_script_safety_summaries =
+    for script_id in &python_script_ids {
+        // A (1.a.) let script_id =;
+        let mut python_script_entry_list = match python_script_repository // A (1.a.) let mut python_script_entry_list =; C (3.b) match
+            .fetch(Some(script_id), None, None) // B (2.a.) python_script_repository.fetch()
+            .await
+        {
+            Ok(val) => val, // C (3.c.) Ok()
+            Err(err) => {
+                // C (3.c) Err();
+                show_ok_notification(
+                    // B (2.a.) show_ok_notification()
+                    app.clone(), // B (2.a.) app.clone();
+                    format!("Failed to fetch python scripts: {:?}", err),
+                    tauri_plugin_dialog::MessageDialogKind::Error,
+                );
+                return Err(format!("Failed to fetch python scripts: {:?}", err));
+                // B (2.b.) priekšlaicīgs return
+            }
+        };
+        if python_script_entry_list.is_empty() {
+            // C (3.a) python_script_entry_list.is_empty() == true; B (2.a.) .is_empty()
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to fetch python script by ID"),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            return Err(format!("Failed to fetch python script by ID")); // B (2.b.) priekšlaicīgs return
+        }
+        let mut entry = python_script_entry_list.remove(0); // A (1.a.) let mut entry =; B (2.a.) python_script_entry_list.remove()
+
+        match python_script::refresh_python_script_metadata(&mut entry) {
+            // C (3.b) match; B (2.a.) ::refresh_python_script_metadata()
+            Ok(_) => {} // C (3.c.) Ok()
+            Err(err) => {
+                // C (3.c) Err()
+                show_ok_notification(
+                    // B (2.a.) show_ok_notification()
+                    app.clone(), // B (2.a.) app.clone();
+                    format!("Failed to read python script: {}", err),
+                    tauri_plugin_dialog::MessageDialogKind::Error,
+                );
+                return Err(format!("Failed to read python script: {}", err)); // B (2.b.) priekšlaicīgs return
             }
-            if !final_launch_args.contains(&"--python".to_string()) {
-                // C (3.a.) final_launch_args.contains(&"--python".to_string()) != true; B (2.a.) .contains(); B (2.a.) to_string()
-                final_launch_args.push("--python".to_string()); // A (1.c.) .push(); B (2.a.) .to_string()
-                final_launch_args.push(entry.script_file_path); // A (1.c.) .push()
-            } else if final_launch_args.contains(&"--python".to_string()) {
-                // C (3.b.) else; C (3.a.) final_launch_args.contains(&"--python".to_string()) == true; B (2.a.) .contains(); B (2.a.) .to_string()
-                final_launch_args.push(entry.script_file_path); // A (1.c.) .push()
+        }
+        match python_script_repository.update(&entry).await {
+            // C (3.b) match; B (2.a.) python_script_repository.update()
+            Ok(_) => {} // C (3.c.) Ok()
+            Err(err) => {
+                // C (3.c) Err();
+                show_ok_notification(
+                    // B (2.a.) show_ok_notification()
+                    app.clone(), // B (2.a.) app.clone();
+                    format!("Failed to update python script: {:?}", err),
+                    tauri_plugin_dialog::MessageDialogKind::Error,
+                );
+                return Err(format!("Failed to update python script: {:?}", err));
+                // B (2.b.) priekšlaicīgs return
             }
         }
-        None => {} // C (3.c) None =>;
+        if let Some(val) = python_script::get_python_script_safety_summary(&entry) {
+            // A (1.d.) if let Some(); B (2.a.) ::get_python_script_safety_summary()
+            python_script_safety_summaries.push(val); // A (1.c.) .push()
+        }
+        python_script_entries.push(entry); // A (1.c.) .push()
     }
-    match file_system_utility::launch_executable(
-        // C (3.b) match; B (2.a.) ::launch_executable()
-        installed_blender_version_entry.executable_file_path.into(), // B (2.a.) .into()
-        Some(final_launch_args),
-    ) {
-        Ok(_) => Ok(()), // C (3.c.) Ok()
+    if !python_script_safety_summaries.is_empty() {
+        // C (3.a.) python_script_safety_summaries.is_empty() != true; B (2.a.) .is_empty()
+        let confirmation = file_system_utility::show_ask_notification(
+            // A (1.a.) let confirmation =; B (2.a.) ::show_ask_notification()
+            app.clone(), // B (2.a.) app.clone();
+            format!(
+                "The selected python scripts were flagged by the safety check:\n\n{}\n\nLaunch anyway?",
+                python_script_safety_summaries.join("\n")
+            ), // B (2.a.) .join()
+            tauri_plugin_dialog::MessageDialogKind::Warning,
+        );
+        if confirmation == false {
+            // C (3.a.) confirmation == false
+            return Ok(()); // B (2.b.) priekšlaicīgs return
+        }
+    }
+
+    // Snapshots are only taken for launches that go ahead, they keep the exact script contents
+    // of this launch for the launch history.
+    let mut python_script_paths: Vec<String> = vec![]; // A (1.a.) let mut python_script_paths =
+    let mut python_script_snapshots: Vec<PythonScriptSnapshot> = vec![]; // A (1.a.) let mut python_script_snapshots =
+    for entry in python_script_entries {
+        // A (1.a.) let entry =;
+        let python_script_snapshot = match python_script::create_python_script_snapshot(&entry) {
+            // A (1.a.) let python_script_snapshot =; C (3.b) match; B (2.a.) ::create_python_script_snapshot()
+            Ok(val) => val, // C (3.c.) Ok()
+            Err(err) => {
+                // C (3.c) Err()
+                show_ok_notification(
+                    // B (2.a.) show_ok_notification()
+                    app.clone(), // B (2.a.) app.clone();
+                    format!("Failed to read python script: {}", err),
+                    tauri_plugin_dialog::MessageDialogKind::Error,
+                );
+                return Err(format!("Failed to read python script: {}", err)); // B (2.b.) priekšlaicīgs return
+            }
+        };
+        python_script_snapshots.push(python_script_snapshot); // A (1.c.) .push()
+        python_script_paths.push(entry.script_file_path); // A (1.c.) .push()
+    }
+    let final_launch_args = launch_argument::build_python_launch_arguments(
+        // A (1.a.) let final_launch_args =; B (2.a.) ::build_python_launch_arguments()
+        final_launch_args,
+        python_script_paths,
+        python_expressions,
+        script_arguments,
+    );
+    match process_supervisor::launch_supervised_executable(
+        // C (3.b) match; B (2.a.) ::launch_supervised_executable(); B (2.a.) app.clone()
+        app.clone(),
+        &installed_blender_version_entry,
+        final_launch_args,
+        launch_arguments_id,
+        python_script_ids.clone(), // B (2.a.) .clone()
+        Some(project_file_entry.id),
+        launch_environment,
+    )
+    .await
+    {
+        Ok(instance) => {
+            // C (3.c.) Ok()
+            python_script::insert_python_script_snapshots(
+                // B (2.a.) ::insert_python_script_snapshots(); B (2.a.) app.clone()
+                app.clone(),
+                python_script_snapshots,
+                &instance.id,
+            )
+            .await
+        }
         Err(err) => {
             // C (3.c) Err();
             show_ok_notification(
@@ -712,7 +826,7 @@ pub async fn open_blend_file(
 }
 
 /// ID: PF_006
-/// ABC analīzes rezultāts:8,25,16
+/// ABC analīzes rezultāts:10,28,19
 #[tauri::command]
 pub async fn create_new_project_file(
     app: AppHandle,
@@ -772,26 +886,34 @@ blend_file_path=r"{}"
         }
     };
     let entry = entry_list.remove(0); // A (1.a.) et entry =; B (2.a.) entry_list.remove()
-    match file_system_utility::launch_executable(
-        // C (3.b) match; B (2.a.) ::launch_executable()
-        entry.executable_file_path.into(), // B (2.a.) .into()
-        Some(vec![
+
+    // Blender saves the new file and quits, the timeout only guards against a hanging process.
+    let output = file_system_utility::get_executable_output(
+        // A (1.a.) let output =; B (2.a.) ::get_executable_output()
+        std::path::Path::new(&entry.executable_file_path), // B (2.a.) ::new()
+        vec![
             "--background".to_string(),  //  B (2.a.) .to_string()
             "--python-expr".to_string(), //  B (2.a.) .to_string()
             python_code_expression,
-        ]),
-    ) {
-        Ok(_) => {} // C (3.c.) Ok()
-        Err(err) => {
-            // C (3.c.) Err();
-            show_ok_notification(
-                // B (2.a.) show_ok_notification()
-                app.clone(), // B (2.a.) app.clone();
-                format!("Failed to launch Blender version: {:?}", err),
-                tauri_plugin_dialog::MessageDialogKind::Error,
-            );
-            return Err(format!("Failed to launch Blender version: {:?}", err)); // B (2.b.) priekšlaicīgs return
-        }
+        ],
+        std::time::Duration::from_secs(120), // B (2.a.) ::from_secs()
+    )
+    .await;
+    let result = match output {
+        // A (1.a.) let result =; C (3.b) match
+        Ok(_) if full_file_path.is_file() => Ok(()), // C (3.c.) Ok(); C (3.a.) full_file_path.is_file() == true; B (2.a.) .is_file()
+        Ok(_) => Err(format!("Blender did not save {}", full_file_path.display())), // C (3.c.) Ok(); B (2.a.) .display()
+        Err(err) => Err(err), // C (3.c.) Err();
+    };
+    if let Err(err) = result {
+        // A (1.d.) if let Err()
+        show_ok_notification(
+            // B (2.a.) show_ok_notification()
+            app.clone(), // B (2.a.) app.clone();
+            format!("Failed to create project file: {:?}", err),
+            tauri_plugin_dialog::MessageDialogKind::Error,
+        );
+        return Err(format!("Failed to create project file: {:?}", err)); // B (2.b.) priekšlaicīgs return
     }
     match insert_blend_file(app.clone(), state, full_file_path).await {
         // C (3.b) match; B (2.a.) insert_blend_file(); B (2.a.) app.clone();
@@ -853,16 +975,17 @@ pub async fn reveal_project_file_in_local_file_system(
 }
 
 /// ID: PF_008
-/// ABC analīzes rezultāts:4,17,9
+/// ABC analīzes rezultāts:14,44,24
 #[tauri::command]
-pub async fn create_project_file_archive_file(
+pub async fn pack_project_file(
     app: AppHandle,
     state: tauri::State<'_, AppState>,
-    id: Option<String>,
+    id: String,
+    compression_method: String,
 ) -> Result<(), String> {
     let repository = ProjectFileRepository::new(&state.pool); // A (1.a.) let repository =; B (2.a.) ...::new();
-    let mut entry_list = match repository.fetch(id.as_deref(), None, None).await {
-        // A (1.a.) let mut entry_list =; C (3.b) match; B (2.a.) repository.fetch(); B (2.a.) .as_deref()
+    let mut entry_list = match repository.fetch(Some(&id), None, None).await {
+        // A (1.a.) let mut entry_list =; C (3.b) match; B (2.a.) repository.fetch()
         Ok(val) => val, // C (3.c.) Ok()
         Err(err) => {
             // C (3.c.) Err();
@@ -875,24 +998,135 @@ pub async fn create_project_file_archive_file(
             return Err(format!("Failed to fetch project files: {:?}", err)); // B (2.b.) priekšlaicīgs return
         }
     };
+    if entry_list.is_empty() {
+        // C (3.a.) entry_list.is_empty() == true; B (2.a.) .is_empty()
+        return Err(format!(
+            "Failed to pack project file: project file not found"
+        )); // B (2.b.) priekšlaicīgs return
+    }
     let entry = entry_list.remove(0); // A (1.a.) let entry =; B (2.a.) entry_list.remove();
-    let archive_path = match file_system_utility::archive_file(std::path::PathBuf::from(
-        // A (1.a.) let archive_path =; C (3.b) match; B (2.a.) ::archive_file(); B (2.a.) ::from()
-        entry.file_path.clone(), // B (2.a.) entry.file_path.clone();
-    )) {
+    let file_path = std::path::PathBuf::from(&entry.file_path); // A (1.a.) let file_path =; B (2.a.) ::from()
+
+    // Walking the dependency tree parses every linked .blend file, it must not stall the async runtime.
+    let dependency_file_path = file_path.clone(); // A (1.a.) let dependency_file_path =; B (2.a.) .clone()
+    let dependencies = tokio::task::spawn_blocking(move || {
+        // A (1.a.) let dependencies =; B (2.a.) ::spawn_blocking()
+        let mut visited_file_paths = Vec::new(); // A (1.a.) let mut visited_file_paths =; B (2.a.) ...::new()
+        blend_file::read_blend_file_dependency_tree(&dependency_file_path, &mut visited_file_paths)
+        // B (2.a.) read_blend_file_dependency_tree()
+    })
+    .await
+    .map_err(|err| format!("{:?}", err))
+    .and_then(|val| val); // B (2.a.) .map_err(); B (2.a.) .and_then()
+    let dependencies = match dependencies {
+        // A (1.a.) let dependencies =; C (3.b) match
         Ok(val) => val, // C (3.c.) Ok()
         Err(err) => {
             // C (3.c.) Err();
             show_ok_notification(
                 // B (2.a.) show_ok_notification()
                 app.clone(), // B (2.a.) app.clone();
-                format!("Failed to archive project file: {:?}", err),
+                format!("Failed to read project file dependencies: {:?}", err),
                 tauri_plugin_dialog::MessageDialogKind::Error,
             );
-            return Err(format!("Failed to archive project file: {:?}", err)); // B (2.b.) priekšlaicīgs return
+            return Err(format!(
+                "Failed to read project file dependencies: {:?}",
+                err
+            )); // B (2.b.) priekšlaicīgs return
         }
     };
-    match file_system_utility::open_in_file_explorer(archive_path) {
+    let archive_file_path_option = match file_system_utility::get_save_file_path_from_file_explorer(
+        app.clone(),
+        format!("{}.zip", get_project_file_archive_root(&file_path)),
+    )
+    .await
+    {
+        // A (1.a.) let archive_file_path_option =; C (3.b) match; B (2.a.) ::get_save_file_path_from_file_explorer(); B (2.a.) app.clone(); B (2.a.) get_project_file_archive_root()
+        Ok(val) => val,          // C (3.c.) Ok()
+        Err(_) => return Ok(()), // C (3.c.) Err(); B (2.b.) priekšlaicīgs return
+    };
+    let Some(archive_file_path) = archive_file_path_option else {
+        // A (1.d.) let Some() else
+        return Ok(()); // B (2.b.) priekšlaicīgs return
+    };
+    let (zip_compression_method, compression_method_name) = match compression_method.as_str() {
+        // A (1.a.) let (zip_compression_method, compression_method_name) =; C (3.b) match; B (2.a.) .as_str()
+        "zstd" => (zip::CompressionMethod::Zstd, "zstd"), // C (3.c) "zstd" =>
+        _ => (zip::CompressionMethod::Deflated, "deflate"), // C (3.c) _ =>
+    };
+
+    // Resolving the entries and compressing the files are blocking too.
+    let zip_file_path = archive_file_path.clone(); // A (1.a.) let zip_file_path =; B (2.a.) .clone()
+    let manifest = tokio::task::spawn_blocking(move || {
+        // A (1.a.) let manifest =; B (2.a.) ::spawn_blocking()
+        write_project_file_archive(
+            &entry,
+            &file_path,
+            &dependencies,
+            &zip_file_path,
+            zip_compression_method,
+            compression_method_name,
+        ) // B (2.a.) write_project_file_archive()
+    })
+    .await
+    .map_err(|err| format!("{:?}", err))
+    .and_then(|val| val); // B (2.a.) .map_err(); B (2.a.) .and_then()
+    let manifest = match manifest {
+        // A (1.a.) let manifest =; C (3.b) match
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c.) Err();
+            let _ = std::fs::remove_file(&archive_file_path); // B (2.a.) ::remove_file()
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to pack project file: {:?}", err),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            return Err(format!("Failed to pack project file: {:?}", err)); // B (2.b.) priekšlaicīgs return
+        }
+    };
+    let missing_file_count = manifest.entries.iter().filter(|val| val.is_missing).count(); // A (1.a.) let missing_file_count =; B (2.a.) .iter(); B (2.a.) .filter(); C (3.a.) val.is_missing == true; B (2.a.) .count()
+    if missing_file_count > 0 {
+        // C (3.a.) missing_file_count > 0
+        show_ok_notification(
+            // B (2.a.) show_ok_notification()
+            app.clone(), // B (2.a.) app.clone();
+            format!(
+                "Packed project file without {} missing referenced files, they are listed in manifest.json.",
+                missing_file_count
+            ),
+            tauri_plugin_dialog::MessageDialogKind::Warning,
+        );
+    }
+    let relink_entries: Vec<String> = manifest
+        .entries
+        .iter()
+        .filter(|val| val.needs_relink)
+        .map(|val| {
+            format!(
+                "{} \"{}\": {} -> {}",
+                val.dependency_type,
+                val.name,
+                val.stored_path,
+                val.archive_path.clone().unwrap_or_default()
+            )
+        })
+        .collect(); // A (1.a.) let relink_entries =; B (2.a.) .iter(); B (2.a.) .filter(); C (3.a.) val.needs_relink == true; B (2.a.) .map(); B (2.a.) .clone(); B (2.a.) .unwrap_or_default(); B (2.a.) .collect()
+    if !relink_entries.is_empty() {
+        // C (3.a.) relink_entries.is_empty() == false; B (2.a.) .is_empty()
+        show_ok_notification(
+            // B (2.a.) show_ok_notification()
+            app.clone(), // B (2.a.) app.clone();
+            format!(
+                "The project file references {} files by external or absolute paths. They were packed, but will not be found after extracting until they are relinked in Blender:\n{}",
+                relink_entries.len(),
+                relink_entries.join("\n")
+            ), // B (2.a.) .len(); B (2.a.) .join()
+            tauri_plugin_dialog::MessageDialogKind::Warning,
+        );
+    }
+    match file_system_utility::open_in_file_explorer(archive_file_path) {
         // C (3.b) match; B (2.a.) ::open_in_file_explorer()
         Ok(_) => Ok(()), // C (3.c.) Ok()
         Err(err) => {
@@ -913,3 +1147,567 @@ pub async fn create_project_file_archive_file(
         }
     }
 }
+
+/// ID: PF_017
+/// ABC analīzes rezultāts:6,16,6
+pub fn write_project_file_archive(
+    entry: &ProjectFile,
+    file_path: &std::path::Path,
+    dependencies: &[BlendFileDependency],
+    archive_file_path: &std::path::Path,
+    zip_compression_method: zip::CompressionMethod,
+    compression_method_name: &str,
+) -> Result<ProjectFileArchiveManifest, String> {
+    let manifest = ProjectFileArchiveManifest {
+        // A (1.a.) let manifest =
+        project_file_name: entry.file_name.clone(), // B (2.a.) .clone()
+        saved_blender_version: entry.saved_blender_version.clone(), // B (2.a.) .clone()
+        compression_method: compression_method_name.to_string(), // B (2.a.) .to_string()
+        created: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
+        entries: get_project_file_archive_entries(file_path, dependencies), // B (2.a.) get_project_file_archive_entries()
+    };
+    let manifest_content = match serde_json::to_string_pretty(&manifest) {
+        // A (1.a.) let manifest_content =; C (3.b) match; B (2.a.) ::to_string_pretty()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => return Err(format!("Failed to serialize archive manifest: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    };
+    let mut files: Vec<(std::path::PathBuf, String)> = Vec::new(); // A (1.a.) let mut files =; B (2.a.) ...::new()
+    for archive_entry in &manifest.entries {
+        // A (1.a.) let archive_entry =;
+        let Some(archive_path) = &archive_entry.archive_path else {
+            // A (1.d.) let Some() else
+            continue; // B (2.b.) continue
+        };
+        // A file referenced from several places is stored once.
+        if files.iter().any(|val| &val.1 == archive_path) {
+            // C (3.a.) .any() == true; B (2.a.) .iter(); B (2.a.) .any(); C (3.a.) val.1 == archive_path
+            continue; // B (2.b.) continue
+        }
+        files.push((
+            std::path::PathBuf::from(&archive_entry.source_path),
+            archive_path.clone(),
+        )); // A (1.c.) .push(); B (2.a.) ::from(); B (2.a.) .clone()
+    }
+    let generated_files = vec![(
+        format!("{}/manifest.json", get_project_file_archive_root(file_path)),
+        manifest_content,
+    )]; // A (1.a.) let generated_files =; B (2.a.) get_project_file_archive_root()
+    file_system_utility::archive_files(
+        archive_file_path,
+        &files,
+        &generated_files,
+        zip_compression_method,
+    )?; // B (2.a.) ::archive_files()
+    Ok(manifest)
+}
+
+/// ID: PF_009
+/// ABC analīzes rezultāts:12,17,6
+pub fn refresh_project_file_metadata(entry: &mut ProjectFile) -> Result<bool, String> {
+    let file_modified = match std::fs::metadata(&entry.file_path).and_then(|val| val.modified()) {
+        // A (1.a.) let file_modified =; C (3.b) match; B (2.a.) ::metadata(); B (2.a.) .and_then(); B (2.a.) .modified()
+        Ok(val) => chrono::DateTime::<chrono::Utc>::from(val).to_rfc3339(), // C (3.c.) Ok(); B (2.a.) ::from(); B (2.a.) .to_rfc3339()
+        Err(err) => {
+            // C (3.c) Err();
+            return Err(format!(
+                "Failed to read project file modification time: {:?}",
+                err
+            )); // B (2.b.) priekšlaicīgs return
+        }
+    };
+    // The .blend file is only parsed again after it was saved since the last read.
+    if entry.metadata_file_modified.as_deref() == Some(file_modified.as_str())
+        && entry.file_hash.is_some()
+    {
+        // C (3.a) entry.metadata_file_modified == file_modified; C (3.a.) entry.file_hash.is_some() == true; B (2.a.) .as_deref(); B (2.a.) .as_str(); B (2.a.) .is_some()
+        return Ok(false); // B (2.b.) priekšlaicīgs return
+    }
+    let metadata = blend_file::read_blend_file_metadata(std::path::Path::new(&entry.file_path))?; // A (1.a.) let metadata =; B (2.a.) read_blend_file_metadata(); B (2.a.) ...::new()
+
+    // The hash keys the thumbnail cache, it goes stale together with the metadata.
+    let file_hash =
+        file_system_utility::get_file_sha256_checksum(std::path::Path::new(&entry.file_path))?; // A (1.a.) let file_hash =; B (2.a.) ::get_file_sha256_checksum(); B (2.a.) ...::new()
+    entry.saved_blender_version = Some(metadata.saved_blender_version); // A (1.a.) entry.saved_blender_version =
+    entry.scene_names_json = match serde_json::to_string(&metadata.scene_names) {
+        // A (1.a.) entry.scene_names_json =; C (3.b) match; B (2.a.) ::to_string()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => return Err(format!("Failed to serialize scene names: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    };
+    entry.frame_start = metadata.frame_start; // A (1.a.) entry.frame_start =
+    entry.frame_end = metadata.frame_end; // A (1.a.) entry.frame_end =
+    entry.render_engine = metadata.render_engine; // A (1.a.) entry.render_engine =
+    entry.resolution_x = metadata.resolution_x; // A (1.a.) entry.resolution_x =
+    entry.resolution_y = metadata.resolution_y; // A (1.a.) entry.resolution_y =
+    entry.resolution_percentage = metadata.resolution_percentage; // A (1.a.) entry.resolution_percentage =
+    entry.metadata_file_modified = Some(file_modified); // A (1.a.) entry.metadata_file_modified =
+    entry.file_hash = Some(file_hash); // A (1.a.) entry.file_hash =
+    Ok(true)
+}
+
+/// ID: PF_010
+/// ABC analīzes rezultāts:7,12,9
+#[tauri::command]
+pub async fn fetch_project_file_thumbnail(
+    app: AppHandle,
+    state: tauri::State<'_, AppState>,
+    id: String,
+) -> Result<Option<Vec<u8>>, String> {
+    let repository = ProjectFileRepository::new(&state.pool); // A (1.a.) let repository =; B (2.a.) ...::new()
+    let mut project_file_list = match repository.fetch(Some(&id), None, None).await {
+        // A (1.a.) let mut project_file_list =; C (3.b) match; B (2.a.) repository.fetch()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c.) Err();
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to fetch project files: {:?}", err),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            return Err(format!("Failed to fetch project files: {:?}", err)); // B (2.b.) priekšlaicīgs return
+        }
+    };
+    if project_file_list.is_empty() {
+        // C (3.a.) project_file_list.is_empty() == true; B (2.a.) .is_empty()
+        return Err(format!(
+            "Failed to fetch project file thumbnail: project file not found"
+        )); // B (2.b.) priekšlaicīgs return
+    }
+    let mut entry = project_file_list.remove(0); // A (1.a.) let mut entry =; B (2.a.) project_file_list.remove()
+
+    // Thumbnails are requested for every row of the project list, failures are not shown as dialogs.
+    // Hashing and parsing large .blend files must not stall the async runtime.
+    let result = tokio::task::spawn_blocking(move || {
+        // A (1.a.) let result =; B (2.a.) ::spawn_blocking()
+        let is_refreshed = refresh_project_file_metadata(&mut entry)?; // A (1.a.) let is_refreshed =; B (2.a.) refresh_project_file_metadata()
+        let thumbnail = get_project_file_thumbnail(&entry)?; // A (1.a.) let thumbnail =; B (2.a.) get_project_file_thumbnail()
+        Ok::<_, String>((entry, is_refreshed, thumbnail))
+    })
+    .await;
+    let (entry, is_refreshed, thumbnail) = match result {
+        // A (1.a.) let (entry, is_refreshed, thumbnail) =; C (3.b) match
+        Ok(val) => val?, // C (3.c.) Ok()
+        Err(err) => return Err(format!("Failed to fetch project file thumbnail: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    };
+    if is_refreshed {
+        // C (3.a.) is_refreshed == true
+        if let Err(err) = repository.update_metadata(&entry).await {
+            // A (1.d.) if let Err(); B (2.a.) repository.update_metadata()
+            return Err(format!("Failed to update project file metadata: {:?}", err));
+            // B (2.b.) priekšlaicīgs return
+        }
+    }
+    Ok(thumbnail)
+}
+
+/// ID: PF_016
+/// ABC analīzes rezultāts:6,12,7
+pub fn get_project_file_thumbnail(entry: &ProjectFile) -> Result<Option<Vec<u8>>, String> {
+    let Some(file_hash) = &entry.file_hash else {
+        // A (1.d.) let Some() else
+        return Ok(None); // B (2.b.) priekšlaicīgs return
+    };
+    // The cache is keyed by content, a re-saved file gets a new thumbnail and a copied one shares it.
+    let thumbnail_file_path = file_system_utility::get_thumbnail_cache_directory_path()?
+        .join(format!("{}.png", file_hash)); // A (1.a.) let thumbnail_file_path =; B (2.a.) ::get_thumbnail_cache_directory_path(); B (2.a.) .join()
+    if thumbnail_file_path.is_file() {
+        // C (3.a.) thumbnail_file_path.is_file() == true; B (2.a.) .is_file()
+        return match std::fs::read(&thumbnail_file_path) {
+            // C (3.b) match; B (2.a.) ::read()
+            Ok(val) => Ok(Some(val)), // C (3.c.) Ok()
+            Err(err) => Err(format!("Failed to read cached thumbnail: {:?}", err)), // C (3.c) Err()
+        }; // B (2.b.) priekšlaicīgs return
+    }
+    let file_path = std::path::Path::new(&entry.file_path); // A (1.a.) let file_path =; B (2.a.) ::new()
+    let Some(thumbnail) = blend_file::read_blend_file_thumbnail(file_path)? else {
+        // A (1.d.) let Some() else; B (2.a.) read_blend_file_thumbnail()
+        return Ok(None); // B (2.b.) priekšlaicīgs return
+    };
+    let png = blend_file::encode_blend_file_thumbnail_png(&thumbnail)?; // A (1.a.) let png =; B (2.a.) encode_blend_file_thumbnail_png()
+    match std::fs::write(&thumbnail_file_path, &png) {
+        // C (3.b) match; B (2.a.) ::write()
+        Ok(_) => Ok(Some(png)), // C (3.c.) Ok()
+        Err(err) => Err(format!("Failed to write cached thumbnail: {:?}", err)), // C (3.c) Err()
+    }
+}
+
+/// ID: PF_011
+/// ABC analīzes rezultāts:4,15,9
+#[tauri::command]
+pub async fn fetch_project_file_dependencies(
+    app: AppHandle,
+    state: tauri::State<'_, AppState>,
+    id: String,
+) -> Result<Vec<BlendFileDependency>, String> {
+    let repository = ProjectFileRepository::new(&state.pool); // A (1.a.) let repository =; B (2.a.) ...::new()
+    let mut project_file_list = match repository.fetch(Some(&id), None, None).await {
+        // A (1.a.) let mut project_file_list =; C (3.b) match; B (2.a.) repository.fetch()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c.) Err();
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to fetch project files: {:?}", err),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            return Err(format!("Failed to fetch project files: {:?}", err)); // B (2.b.) priekšlaicīgs return
+        }
+    };
+    if project_file_list.is_empty() {
+        // C (3.a.) project_file_list.is_empty() == true; B (2.a.) .is_empty()
+        return Err(format!(
+            "Failed to fetch project file dependencies: project file not found"
+        )); // B (2.b.) priekšlaicīgs return
+    }
+    let entry = project_file_list.remove(0); // A (1.a.) let entry =; B (2.a.) project_file_list.remove()
+    let mut visited_file_paths = Vec::new(); // A (1.a.) let mut visited_file_paths =; B (2.a.) ...::new()
+    match blend_file::read_blend_file_dependency_tree(
+        std::path::Path::new(&entry.file_path),
+        &mut visited_file_paths,
+    ) {
+        // C (3.b) match; B (2.a.) read_blend_file_dependency_tree(); B (2.a.) ::new()
+        Ok(val) => Ok(val), // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c.) Err();
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to read project file dependencies: {:?}", err),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            Err(format!(
+                "Failed to read project file dependencies: {:?}",
+                err
+            ))
+        }
+    }
+}
+
+/// ID: PF_012
+/// ABC analīzes rezultāts:3,12,6
+pub fn get_project_file_downgrade_warning(
+    file_path: &std::path::Path,
+    target_blender_version: &InstalledBlenderVersion,
+    installed_blender_versions: &[InstalledBlenderVersion],
+) -> Option<String> {
+    // Only the header is read, an unreadable file is left for Blender to report.
+    let saved_version = blend_file::read_blend_file_version(file_path).ok()?; // A (1.a.) let saved_version =; B (2.a.) ::read_blend_file_version(); B (2.a.) .ok()
+    let target_version =
+        blender_version::parse_blender_major_minor_version(&target_blender_version.version)?; // A (1.a.) let target_version =; B (2.a.) ::parse_blender_major_minor_version()
+    if target_version >= saved_version {
+        // C (3.a.) target_version >= saved_version
+        return None; // B (2.b.) priekšlaicīgs return
+    }
+    let suggestion = match blender_version::find_closest_blender_version(
+        installed_blender_versions,
+        saved_version,
+    ) {
+        // A (1.a.) let suggestion =; C (3.b) match; B (2.a.) ::find_closest_blender_version()
+        Some(val)
+            if blender_version::parse_blender_major_minor_version(&val.version)
+                .is_some_and(|version| version >= saved_version) =>
+        {
+            // C (3.c) Some(); B (2.a.) ::parse_blender_major_minor_version(); B (2.a.) .is_some_and(); C (3.a.) version >= saved_version
+            format!(
+                "The closest installed version is Blender {} {}.",
+                val.version, val.variant_type
+            )
+        }
+        _ => format!(
+            "No installed Blender version is {}.{} or newer.",
+            saved_version.0, saved_version.1
+        ), // C (3.c) _ =>
+    };
+    Some(format!(
+        "This file was saved with Blender {}.{}. Opening it in the older Blender {} {} can lose data. {}\n\nOpen anyway?",
+        saved_version.0,
+        saved_version.1,
+        target_blender_version.version,
+        target_blender_version.variant_type,
+        suggestion
+    ))
+}
+
+/// ID: PF_013
+/// ABC analīzes rezultāts:7,21,13
+#[tauri::command]
+pub async fn fetch_project_file_default_blender_version(
+    app: AppHandle,
+    state: tauri::State<'_, AppState>,
+    id: String,
+) -> Result<Option<String>, String> {
+    let project_file_repository = ProjectFileRepository::new(&state.pool); // A (1.a.) let project_file_repository =; B (2.a.) ...::new()
+    let installed_blender_version_repository = InstalledBlenderVersionRepository::new(&state.pool); // A (1.a.) let installed_blender_version_repository =; B (2.a.) ...::new()
+    let mut project_file_list = match project_file_repository.fetch(Some(&id), None, None).await {
+        // A (1.a.) let mut project_file_list =; C (3.b) match; B (2.a.) project_file_repository.fetch()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c.) Err();
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to fetch project files: {:?}", err),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            return Err(format!("Failed to fetch project files: {:?}", err)); // B (2.b.) priekšlaicīgs return
+        }
+    };
+    if project_file_list.is_empty() {
+        // C (3.a.) project_file_list.is_empty() == true; B (2.a.) .is_empty()
+        return Err(format!("Failed to fetch project file by ID")); // B (2.b.) priekšlaicīgs return
+    }
+    let entry = project_file_list.remove(0); // A (1.a.) let entry =; B (2.a.) project_file_list.remove()
+    let installed_blender_versions = match installed_blender_version_repository // A (1.a.) let installed_blender_versions =; C (3.b) match
+        .fetch(None, None, None) // B (2.a.) installed_blender_version_repository.fetch()
+        .await
+    {
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c) Err();
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to fetch installed Blender versions: {:?}", err),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            return Err(format!(
+                "Failed to fetch installed Blender versions: {:?}",
+                err
+            )); // B (2.b.) priekšlaicīgs return
+        }
+    };
+    if let Some(last_used_blender_version_id) = entry.last_used_blender_version_id {
+        // A (1.d.) if let Some()
+        if installed_blender_versions
+            .iter()
+            .any(|val| val.id == last_used_blender_version_id)
+        {
+            // C (3.a.) .any() == true; B (2.a.) .iter(); B (2.a.) .any(); C (3.a.) val.id == last_used_blender_version_id
+            return Ok(Some(last_used_blender_version_id)); // B (2.b.) priekšlaicīgs return
+        }
+    }
+    // Without a usable last version the saved version picks the install, unreadable files get the default one.
+    let best_blender_version =
+        match blend_file::read_blend_file_version(std::path::Path::new(&entry.file_path)) {
+            // A (1.a.) let best_blender_version =; C (3.b) match; B (2.a.) ::read_blend_file_version(); B (2.a.) ::new()
+            Ok(val) => {
+                blender_version::find_closest_blender_version(&installed_blender_versions, val)
+            } // C (3.c.) Ok(); B (2.a.) ::find_closest_blender_version()
+            Err(_) => installed_blender_versions
+                .iter()
+                .find(|val| val.is_default)
+                .or(installed_blender_versions.first()), // C (3.c) Err(); B (2.a.) .iter(); B (2.a.) .find(); C (3.a.) val.is_default == true; B (2.a.) .or(); B (2.a.) .first()
+        };
+    Ok(best_blender_version.map(|val| val.id.clone())) // B (2.a.) .map(); B (2.a.) .clone()
+}
+
+/// ID: PF_014
+/// ABC analīzes rezultāts:1,4,1
+pub fn get_project_file_archive_root(file_path: &std::path::Path) -> String {
+    file_path
+        .file_stem()
+        .map(|val| val.to_string_lossy().to_string())
+        .unwrap_or(String::from("project")) // B (2.a.) .file_stem(); B (2.a.) .map(); B (2.a.) .to_string_lossy(); B (2.a.) .to_string(); B (2.a.) .unwrap_or(); B (2.a.) ::from()
+}
+
+/// ID: PF_015
+/// ABC analīzes rezultāts:20,44,13
+pub fn get_project_file_archive_entries(
+    file_path: &std::path::Path,
+    dependencies: &[BlendFileDependency],
+) -> Vec<ProjectFileArchiveEntry> {
+    let archive_root = get_project_file_archive_root(file_path); // A (1.a.) let archive_root =; B (2.a.) get_project_file_archive_root()
+    let project_directory = file_path.parent().unwrap_or(std::path::Path::new("")); // A (1.a.) let project_directory =; B (2.a.) .parent(); B (2.a.) .unwrap_or(); B (2.a.) ::new()
+    let project_directory =
+        std::fs::canonicalize(project_directory).unwrap_or(project_directory.to_path_buf()); // A (1.a.) let project_directory =; B (2.a.) ::canonicalize(); B (2.a.) .unwrap_or(); B (2.a.) .to_path_buf()
+    let file_name = file_path
+        .file_name()
+        .map(|val| val.to_string_lossy().to_string())
+        .unwrap_or_default(); // A (1.a.) let file_name =; B (2.a.) .file_name(); B (2.a.) .map(); B (2.a.) .to_string_lossy(); B (2.a.) .to_string(); B (2.a.) .unwrap_or_default()
+    let blend_archive_path = format!("{}/{}", archive_root, file_name); // A (1.a.) let blend_archive_path =
+    let mut archive_paths: std::collections::HashMap<std::path::PathBuf, String> =
+        std::collections::HashMap::new(); // A (1.a.) let mut archive_paths =; B (2.a.) ::new()
+    archive_paths.insert(
+        std::fs::canonicalize(file_path).unwrap_or(file_path.to_path_buf()),
+        blend_archive_path.clone(),
+    ); // A (1.c.) .insert(); B (2.a.) ::canonicalize(); B (2.a.) .unwrap_or(); B (2.a.) .to_path_buf(); B (2.a.) .clone()
+    let mut entries = vec![ProjectFileArchiveEntry {
+        // A (1.a.) let mut entries =
+        dependency_type: String::from("Blend"), // B (2.a.) ::from()
+        name: file_name,
+        stored_path: file_path.to_string_lossy().to_string(), // B (2.a.) .to_string_lossy(); B (2.a.) .to_string()
+        source_path: file_path.to_string_lossy().to_string(), // B (2.a.) .to_string_lossy(); B (2.a.) .to_string()
+        archive_path: Some(blend_archive_path),
+        ..Default::default() // B (2.a.) ::default()
+    }];
+    let mut pending_dependencies: Vec<&BlendFileDependency> = dependencies.iter().rev().collect(); // A (1.a.) let mut pending_dependencies =; B (2.a.) .iter(); B (2.a.) .rev(); B (2.a.) .collect()
+    while let Some(dependency) = pending_dependencies.pop() {
+        // A (1.d.) while let Some(); B (2.a.) .pop()
+        pending_dependencies.extend(dependency.dependencies.iter().rev()); // A (1.c.) .extend(); B (2.a.) .iter(); B (2.a.) .rev()
+        let mut archive_entry = ProjectFileArchiveEntry {
+            // A (1.a.) let mut archive_entry =
+            dependency_type: dependency.dependency_type.clone(), // B (2.a.) .clone()
+            name: dependency.name.clone(),                       // B (2.a.) .clone()
+            stored_path: dependency.stored_path.clone(),         // B (2.a.) .clone()
+            source_path: dependency.resolved_path.clone(),       // B (2.a.) .clone()
+            archive_path: None,
+            is_packed: dependency.is_packed,
+            is_missing: dependency.is_missing,
+            needs_relink: false,
+        };
+        // Packed files travel inside the .blend, missing ones are only listed. Sequences and UDIM
+        // tiles are listed too, their files follow as dependencies of their own.
+        if dependency.is_packed
+            || dependency.is_missing
+            || blend_file::BLEND_FILE_DEPENDENCY_FILE_LIST_TYPES
+                .contains(&dependency.dependency_type.as_str())
+        {
+            // C (3.a.) dependency.is_packed == true; C (3.a.) dependency.is_missing == true; C (3.a.) .contains() == true; B (2.a.) .contains(); B (2.a.) .as_str()
+            entries.push(archive_entry); // A (1.c.) .push()
+            continue; // B (2.b.) continue
+        }
+        let source_path = std::path::PathBuf::from(&dependency.resolved_path); // A (1.a.) let source_path =; B (2.a.) ::from()
+        let source_path = std::fs::canonicalize(&source_path).unwrap_or(source_path); // A (1.a.) let source_path =; B (2.a.) ::canonicalize(); B (2.a.) .unwrap_or()
+        if let Some(val) = archive_paths.get(&source_path) {
+            // A (1.d.) if let Some(); B (2.a.) .get()
+            archive_entry.archive_path = Some(val.clone()); // A (1.a.) archive_entry.archive_path =; B (2.a.) .clone()
+            entries.push(archive_entry); // A (1.c.) .push()
+            continue; // B (2.b.) continue
+        }
+        // Files inside the project directory keep their place, so `//` paths still resolve after
+        // extracting. Files from elsewhere are collected by type and have to be relinked in Blender.
+        let archive_path = match source_path.strip_prefix(&project_directory) {
+            // A (1.a.) let archive_path =; C (3.b) match; B (2.a.) .strip_prefix()
+            Ok(val) => format!(
+                "{}/{}",
+                archive_root,
+                val.components()
+                    .map(|component| component.as_os_str().to_string_lossy().to_string())
+                    .collect::<Vec<String>>()
+                    .join("/")
+            ), // C (3.c.) Ok(); B (2.a.) .components(); B (2.a.) .map(); B (2.a.) .as_os_str(); B (2.a.) .to_string_lossy(); B (2.a.) .to_string(); B (2.a.) .collect(); B (2.a.) .join()
+            Err(_) => {
+                // C (3.c) Err();
+                let source_file_name = source_path
+                    .file_name()
+                    .map(|val| val.to_string_lossy().to_string())
+                    .unwrap_or_default(); // A (1.a.) let source_file_name =; B (2.a.) .file_name(); B (2.a.) .map(); B (2.a.) .to_string_lossy(); B (2.a.) .to_string(); B (2.a.) .unwrap_or_default()
+                let external_directory = format!(
+                    "{}/external/{}",
+                    archive_root,
+                    dependency.dependency_type.to_lowercase()
+                ); // A (1.a.) let external_directory =; B (2.a.) .to_lowercase()
+                let mut archive_path = format!("{}/{}", external_directory, source_file_name); // A (1.a.) let mut archive_path =
+                let mut suffix = 1; // A (1.a.) let mut suffix =
+                while archive_paths.values().any(|val| val == &archive_path) {
+                    // C (3.a.) .any() == true; B (2.a.) .values(); B (2.a.) .any(); C (3.a.) val == archive_path
+                    archive_path =
+                        format!("{}/{}_{}", external_directory, suffix, source_file_name); // A (1.a.) archive_path =
+                    suffix += 1; // A (1.b.) suffix +=
+                }
+                archive_path
+            }
+        };
+        // The .blend keeps the stored path, so only `//` paths inside the project directory resolve
+        // after extracting.
+        archive_entry.needs_relink = !dependency.stored_path.starts_with("//")
+            || !source_path.starts_with(&project_directory); // A (1.a.) archive_entry.needs_relink =; B (2.a.) .starts_with(); B (2.a.) .starts_with()
+        archive_paths.insert(source_path, archive_path.clone()); // A (1.c.) .insert(); B (2.a.) .clone()
+        archive_entry.archive_path = Some(archive_path); // A (1.a.) archive_entry.archive_path =
+        entries.push(archive_entry); // A (1.c.) .push()
+    }
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_dependency(
+        stored_path: &str,
+        resolved_path: &std::path::Path,
+    ) -> BlendFileDependency {
+        BlendFileDependency {
+            dependency_type: String::from("Image"),
+            name: String::from("IMtexture"),
+            stored_path: String::from(stored_path),
+            resolved_path: resolved_path.to_string_lossy().to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn archive_entries_flag_paths_that_need_relinking() {
+        let directory_path =
+            std::env::temp_dir().join(format!("blendio-test-{}", uuid::Uuid::new_v4()));
+        let project_directory = directory_path.join("project");
+        std::fs::create_dir_all(project_directory.join("textures")).unwrap();
+        std::fs::create_dir_all(directory_path.join("shared")).unwrap();
+        let file_path = project_directory.join("scene.blend");
+        let relative_file_path = project_directory.join("textures").join("wood.png");
+        let absolute_file_path = project_directory.join("textures").join("metal.png");
+        let external_file_path = directory_path.join("shared").join("wood.png");
+        for val in [
+            &file_path,
+            &relative_file_path,
+            &absolute_file_path,
+            &external_file_path,
+        ] {
+            std::fs::write(val, b"payload").unwrap();
+        }
+        let dependencies = vec![
+            create_dependency("//textures/wood.png", &relative_file_path),
+            create_dependency(&absolute_file_path.to_string_lossy(), &absolute_file_path),
+            create_dependency("//../shared/wood.png", &external_file_path),
+        ];
+
+        let entries = get_project_file_archive_entries(&file_path, &dependencies);
+
+        assert_eq!(entries.len(), 4);
+        assert!(!entries[0].needs_relink);
+        assert_eq!(
+            entries[1].archive_path.as_deref(),
+            Some("scene/textures/wood.png")
+        );
+        assert!(!entries[1].needs_relink);
+        assert_eq!(
+            entries[2].archive_path.as_deref(),
+            Some("scene/textures/metal.png")
+        );
+        assert!(entries[2].needs_relink);
+        assert_eq!(
+            entries[3].archive_path.as_deref(),
+            Some("scene/external/image/wood.png")
+        );
+        assert!(entries[3].needs_relink);
+        std::fs::remove_dir_all(&directory_path).unwrap();
+    }
+
+    #[test]
+    fn archive_entries_pack_the_files_of_image_sequences() {
+        let directory_path =
+            std::env::temp_dir().join(format!("blendio-test-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(directory_path.join("frames")).unwrap();
+        let file_path = directory_path.join("scene.blend");
+        let frame_file_path = directory_path.join("frames").join("render_0001.png");
+        std::fs::write(&file_path, b"payload").unwrap();
+        std::fs::write(&frame_file_path, b"payload").unwrap();
+        let mut dependency = create_dependency("//frames/render_<UDIM>.png", &frame_file_path);
+        dependency.dependency_type = String::from("ImageTiles");
+        dependency.dependencies = vec![create_dependency(
+            "//frames/render_0001.png",
+            &frame_file_path,
+        )];
+
+        let entries = get_project_file_archive_entries(&file_path, &[dependency]);
+
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[1].archive_path, None);
+        assert_eq!(
+            entries[2].archive_path.as_deref(),
+            Some("scene/frames/render_0001.png")
+        );
+        std::fs::remove_dir_all(&directory_path).unwrap();
+    }
+}
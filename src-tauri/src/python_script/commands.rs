@@ -1,13 +1,23 @@
+use super::{
+    PYTHON_COMPOUND_KEYWORDS, PYTHON_OPERATORS, PYTHON_SCRIPT_RISKY_NAMES, PYTHON_TAB_SIZE,
+};
 use crate::{
-    db_repo::PythonScriptRepository,
+    db_repo::{
+        PythonScriptLibraryFolderRepository, PythonScriptRepository, PythonScriptSnapshotRepository,
+    },
     file_system_utility::{self, show_ok_notification},
-    models::PythonScript,
+    models::{
+        PythonScript, PythonScriptLibraryFolder, PythonScriptMetadata, PythonScriptSafetyWarning,
+        PythonScriptSnapshot, PythonToken, PythonTokenKind,
+    },
     AppState,
 };
-use tauri::AppHandle;
+use regex::Regex;
+use sha2::{Digest, Sha256};
+use tauri::{AppHandle, Manager};
 
 /// ID: PS_001
-/// ABC analīzes rezultāts:8,35,16
+/// ABC analīzes rezultāts:9,40,16
 #[tauri::command]
 pub async fn insert_python_script(
     app: AppHandle,
@@ -43,6 +53,7 @@ pub async fn insert_python_script(
         let mut existing_entry = results.remove(0); // A (1.a.) let mut existing_entry =; B (2.a.) results.remove();
         existing_entry.accessed = chrono::Utc::now().to_rfc3339(); // A (1.a.) existing_entry.accessed =; B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
         existing_entry.modified = chrono::Utc::now().to_rfc3339(); // A (1.a.) existing_entry.modified =; B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
+        existing_entry.is_missing = false; // A (1.a.) existing_entry.is_missing =
         match repository.update(&existing_entry).await {
             // C (3.b.) match; B (2.a.) repository.update()
             Ok(_) => return Ok(Some(existing_entry)), // C (3.c) Ok(); B (2.b.) priekšlaicīgs return
@@ -62,14 +73,28 @@ pub async fn insert_python_script(
         }
     }
     // If not, create and insert new entry
-    let entry = PythonScript {
-        // A (1.a.)  let entry =;
+    let mut entry = PythonScript {
+        // A (1.a.)  let mut entry =;
         id: uuid::Uuid::new_v4().to_string(), // B (2.a.) uuid::Uuid::new_v4(); B (2.a.) .to_string();
         script_file_path: file_path.to_string_lossy().to_string(), // B (2.a.) .to_string_loosy(); B (2.a.) .to_string();
+        metadata_json: "{}".to_string(),                           // B (2.a.) .to_string()
+        tags_json: "[]".to_string(),                               // B (2.a.) .to_string()
+        safety_warnings_json: "[]".to_string(),                    // B (2.a.) .to_string()
         created: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
         modified: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
         accessed: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
+        ..Default::default()                       // B (2.a.) ::default()
     };
+    if let Err(err) = refresh_python_script_metadata(&mut entry) {
+        // A (1.d.) if let Err(); B (2.a.) refresh_python_script_metadata()
+        show_ok_notification(
+            // B (2.a.) show_ok_notification()
+            app.clone(), // B (2.a.) app.clone();
+            format!("Failed to read python script: {}", err),
+            tauri_plugin_dialog::MessageDialogKind::Error,
+        );
+        return Err(format!("Failed to read python script: {}", err)); // B (2.b.) priekšlaicīgs return
+    }
     match repository.insert(&entry).await {
         // C (3.b.) match; B (2.a.) repository.insert();
         Ok(_) => Ok(Some(entry)), // C (3.c) Ok();
@@ -152,3 +177,1628 @@ pub async fn delete_python_script(
         }
     }
 }
+
+/// ID: PS_004
+/// ABC analīzes rezultāts:8,30,12
+pub fn parse_python_script_metadata(content: &str) -> PythonScriptMetadata {
+    let mut metadata = PythonScriptMetadata::default(); // A (1.a.) let mut metadata =; B (2.a.) ::default()
+
+    // The module docstring may only be preceded by blank lines, comments and the shebang.
+    let body = content
+        .lines()
+        .skip_while(|val| val.trim().is_empty() || val.trim_start().starts_with('#'))
+        .collect::<Vec<&str>>()
+        .join("\n"); // A (1.a.) let body =; B (2.a.) .lines(); B (2.a.) .skip_while(); B (2.a.) .trim(); B (2.a.) .is_empty(); B (2.a.) .trim_start(); B (2.a.) .starts_with(); B (2.a.) .collect(); B (2.a.) .join()
+    for quote in ["\"\"\"", "'''"] {
+        // A (1.a.) let quote =;
+        if let Some(docstring) = body
+            .strip_prefix(quote)
+            .and_then(|val| val.split_once(quote))
+        {
+            // A (1.d.) if let Some(); B (2.a.) .strip_prefix(); B (2.a.) .and_then(); B (2.a.) .split_once()
+            metadata.docstring = Some(docstring.0.trim().to_string()); // A (1.a.) metadata.docstring =; B (2.a.) .trim(); B (2.a.) .to_string()
+        }
+    }
+    let bl_info_regex = Regex::new(r"(?s)bl_info\s*=\s*\{(.*?)\}").unwrap(); // A (1.a.) let bl_info_regex =; B (2.a.) ::new(); B (2.a.) .unwrap()
+    let entry_regex = Regex::new(
+        r#"["'](\w+)["']\s*:\s*(?:"((?:[^"\\]|\\.)*)"|'((?:[^'\\]|\\.)*)'|\(([^)]*)\))"#,
+    )
+    .unwrap(); // A (1.a.) let entry_regex =; B (2.a.) ::new(); B (2.a.) .unwrap()
+    let Some(bl_info) = bl_info_regex.captures(content) else {
+        // A (1.d.) let Some() else; B (2.a.) .captures()
+        return metadata; // B (2.b.) priekšlaicīgs return
+    };
+    for captures in entry_regex.captures_iter(&bl_info[1]) {
+        // A (1.a.) let captures =; B (2.a.) .captures_iter()
+        // Version tuples such as (1, 2, 0) are stored as 1.2.0.
+        let value = match (captures.get(2).or(captures.get(3)), captures.get(4)) {
+            // A (1.a.) let value =; C (3.b) match; B (2.a.) .get(); B (2.a.) .or(); B (2.a.) .get(); B (2.a.) .get()
+            (Some(val), _) => val.as_str().to_string(), // C (3.c) Some(); B (2.a.) .as_str(); B (2.a.) .to_string()
+            (None, Some(val)) => val
+                .as_str()
+                .split(',')
+                .map(|part| part.trim())
+                .filter(|part| !part.is_empty())
+                .collect::<Vec<&str>>()
+                .join("."), // C (3.c) Some(); B (2.a.) .as_str(); B (2.a.) .split(); B (2.a.) .map(); B (2.a.) .trim(); B (2.a.) .filter(); B (2.a.) .is_empty(); B (2.a.) .collect(); B (2.a.) .join()
+            (None, None) => continue, // C (3.c) None =>; B (2.b.) continue
+        };
+        match &captures[1] {
+            // C (3.b) match
+            "name" => metadata.name = Some(value), // C (3.c) "name" =>; A (1.a.) metadata.name =
+            "description" => metadata.description = Some(value), // C (3.c) "description" =>; A (1.a.) metadata.description =
+            "author" => metadata.author = Some(value), // C (3.c) "author" =>; A (1.a.) metadata.author =
+            "version" => metadata.version = Some(value), // C (3.c) "version" =>; A (1.a.) metadata.version =
+            "blender" => metadata.blender = Some(value), // C (3.c) "blender" =>; A (1.a.) metadata.blender =
+            "category" => metadata.category = Some(value), // C (3.c) "category" =>; A (1.a.) metadata.category =
+            _ => {}                                        // C (3.c) _ =>
+        }
+    }
+    metadata
+}
+
+/// ID: PS_005
+/// ABC analīzes rezultāts:7,22,8
+pub fn refresh_python_script_metadata(entry: &mut PythonScript) -> Result<(), String> {
+    let script_file_path = std::path::Path::new(&entry.script_file_path); // A (1.a.) let script_file_path =; B (2.a.) ::new()
+    let content = match std::fs::read_to_string(script_file_path) {
+        // A (1.a.) let content =; C (3.b) match; B (2.a.) ::read_to_string()
+        Ok(val) => val,                               // C (3.c.) Ok()
+        Err(err) => return Err(format!("{:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    };
+    let metadata = parse_python_script_metadata(&content); // A (1.a.) let metadata =; B (2.a.) parse_python_script_metadata()
+    let docstring_paragraph = metadata
+        .docstring
+        .as_deref()
+        .and_then(|val| val.split("\n\n").next())
+        .map(|val| val.trim().to_string()); // A (1.a.) let docstring_paragraph =; B (2.a.) .as_deref(); B (2.a.) .and_then(); B (2.a.) .split(); B (2.a.) .next(); B (2.a.) .map(); B (2.a.) .trim(); B (2.a.) .to_string()
+
+    // Names and descriptions edited by the user are kept, parsed values only fill empty ones.
+    if entry.name.is_empty() {
+        // C (3.a.) entry.name.is_empty() == true; B (2.a.) .is_empty()
+        entry.name = metadata
+            .name
+            .clone()
+            .or(script_file_path
+                .file_stem()
+                .map(|val| val.to_string_lossy().to_string()))
+            .unwrap_or_default(); // A (1.a.) entry.name =; B (2.a.) .clone(); B (2.a.) .or(); B (2.a.) .file_stem(); B (2.a.) .map(); B (2.a.) .to_string_lossy(); B (2.a.) .to_string(); B (2.a.) .unwrap_or_default()
+    }
+    if entry.description.is_empty() {
+        // C (3.a.) entry.description.is_empty() == true; B (2.a.) .is_empty()
+        entry.description = metadata
+            .description
+            .clone()
+            .or(docstring_paragraph)
+            .unwrap_or_default(); // A (1.a.) entry.description =; B (2.a.) .clone(); B (2.a.) .or(); B (2.a.) .unwrap_or_default()
+    }
+    entry.metadata_json = serde_json::to_string(&metadata).unwrap_or_default(); // A (1.a.) entry.metadata_json =; B (2.a.) ::to_string(); B (2.a.) .unwrap_or_default()
+    entry.safety_warnings_json =
+        serde_json::to_string(&check_python_script_safety(&content)).unwrap_or_default(); // A (1.a.) entry.safety_warnings_json =; B (2.a.) ::to_string(); B (2.a.) check_python_script_safety(); B (2.a.) .unwrap_or_default()
+    entry.content_hash = Some(file_system_utility::get_file_sha256_checksum(
+        script_file_path,
+    )?); // A (1.a.) entry.content_hash =; B (2.a.) ::get_file_sha256_checksum()
+    Ok(())
+}
+
+/// ID: PS_006
+/// ABC analīzes rezultāts:1,2,0
+pub fn create_python_script_snapshot(entry: &PythonScript) -> Result<PythonScriptSnapshot, String> {
+    let snapshot_directory_path = file_system_utility::get_python_script_snapshot_directory_path()?; // A (1.a.) let snapshot_directory_path =; B (2.a.) ::get_python_script_snapshot_directory_path()
+    write_python_script_snapshot(entry, &snapshot_directory_path) // B (2.a.) write_python_script_snapshot()
+}
+
+/// ID: PS_022
+/// ABC analīzes rezultāts:4,13,5
+pub fn write_python_script_snapshot(
+    entry: &PythonScript,
+    snapshot_directory_path: &std::path::Path,
+) -> Result<PythonScriptSnapshot, String> {
+    // The script is read once, so the hash always names the bytes stored in the snapshot.
+    let content = match std::fs::read(&entry.script_file_path) {
+        // A (1.a.) let content =; C (3.b) match; B (2.a.) ::read()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c) Err()
+            return Err(format!(
+                "Failed to create python script snapshot: {:?}",
+                err
+            )); // B (2.b.) priekšlaicīgs return
+        }
+    };
+    let content_hash = Sha256::digest(&content)
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<String>(); // A (1.a.) let content_hash =; B (2.a.) ::digest(); B (2.a.) .iter(); B (2.a.) .map(); B (2.a.) .collect()
+
+    // Snapshots are stored by content hash, launches of an unchanged script share one copy.
+    let snapshot_file_path = snapshot_directory_path.join(format!("{}.py", content_hash)); // A (1.a.) let snapshot_file_path =; B (2.a.) .join()
+    if !snapshot_file_path.exists() {
+        // C (3.a.) snapshot_file_path.exists() != true; B (2.a.) .exists()
+        if let Err(err) = std::fs::write(&snapshot_file_path, &content) {
+            // A (1.d.) if let Err(); B (2.a.) ::write()
+            return Err(format!(
+                "Failed to create python script snapshot: {:?}",
+                err
+            )); // B (2.b.) priekšlaicīgs return
+        }
+    }
+    Ok(PythonScriptSnapshot {
+        id: uuid::Uuid::new_v4().to_string(), // B (2.a.) uuid::Uuid::new_v4(); B (2.a.) .to_string();
+        python_script_id: entry.id.clone(),   // B (2.a.) .clone()
+        launch_session_id: None,
+        script_file_path: entry.script_file_path.clone(), // B (2.a.) .clone()
+        content_hash: content_hash,
+        snapshot_file_path: snapshot_file_path.to_string_lossy().to_string(), // B (2.a.) .to_string_lossy(); B (2.a.) .to_string()
+        created: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
+    })
+}
+
+/// ID: PS_007
+/// ABC analīzes rezultāts:4,5,3
+pub async fn insert_python_script_snapshots(
+    app: AppHandle,
+    snapshots: Vec<PythonScriptSnapshot>,
+    launch_session_id: &str,
+) -> Result<(), String> {
+    let state = app.state::<AppState>(); // A (1.a.) let state =; B (2.a.) .state()
+    let repository = PythonScriptSnapshotRepository::new(&state.pool); // A (1.a.) let repository =; B (2.a.) ...::new()
+    for mut snapshot in snapshots {
+        // A (1.a.) let mut snapshot =;
+        snapshot.launch_session_id = Some(launch_session_id.to_string()); // A (1.a.) snapshot.launch_session_id =; B (2.a.) .to_string()
+        if let Err(err) = repository.insert(&snapshot).await {
+            // A (1.d.) if let Err(); B (2.a.) .insert()
+            return Err(format!(
+                "Failed to insert python script snapshot: {:?}",
+                err
+            )); // B (2.b.) priekšlaicīgs return
+        }
+    }
+    Ok(())
+}
+
+/// ID: PS_008
+/// ABC analīzes rezultāts:4,12,5
+#[tauri::command]
+pub async fn update_python_script(
+    app: AppHandle,
+    state: tauri::State<'_, AppState>,
+    id: String,
+    name: String,
+    description: String,
+) -> Result<(), String> {
+    let repository = PythonScriptRepository::new(&state.pool); // A (1.a.) let repository =; B (2.a.) ...::new()
+    let mut results = match repository.fetch(Some(&id), None, None).await {
+        // A (1.a.) let mut results =; C (3.b.) match; B (2.a.) repository.fetch();
+        Ok(val) => val, // C (3.c) Ok()
+        Err(err) => {
+            // C (3.c) Err();
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to fetch python scripts: {:?}", err),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            return Err(format!("Failed to fetch python scripts: {:?}", err)); // B (2.b.) priekšlaicīgs return
+        }
+    };
+    if results.is_empty() {
+        // B (2.a.) results.is_empty(); C (3.a) results.is_empty() == true
+        return Err(format!("Failed to fetch python script by ID")); // B (2.b.) priekšlaicīgs return
+    }
+    let mut entry = results.remove(0); // A (1.a.) let mut entry =; B (2.a.) results.remove();
+    entry.name = name; // A (1.a.) entry.name =
+    entry.description = description; // A (1.a.) entry.description =
+    match repository.update(&entry).await {
+        // C (3.b.) match; B (2.a.) repository.update()
+        Ok(_) => Ok(()), // C (3.c) Ok();
+        Err(err) => {
+            // C (3.c) Err();
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to update python script: {:?}", err),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            Err(format!("Failed to update python script: {:?}", err))
+        }
+    }
+}
+
+/// ID: PS_009
+/// ABC analīzes rezultāts:1,5,2
+#[tauri::command]
+pub async fn fetch_python_script_snapshots(
+    app: AppHandle,
+    state: tauri::State<'_, AppState>,
+    launch_session_id: Option<String>,
+    python_script_id: Option<String>,
+) -> Result<Vec<PythonScriptSnapshot>, String> {
+    let repository = PythonScriptSnapshotRepository::new(&state.pool); // A (1.a.) let repository =; B (2.a.) ...::new()
+    match repository
+        .fetch(
+            None,
+            launch_session_id.as_deref(),
+            python_script_id.as_deref(),
+        )
+        .await
+    {
+        // C (3.b) match; B (2.a.) .fetch(); B (2.a.) .as_deref(); B (2.a.) .as_deref()
+        Ok(val) => Ok(val), // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c) Err()
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to fetch python script snapshots: {:?}", err),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            Err(format!(
+                "Failed to fetch python script snapshots: {:?}",
+                err
+            ))
+        }
+    }
+}
+
+/// ID: PS_010
+/// ABC analīzes rezultāts:2,6,4
+#[tauri::command]
+pub async fn read_python_script_snapshot(
+    state: tauri::State<'_, AppState>,
+    id: String,
+) -> Result<String, String> {
+    let repository = PythonScriptSnapshotRepository::new(&state.pool); // A (1.a.) let repository =; B (2.a.) ...::new()
+    let mut results = match repository.fetch(Some(&id), None, None).await {
+        // A (1.a.) let mut results =; C (3.b) match; B (2.a.) .fetch()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => {
+            return Err(format!(
+                "Failed to fetch python script snapshots: {:?}",
+                err
+            ))
+        } // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    };
+    if results.is_empty() {
+        // B (2.a.) .is_empty(); C (3.a) results.is_empty() == true
+        return Err(format!("Failed to fetch python script snapshot by ID")); // B (2.b.) priekšlaicīgs return
+    }
+    match std::fs::read_to_string(results.remove(0).snapshot_file_path) {
+        // C (3.b) match; B (2.a.) ::read_to_string(); B (2.a.) .remove()
+        Ok(val) => Ok(val), // C (3.c.) Ok()
+        Err(err) => Err(format!("Failed to read python script snapshot: {:?}", err)), // C (3.c) Err()
+    }
+}
+
+/// ID: PS_011
+/// ABC analīzes rezultāts:6,28,13
+#[tauri::command]
+pub async fn insert_python_script_library_folder(
+    app: AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<Option<PythonScriptLibraryFolder>, String> {
+    let folder_path_option = // A (1.a.) let folder_path_option =
+        match file_system_utility::get_directory_from_file_explorer(app.clone()) // C (3.b) match; B (2.a.) get_directory_from_file_explorer(); B (2.a.) app.clone();
+            .await
+        {
+            Ok(val) => val, // C (3.c.) Ok()
+            Err(_) => return Ok(None),  // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+        };
+    let folder_path = match folder_path_option {
+        // A (1.a.) let folder_path =; C (3.b) match
+        Some(val) => val,        // C (3.c) Some()
+        None => return Ok(None), // C (3.c) None =>; B (2.b.) priekšlaicīgs return;
+    };
+    let repository = PythonScriptLibraryFolderRepository::new(&state.pool); // A (1.a.) let repository =; B (2.a.) ...::new()
+    let results = match repository.fetch(None, folder_path.to_str()).await {
+        // A (1.a.) let results =; C (3.b) match; B (2.a.) .fetch(); B (2.a.) .to_str()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c) Err()
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to fetch script library folders: {:?}", err),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            return Err(format!("Failed to fetch script library folders: {:?}", err));
+            // B (2.b.) priekšlaicīgs return
+        }
+    };
+    if !results.is_empty() {
+        // B (2.a.) .is_empty(); C (3.a) results.is_empty() != true
+        return Ok(None); // B (2.b.) priekšlaicīgs return
+    }
+    let entry = PythonScriptLibraryFolder {
+        // A (1.a.) let entry =;
+        id: uuid::Uuid::new_v4().to_string(), // B (2.a.) uuid::Uuid::new_v4(); B (2.a.) .to_string();
+        folder_path: folder_path.to_string_lossy().to_string(), // B (2.a.) .to_string_loosy(); B (2.a.) .to_string();
+        created: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
+        modified: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
+        accessed: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
+    };
+    if let Err(err) = repository.insert(&entry).await {
+        // A (1.d.) if let Err(); B (2.a.) repository.insert();
+        show_ok_notification(
+            // B (2.a.) show_ok_notification()
+            app.clone(), // B (2.a.) app.clone();
+            format!("Failed to insert script library folder: {:?}", err),
+            tauri_plugin_dialog::MessageDialogKind::Error,
+        );
+        return Err(format!("Failed to insert script library folder: {:?}", err));
+        // B (2.b.) priekšlaicīgs return
+    }
+    sync_python_script_library_folders(app.clone(), state).await?; // B (2.a.) sync_python_script_library_folders(); B (2.a.) app.clone()
+    Ok(Some(entry))
+}
+
+/// ID: PS_012
+/// ABC analīzes rezultāts:3,7,3
+#[tauri::command]
+pub async fn fetch_python_script_library_folders(
+    app: AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<PythonScriptLibraryFolder>, String> {
+    let repository = PythonScriptLibraryFolderRepository::new(&state.pool); // A (1.a.) let repository =; B (2.a.) ...::new()
+    let mut results = match repository.fetch(None, None).await {
+        // A (1.a.) let mut results =; C (3.b.) match; B (2.a.) repository.fetch()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c) Err()
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to fetch script library folders: {:?}", err),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            return Err(format!("Failed to fetch script library folders: {:?}", err));
+            // B(2.b) priekšlaicīgs return
+        }
+    };
+    results.sort_by(|a, b| a.folder_path.cmp(&b.folder_path)); // A (1.c.) sort_by(); B (2.a) |a, b| a.folder_path.cmp(&b.folder_path)
+    Ok(results)
+}
+
+/// ID: PS_013
+/// ABC analīzes rezultāts:4,16,8
+#[tauri::command]
+pub async fn delete_python_script_library_folder(
+    app: AppHandle,
+    state: tauri::State<'_, AppState>,
+    id: String,
+) -> Result<(), String> {
+    let confirmation = file_system_utility::show_ask_notification(
+        // A (1.a.) let confirmation =; B (2.a.) show_ask_notification()
+        app.clone(), // B (2.a.) app.clone();
+        format!("Are you sure you want to delete this script library folder? Scripts registered from it will be removed from the list."),
+        tauri_plugin_dialog::MessageDialogKind::Warning,
+    );
+    if confirmation == false {
+        // C (3.a.) confirmation == false
+        return Ok(()); // B (2.b.) priekšlaicīgs return
+    }
+    let script_repository = PythonScriptRepository::new(&state.pool); // A (1.a.) let script_repository =; B (2.a.) ...::new()
+    let scripts = match script_repository.fetch_by_library_folder(&id).await {
+        // A (1.a.) let scripts =; C (3.b) match; B (2.a.) .fetch_by_library_folder()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c) Err()
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to fetch python scripts: {:?}", err),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            return Err(format!("Failed to fetch python scripts: {:?}", err)); // B(2.b) priekšlaicīgs return
+        }
+    };
+    for script in scripts {
+        // A (1.a.) let script =;
+        if let Err(err) = script_repository.delete(&script.id).await {
+            // A (1.d.) if let Err(); B (2.a.) .delete()
+            return Err(format!("Failed to delete python script: {:?}", err)); // B(2.b) priekšlaicīgs return
+        }
+    }
+    let repository = PythonScriptLibraryFolderRepository::new(&state.pool); // A (1.a.) let repository =; B (2.a.) ...::new()
+    match repository.delete(&id).await {
+        // B (2.a.) repository.delete(); // C (3.b) match
+        Ok(_) => Ok(()), // C (3.c) Ok()
+        Err(err) => {
+            // C (3.c) Err()
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to delete script library folder: {:?}", err),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            return Err(format!("Failed to delete script library folder: {:?}", err));
+            // B(2.b) priekšlaicīgs return
+        }
+    }
+}
+
+/// ID: PS_014
+/// ABC analīzes rezultāts:0,4,2
+#[tauri::command]
+pub async fn sync_python_script_library_folders(
+    app: AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<(), String> {
+    match sync_python_script_library_folder_scripts(&state.pool).await {
+        // C (3.b) match; B (2.a.) sync_python_script_library_folder_scripts()
+        Ok(_) => Ok(()), // C (3.c.) Ok()
+        Err(err) => {
+            // C (3.c) Err()
+            show_ok_notification(
+                // B (2.a.) show_ok_notification()
+                app.clone(), // B (2.a.) app.clone();
+                format!("Failed to sync script library folders: {}", err),
+                tauri_plugin_dialog::MessageDialogKind::Error,
+            );
+            Err(err)
+        }
+    }
+}
+
+/// ID: PS_023
+/// ABC analīzes rezultāts:24,50,27
+pub async fn sync_python_script_library_folder_scripts(
+    pool: &sqlx::SqlitePool,
+) -> Result<(), String> {
+    let folder_repository = PythonScriptLibraryFolderRepository::new(pool); // A (1.a.) let folder_repository =; B (2.a.) ...::new()
+    let folders = match folder_repository.fetch(None, None).await {
+        // A (1.a.) let folders =; C (3.b) match; B (2.a.) .fetch()
+        Ok(val) => val, // C (3.c.) Ok()
+        Err(err) => return Err(format!("Failed to fetch script library folders: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+    };
+    let repository = PythonScriptRepository::new(pool); // A (1.a.) let repository =; B (2.a.) ...::new()
+    let mut scripts: std::collections::HashMap<String, PythonScript> =
+        match repository.fetch(None, None, None).await {
+            // A (1.a.) let mut scripts =; C (3.b) match; B (2.a.) .fetch()
+            Ok(val) => val
+                .into_iter()
+                .map(|val| (val.script_file_path.clone(), val))
+                .collect(), // C (3.c.) Ok(); B (2.a.) .into_iter(); B (2.a.) .map(); B (2.a.) .clone(); B (2.a.) .collect()
+            Err(err) => return Err(format!("Failed to fetch python scripts: {:?}", err)), // C (3.c) Err(); B (2.b.) priekšlaicīgs return
+        };
+    for folder in folders {
+        // A (1.a.) let folder =;
+        let folder_path = std::path::PathBuf::from(&folder.folder_path); // A (1.a.) let folder_path =; B (2.a.) ::from()
+
+        // An unreachable folder yields no files, its scripts are then flagged missing below.
+        let script_file_paths =
+            file_system_utility::find_python_scripts_recursively(&folder_path).unwrap_or_default(); // A (1.a.) let script_file_paths =; B (2.a.) find_python_scripts_recursively(); B (2.a.) .unwrap_or_default()
+        for script_file_path in script_file_paths {
+            // A (1.a.) let script_file_path =;
+            let tags: Vec<String> = script_file_path
+                .parent()
+                .and_then(|val| val.strip_prefix(&folder_path).ok())
+                .map(|val| {
+                    val.components()
+                        .map(|val| val.as_os_str().to_string_lossy().to_string())
+                        .collect()
+                })
+                .unwrap_or_default(); // A (1.a.) let tags =; B (2.a.) .parent(); B (2.a.) .and_then(); B (2.a.) .strip_prefix(); B (2.a.) .ok(); B (2.a.) .map(); B (2.a.) .components(); B (2.a.) .as_os_str(); B (2.a.) .to_string_lossy(); B (2.a.) .to_string(); B (2.a.) .collect(); B (2.a.) .unwrap_or_default()
+            let tags_json = serde_json::to_string(&tags).unwrap_or_default(); // A (1.a.) let tags_json =; B (2.a.) ::to_string(); B (2.a.) .unwrap_or_default()
+            let script_file_path_string = script_file_path.to_string_lossy().to_string(); // A (1.a.) let script_file_path_string =; B (2.a.) .to_string_lossy(); B (2.a.) .to_string()
+            match scripts.remove(&script_file_path_string) {
+                // C (3.b) match; B (2.a.) .remove()
+                Some(mut entry) => {
+                    // C (3.c) Some()
+                    let content_hash = entry.content_hash.clone(); // A (1.a.) let content_hash =; B (2.a.) .clone()
+
+                    // Scripts added one by one before the folder was added are adopted by it.
+                    let library_folder_id =
+                        entry.library_folder_id.clone().unwrap_or(folder.id.clone()); // A (1.a.) let library_folder_id =; B (2.a.) .clone(); B (2.a.) .unwrap_or()
+                    let _ = refresh_python_script_metadata(&mut entry); // A (1.a.) let _ =; B (2.a.) refresh_python_script_metadata()
+                    let is_changed = entry.is_missing
+                        || entry.tags_json != tags_json
+                        || entry.content_hash != content_hash
+                        || entry.library_folder_id.as_deref() != Some(library_folder_id.as_str()); // A (1.a.) let is_changed =; C (3.a.) entry.is_missing == true; C (3.a.) entry.tags_json != tags_json; C (3.a.) entry.content_hash != content_hash; C (3.a.) .as_deref() != Some(); B (2.a.) .as_deref(); B (2.a.) .as_str()
+                    if !is_changed {
+                        // C (3.a.) is_changed != true
+                        continue; // B (2.b.) continue
+                    }
+                    entry.library_folder_id = Some(library_folder_id); // A (1.a.) entry.library_folder_id =
+                    entry.tags_json = tags_json; // A (1.a.) entry.tags_json =
+                    entry.is_missing = false; // A (1.a.) entry.is_missing =
+                    if let Err(err) = repository.update(&entry).await {
+                        // A (1.d.) if let Err(); B (2.a.) repository.update()
+                        return Err(format!("Failed to update python script: {:?}", err));
+                        // B (2.b.) priekšlaicīgs return
+                    }
+                }
+                None => {
+                    // C (3.c) None =>
+                    let mut entry = PythonScript {
+                        // A (1.a.)  let mut entry =;
+                        id: uuid::Uuid::new_v4().to_string(), // B (2.a.) uuid::Uuid::new_v4(); B (2.a.) .to_string();
+                        script_file_path: script_file_path_string,
+                        metadata_json: "{}".to_string(), // B (2.a.) .to_string()
+                        library_folder_id: Some(folder.id.clone()), // B (2.a.) .clone()
+                        tags_json,
+                        safety_warnings_json: "[]".to_string(), // B (2.a.) .to_string()
+                        created: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
+                        modified: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
+                        accessed: chrono::Utc::now().to_rfc3339(), // B (2.a.) chrono::Utc::now(); B (2.a.) .to_rfc3339();
+                        ..Default::default()                       // B (2.a.) ::default()
+                    };
+                    // A file that can't be read right now is still registered, the next sync fills in its metadata.
+                    let _ = refresh_python_script_metadata(&mut entry); // A (1.a.) let _ =; B (2.a.) refresh_python_script_metadata()
+                    if let Err(err) = repository.insert(&entry).await {
+                        // A (1.d.) if let Err(); B (2.a.) repository.insert()
+                        return Err(format!("Failed to insert python script: {:?}", err));
+                        // B (2.b.) priekšlaicīgs return
+                    }
+                }
+            }
+        }
+    }
+    // Whatever was not seen in a library folder is only kept when its file still exists.
+    for (_, mut entry) in scripts {
+        // A (1.a.) let (_, mut entry) =;
+        let is_missing = !std::path::Path::new(&entry.script_file_path).is_file(); // A (1.a.) let is_missing =; C (3.a.) .is_file() != true; B (2.a.) ::new(); B (2.a.) .is_file()
+        if entry.is_missing == is_missing {
+            // C (3.a.) entry.is_missing == is_missing
+            continue; // B (2.b.) continue
+        }
+        entry.is_missing = is_missing; // A (1.a.) entry.is_missing =
+        if let Err(err) = repository.update(&entry).await {
+            // A (1.d.) if let Err(); B (2.a.) repository.update()
+            return Err(format!("Failed to update python script: {:?}", err)); // B (2.b.) priekšlaicīgs return
+        }
+    }
+    Ok(())
+}
+
+/// ID: PS_015
+/// ABC analīzes rezultāts:38,62,58
+pub fn tokenize_python_source(
+    content: &str,
+) -> (Vec<PythonToken>, Option<PythonScriptSafetyWarning>) {
+    let characters: Vec<char> = content.chars().collect(); // A (1.a.) let characters =; B (2.a.) .chars(); B (2.a.) .collect()
+    let mut tokens: Vec<PythonToken> = Vec::new(); // A (1.a.) let mut tokens =; B (2.a.) ...::new()
+    let mut indentation_stack: Vec<usize> = vec![0]; // A (1.a.) let mut indentation_stack =
+    let mut bracket_stack: Vec<(char, usize, usize)> = Vec::new(); // A (1.a.) let mut bracket_stack =; B (2.a.) ...::new()
+    let mut is_line_start = true; // A (1.a.) let mut is_line_start =
+    let mut is_indent_expected = false; // A (1.a.) let mut is_indent_expected =
+    let mut index = 0; // A (1.a.) let mut index =
+    let mut line = 1; // A (1.a.) let mut line =
+    let mut line_start_index = 0; // A (1.a.) let mut line_start_index =
+    let syntax_error = |message: &str, line: usize, column: usize| PythonScriptSafetyWarning {
+        // A (1.a.) let syntax_error =
+        category: "syntax".to_string(), // B (2.a.) .to_string()
+        message: message.to_string(),   // B (2.a.) .to_string()
+        line,
+        column,
+    };
+    while index < characters.len() {
+        // C (3.a.) index < characters.len(); B (2.a.) .len()
+        let character = characters[index]; // A (1.a.) let character =
+        if is_line_start && bracket_stack.is_empty() {
+            // C (3.a.) is_line_start == true; C (3.a.) bracket_stack.is_empty() == true; B (2.a.) .is_empty()
+            is_line_start = false; // A (1.a.) is_line_start =
+            let mut width = 0; // A (1.a.) let mut width =
+            while index < characters.len() && matches!(characters[index], ' ' | '\t' | '\x0c') {
+                // C (3.a.) index < characters.len(); B (2.a.) .len(); C (3.a.) matches!()
+                width = match characters[index] {
+                    // A (1.a.) width =; C (3.b) match
+                    ' ' => width + 1, // C (3.c) ' ' =>
+                    '\t' => (width / PYTHON_TAB_SIZE + 1) * PYTHON_TAB_SIZE, // C (3.c) '\t' =>
+                    _ => 0,           // C (3.c) _ =>
+                };
+                index += 1; // A (1.b.) index +=
+            }
+            if index >= characters.len() || matches!(characters[index], '#' | '\n' | '\r') {
+                // C (3.a.) index >= characters.len(); B (2.a.) .len(); C (3.a.) matches!()
+                continue; // B (2.b.) continue
+            }
+            // Blank and comment-only lines were skipped above, they never change the indentation.
+            let column = index - line_start_index + 1; // A (1.a.) let column =
+            let current_width = indentation_stack.last().copied().unwrap_or(0); // A (1.a.) let current_width =; B (2.a.) .last(); B (2.a.) .copied(); B (2.a.) .unwrap_or()
+            if width > current_width {
+                // C (3.a.) width > current_width
+                if !is_indent_expected {
+                    // C (3.a.) is_indent_expected != true
+                    return (
+                        tokens,
+                        Some(syntax_error("Unexpected indent", line, column)),
+                    ); // B (2.b.) priekšlaicīgs return; B (2.a.) syntax_error()
+                }
+                indentation_stack.push(width); // A (1.c.) .push()
+                tokens.push(PythonToken {
+                    // A (1.c.) .push()
+                    kind: PythonTokenKind::Indent,
+                    text: String::new(), // B (2.a.) ...::new()
+                    line,
+                    column,
+                });
+            } else {
+                // C (3.b.) else
+                if is_indent_expected {
+                    // C (3.a.) is_indent_expected == true
+                    return (
+                        tokens,
+                        Some(syntax_error("Expected an indented block", line, column)),
+                    ); // B (2.b.) priekšlaicīgs return; B (2.a.) syntax_error()
+                }
+                while width < indentation_stack.last().copied().unwrap_or(0) {
+                    // C (3.a.) width < ...; B (2.a.) .last(); B (2.a.) .copied(); B (2.a.) .unwrap_or()
+                    indentation_stack.pop(); // A (1.c.) .pop()
+                    tokens.push(PythonToken {
+                        // A (1.c.) .push()
+                        kind: PythonTokenKind::Dedent,
+                        text: String::new(), // B (2.a.) ...::new()
+                        line,
+                        column,
+                    });
+                }
+                if width != indentation_stack.last().copied().unwrap_or(0) {
+                    // C (3.a.) width != ...; B (2.a.) .last(); B (2.a.) .copied(); B (2.a.) .unwrap_or()
+                    return (
+                        tokens,
+                        Some(syntax_error(
+                            "Unindent does not match any outer indentation level",
+                            line,
+                            column,
+                        )),
+                    ); // B (2.b.) priekšlaicīgs return; B (2.a.) syntax_error()
+                }
+            }
+            is_indent_expected = false; // A (1.a.) is_indent_expected =
+            continue; // B (2.b.) continue
+        }
+        let column = index - line_start_index + 1; // A (1.a.) let column =
+        let token_kind = match character {
+            // A (1.a.) let token_kind =; C (3.b) match
+            '#' => {
+                // C (3.c) '#' =>
+                while index < characters.len() && characters[index] != '\n' {
+                    // C (3.a.) index < characters.len(); B (2.a.) .len(); C (3.a.) characters[index] != '\n'
+                    index += 1; // A (1.b.) index +=
+                }
+                continue; // B (2.b.) continue
+            }
+            ' ' | '\t' | '\r' | '\x0c' => {
+                // C (3.c) ' ' | '\t' | '\r' | '\x0c' =>
+                index += 1; // A (1.b.) index +=
+                continue; // B (2.b.) continue
+            }
+            '\\' => {
+                // C (3.c) '\\' =>
+                let next_index = match characters.get(index + 1) {
+                    // A (1.a.) let next_index =; C (3.b) match; B (2.a.) .get()
+                    Some('\r') => index + 2, // C (3.c) Some()
+                    _ => index + 1,          // C (3.c) _ =>
+                };
+                if characters.get(next_index) != Some(&'\n') {
+                    // C (3.a.) characters.get() != Some(); B (2.a.) .get()
+                    return (
+                        tokens,
+                        Some(syntax_error(
+                            "Unexpected character after line continuation character",
+                            line,
+                            column,
+                        )),
+                    ); // B (2.b.) priekšlaicīgs return; B (2.a.) syntax_error()
+                }
+                index = next_index + 1; // A (1.a.) index =
+                line += 1; // A (1.b.) line +=
+                line_start_index = index; // A (1.a.) line_start_index =
+                continue; // B (2.b.) continue
+            }
+            '\n' => {
+                // C (3.c) '\n' =>
+                let is_statement_end = bracket_stack.is_empty()
+                    && tokens
+                        .last()
+                        .map(|val| {
+                            !matches!(
+                                val.kind,
+                                PythonTokenKind::Newline
+                                    | PythonTokenKind::Indent
+                                    | PythonTokenKind::Dedent
+                            )
+                        })
+                        .unwrap_or(false); // A (1.a.) let is_statement_end =; B (2.a.) .is_empty(); B (2.a.) .last(); B (2.a.) .map(); C (3.a.) matches!(); B (2.a.) .unwrap_or()
+                if is_statement_end {
+                    // C (3.a.) is_statement_end == true
+                    is_indent_expected = tokens
+                        .last()
+                        .map(|val| val.kind == PythonTokenKind::Operator && val.text == ":")
+                        .unwrap_or(false); // A (1.a.) is_indent_expected =; B (2.a.) .last(); B (2.a.) .map(); C (3.a.) val.kind == Operator; C (3.a.) val.text == ":"; B (2.a.) .unwrap_or()
+                    tokens.push(PythonToken {
+                        // A (1.c.) .push()
+                        kind: PythonTokenKind::Newline,
+                        text: String::new(), // B (2.a.) ...::new()
+                        line,
+                        column,
+                    });
+                }
+                index += 1; // A (1.b.) index +=
+                line += 1; // A (1.b.) line +=
+                line_start_index = index; // A (1.a.) line_start_index =
+                is_line_start = bracket_stack.is_empty(); // A (1.a.) is_line_start =; B (2.a.) .is_empty()
+                continue; // B (2.b.) continue
+            }
+            '(' | '[' | '{' => {
+                // C (3.c) '(' | '[' | '{' =>
+                bracket_stack.push((character, line, column)); // A (1.c.) .push()
+                index += 1; // A (1.b.) index +=
+                PythonTokenKind::Operator
+            }
+            ')' | ']' | '}' => {
+                // C (3.c) ')' | ']' | '}' =>
+                let opening_character = match character {
+                    // A (1.a.) let opening_character =; C (3.b) match
+                    ')' => '(', // C (3.c) ')' =>
+                    ']' => '[', // C (3.c) ']' =>
+                    _ => '{',   // C (3.c) _ =>
+                };
+                match bracket_stack.pop() {
+                    // C (3.b) match; B (2.a.) .pop()
+                    Some((val, _, _)) if val == opening_character => {} // C (3.c) Some(); C (3.a.) val == opening_character
+                    Some((val, opening_line, _)) => {
+                        // C (3.c) Some()
+                        let message = format!(
+                            "Closing bracket '{}' does not match '{}' opened on line {}",
+                            character, val, opening_line
+                        ); // A (1.a.) let message =
+                        return (tokens, Some(syntax_error(&message, line, column)));
+                        // B (2.b.) priekšlaicīgs return; B (2.a.) syntax_error()
+                    }
+                    None => {
+                        // C (3.c) None =>
+                        let message = format!("Unmatched '{}'", character); // A (1.a.) let message =
+                        return (tokens, Some(syntax_error(&message, line, column)));
+                        // B (2.b.) priekšlaicīgs return; B (2.a.) syntax_error()
+                    }
+                }
+                index += 1; // A (1.b.) index +=
+                PythonTokenKind::Operator
+            }
+            '"' | '\'' => {
+                // C (3.c) '"' | '\'' =>
+                match find_python_string_end(&characters, index) {
+                    // C (3.b) match; B (2.a.) find_python_string_end()
+                    Some(val) => index = val, // C (3.c) Some(); A (1.a.) index =
+                    None => {
+                        // C (3.c) None =>
+                        return (
+                            tokens,
+                            Some(syntax_error("Unterminated string literal", line, column)),
+                        ); // B (2.b.) priekšlaicīgs return; B (2.a.) syntax_error()
+                    }
+                }
+                PythonTokenKind::String
+            }
+            val if val.is_alphabetic() || val == '_' => {
+                // C (3.c) val =>; C (3.a.) val.is_alphabetic() == true; B (2.a.) .is_alphabetic(); C (3.a.) val == '_'
+                while index < characters.len()
+                    && (characters[index].is_alphanumeric() || characters[index] == '_')
+                {
+                    // C (3.a.) index < characters.len(); B (2.a.) .len(); C (3.a.) .is_alphanumeric() == true; B (2.a.) .is_alphanumeric(); C (3.a.) characters[index] == '_'
+                    index += 1; // A (1.b.) index +=
+                }
+                let name_length = index - (line_start_index + column - 1); // A (1.a.) let name_length =
+                let is_string_prefix = name_length <= 2
+                    && characters[index - name_length..index]
+                        .iter()
+                        .all(|val| "rRbBuUfF".contains(*val))
+                    && matches!(characters.get(index), Some('"' | '\'')); // A (1.a.) let is_string_prefix =; C (3.a.) name_length <= 2; B (2.a.) .iter(); B (2.a.) .all(); B (2.a.) .contains(); C (3.a.) matches!(); B (2.a.) .get()
+                if !is_string_prefix {
+                    // C (3.a.) is_string_prefix != true
+                    PythonTokenKind::Name
+                } else {
+                    // C (3.b.) else
+                    match find_python_string_end(&characters, index) {
+                        // C (3.b) match; B (2.a.) find_python_string_end()
+                        Some(val) => index = val, // C (3.c) Some(); A (1.a.) index =
+                        None => {
+                            // C (3.c) None =>
+                            return (
+                                tokens,
+                                Some(syntax_error("Unterminated string literal", line, column)),
+                            ); // B (2.b.) priekšlaicīgs return; B (2.a.) syntax_error()
+                        }
+                    }
+                    PythonTokenKind::String
+                }
+            }
+            val if val.is_ascii_digit()
+                || (val == '.'
+                    && characters.get(index + 1).map(|val| val.is_ascii_digit()) == Some(true)) =>
+            {
+                // C (3.c) val =>; C (3.a.) .is_ascii_digit() == true; B (2.a.) .is_ascii_digit(); C (3.a.) val == '.'; B (2.a.) .get(); B (2.a.) .map()
+                let is_hex = matches!(characters.get(index + 1), Some('x' | 'X')); // A (1.a.) let is_hex =; C (3.a.) matches!(); B (2.a.) .get()
+                index += 1; // A (1.b.) index +=
+                while index < characters.len() {
+                    // C (3.a.) index < characters.len(); B (2.a.) .len()
+                    let val = characters[index]; // A (1.a.) let val =
+                    let is_exponent_sign = matches!(val, '+' | '-')
+                        && matches!(characters[index - 1], 'e' | 'E')
+                        && !is_hex; // A (1.a.) let is_exponent_sign =; C (3.a.) matches!(); C (3.a.) is_hex != true
+                    if !(val.is_alphanumeric() || val == '_' || val == '.' || is_exponent_sign) {
+                        // C (3.a.) .is_alphanumeric() == true; B (2.a.) .is_alphanumeric(); C (3.a.) val == '_'; C (3.a.) val == '.'; C (3.a.) is_exponent_sign == true
+                        break; // B (2.b.) break
+                    }
+                    index += 1; // A (1.b.) index +=
+                }
+                PythonTokenKind::Number
+            }
+            _ => {
+                // C (3.c) _ =>
+                let operator = PYTHON_OPERATORS.iter().find(|val| {
+                    val.chars()
+                        .enumerate()
+                        .all(|(offset, val)| characters.get(index + offset) == Some(&val))
+                }); // A (1.a.) let operator =; B (2.a.) .iter(); B (2.a.) .find(); B (2.a.) .chars(); B (2.a.) .enumerate(); B (2.a.) .all(); C (3.a.) characters.get() == Some(); B (2.a.) .get()
+                match operator {
+                    // C (3.b) match
+                    Some(val) => index += val.chars().count(), // C (3.c) Some(); A (1.b.) index +=; B (2.a.) .chars(); B (2.a.) .count()
+                    None => {
+                        // C (3.c) None =>
+                        let message = format!("Invalid character '{}'", character); // A (1.a.) let message =
+                        return (tokens, Some(syntax_error(&message, line, column)));
+                        // B (2.b.) priekšlaicīgs return; B (2.a.) syntax_error()
+                    }
+                }
+                PythonTokenKind::Operator
+            }
+        };
+        let start_index = line_start_index + column - 1; // A (1.a.) let start_index =
+        tokens.push(PythonToken {
+            // A (1.c.) .push()
+            kind: token_kind,
+            text: characters[start_index..index].iter().collect(), // B (2.a.) .iter(); B (2.a.) .collect()
+            line,
+            column,
+        });
+        for position in start_index..index {
+            // A (1.a.) let position =;
+            // Triple-quoted strings span lines, the positions of later tokens count from the last one.
+            if characters[position] == '\n' {
+                // C (3.a.) characters[position] == '\n'
+                line += 1; // A (1.b.) line +=
+                line_start_index = position + 1; // A (1.a.) line_start_index =
+            }
+        }
+    }
+    if let Some((val, opening_line, opening_column)) = bracket_stack.last() {
+        // A (1.d.) if let Some(); B (2.a.) .last()
+        let message = format!("'{}' was never closed", val); // A (1.a.) let message =
+        return (
+            tokens,
+            Some(syntax_error(&message, *opening_line, *opening_column)),
+        ); // B (2.b.) priekšlaicīgs return; B (2.a.) syntax_error()
+    }
+    let is_colon_last = tokens
+        .last()
+        .map(|val| val.kind == PythonTokenKind::Operator && val.text == ":")
+        .unwrap_or(false); // A (1.a.) let is_colon_last =; B (2.a.) .last(); B (2.a.) .map(); C (3.a.) val.kind == Operator; C (3.a.) val.text == ":"; B (2.a.) .unwrap_or()
+    if is_indent_expected || is_colon_last {
+        // C (3.a.) is_indent_expected == true; C (3.a.) is_colon_last == true
+        return (
+            tokens,
+            Some(syntax_error("Expected an indented block", line, 1)),
+        ); // B (2.b.) priekšlaicīgs return; B (2.a.) syntax_error()
+    }
+    (tokens, None)
+}
+
+/// ID: PS_016
+/// ABC analīzes rezultāts:5,8,9
+pub fn find_python_string_end(characters: &[char], quote_index: usize) -> Option<usize> {
+    let quote = characters[quote_index]; // A (1.a.) let quote =
+    let is_triple_quoted = characters.get(quote_index + 1) == Some(&quote)
+        && characters.get(quote_index + 2) == Some(&quote); // A (1.a.) let is_triple_quoted =; C (3.a.) characters.get() == Some(); B (2.a.) .get()
+    let mut index = if is_triple_quoted {
+        // A (1.a.) let mut index =; C (3.a.) is_triple_quoted == true
+        quote_index + 3
+    } else {
+        // C (3.b.) else
+        quote_index + 1
+    };
+    while index < characters.len() {
+        // C (3.a.) index < characters.len(); B (2.a.) .len()
+        match characters[index] {
+            // C (3.b) match
+            // An escaped character never ends the string, this also holds for raw strings.
+            '\\' => index += 1, // C (3.c) '\\' =>; A (1.b.) index +=
+            '\n' if !is_triple_quoted => return None, // C (3.c) '\n' =>; C (3.a.) is_triple_quoted != true; B (2.b.) priekšlaicīgs return
+            val if val == quote && !is_triple_quoted => return Some(index + 1), // C (3.c) val =>; C (3.a.) val == quote; B (2.b.) priekšlaicīgs return
+            val if val == quote
+                && characters.get(index + 1) == Some(&quote)
+                && characters.get(index + 2) == Some(&quote) =>
+            {
+                // C (3.c) val =>; C (3.a.) val == quote; C (3.a.) characters.get() == Some(); B (2.a.) .get()
+                return Some(index + 3); // B (2.b.) priekšlaicīgs return
+            }
+            _ => {} // C (3.c) _ =>
+        }
+        index += 1; // A (1.b.) index +=
+    }
+    None
+}
+
+/// ID: PS_017
+/// ABC analīzes rezultāts:3,6,4
+pub fn find_python_script_risky_name(
+    name: &str,
+) -> Option<&'static (&'static str, &'static str, &'static str)> {
+    PYTHON_SCRIPT_RISKY_NAMES.iter().find(|(val, _, _)| {
+        // B (2.a.) .iter(); B (2.a.) .find()
+        name == *val
+            || name
+                .strip_prefix(val)
+                .map(|rest| rest.starts_with('.'))
+                .unwrap_or(false) // C (3.a.) name == val; B (2.a.) .strip_prefix(); B (2.a.) .map(); B (2.a.) .starts_with(); B (2.a.) .unwrap_or()
+    })
+}
+
+/// ID: PS_018
+/// ABC analīzes rezultāts:33,72,45
+pub fn find_python_script_safety_warnings(
+    tokens: &[PythonToken],
+) -> Vec<PythonScriptSafetyWarning> {
+    let mut warnings: Vec<PythonScriptSafetyWarning> = Vec::new(); // A (1.a.) let mut warnings =; B (2.a.) ...::new()
+    let mut aliases: std::collections::HashMap<String, String> = std::collections::HashMap::new(); // A (1.a.) let mut aliases =; B (2.a.) ...::new()
+    let mut star_modules: Vec<String> = Vec::new(); // A (1.a.) let mut star_modules =; B (2.a.) ...::new()
+    let mut import_statements: Vec<(usize, usize)> = Vec::new(); // A (1.a.) let mut import_statements =; B (2.a.) ...::new()
+    let mut push_warning = |name: &str, token: &PythonToken, message: String| {
+        // A (1.a.) let mut push_warning =
+        let Some((_, category, _)) = find_python_script_risky_name(name) else {
+            // A (1.d.) let Some() else; B (2.a.) find_python_script_risky_name()
+            return; // B (2.b.) priekšlaicīgs return
+        };
+        if warnings
+            .iter()
+            .any(|val| val.category == *category && val.line == token.line)
+        {
+            // B (2.a.) .iter(); B (2.a.) .any(); C (3.a.) val.category == category; C (3.a.) val.line == token.line
+            return; // B (2.b.) priekšlaicīgs return
+        }
+        warnings.push(PythonScriptSafetyWarning {
+            // A (1.c.) .push()
+            category: category.to_string(), // B (2.a.) .to_string()
+            message,
+            line: token.line,
+            column: token.column,
+        });
+    };
+    let statement_starts = get_python_statement_starts(tokens); // A (1.a.) let statement_starts =; B (2.a.) get_python_statement_starts()
+
+    // Functions and classes defined in the script shadow names a `*` import brings in.
+    let mut local_names: Vec<&str> = Vec::new(); // A (1.a.) let mut local_names =; B (2.a.) ...::new()
+    for (index, token) in tokens.iter().enumerate() {
+        // A (1.a.) let (index, token) =; B (2.a.) .iter(); B (2.a.) .enumerate()
+        let is_definition = index > 0 && matches!(tokens[index - 1].text.as_str(), "def" | "class"); // A (1.a.) let is_definition =; C (3.a.) index > 0; C (3.a.) matches!(); B (2.a.) .as_str()
+        let is_assignment = statement_starts[index]
+            && tokens.get(index + 1).map(|val| val.text == "=") == Some(true); // A (1.a.) let is_assignment =; B (2.a.) .get(); B (2.a.) .map(); C (3.a.) val.text == "="
+        if token.kind == PythonTokenKind::Name && (is_definition || is_assignment) {
+            // C (3.a.) token.kind == Name; C (3.a.) is_definition == true; C (3.a.) is_assignment == true
+            local_names.push(&token.text); // A (1.c.) .push()
+        }
+    }
+
+    // Imports are read first, a function body may use a module that is imported further down.
+    for (index, token) in tokens.iter().enumerate() {
+        // A (1.a.) let (index, token) =; B (2.a.) .iter(); B (2.a.) .enumerate()
+        if !statement_starts[index]
+            || token.kind != PythonTokenKind::Name
+            || (token.text != "import" && token.text != "from")
+        {
+            // C (3.a.) statement_starts[index] != true; C (3.a.) token.kind != Name; C (3.a.) token.text != "import"; C (3.a.) token.text != "from"
+            continue; // B (2.b.) continue
+        }
+        let statement_end = tokens[index..]
+            .iter()
+            .position(|val| val.kind == PythonTokenKind::Newline || val.text == ";")
+            .map(|val| index + val)
+            .unwrap_or(tokens.len()); // A (1.a.) let statement_end =; B (2.a.) .iter(); B (2.a.) .position(); C (3.a.) val.kind == Newline; C (3.a.) val.text == ";"; B (2.a.) .map(); B (2.a.) .unwrap_or(); B (2.a.) .len()
+        import_statements.push((index, statement_end)); // A (1.c.) .push()
+
+        // `from a.b import (c as d, e)` is read as the text `from a.b import c as d,e`.
+        let mut statement = String::new(); // A (1.a.) let mut statement =; B (2.a.) ...::new()
+        for val in &tokens[index..statement_end] {
+            // A (1.a.) let val =;
+            let is_after_word = statement
+                .chars()
+                .last()
+                .map(|val| val.is_alphanumeric() || val == '_' || val == '*')
+                .unwrap_or(false); // A (1.a.) let is_after_word =; B (2.a.) .chars(); B (2.a.) .last(); B (2.a.) .map(); B (2.a.) .is_alphanumeric(); C (3.a.) val == '_'; C (3.a.) val == '*'; B (2.a.) .unwrap_or()
+            if (val.kind == PythonTokenKind::Name || val.text == "*") && is_after_word {
+                // C (3.a.) val.kind == Name; C (3.a.) val.text == "*"; C (3.a.) is_after_word == true
+                statement.push(' '); // A (1.c.) .push()
+            }
+            if val.text != "(" && val.text != ")" {
+                // C (3.a.) val.text != "("; C (3.a.) val.text != ")"
+                statement.push_str(&val.text); // A (1.c.) .push_str()
+            }
+        }
+        let (module, names) = match statement.strip_prefix("from ") {
+            // A (1.a.) let (module, names) =; C (3.b) match; B (2.a.) .strip_prefix()
+            Some(val) => match val.split_once(" import ") {
+                // C (3.c) Some(); C (3.b) match; B (2.a.) .split_once()
+                // Relative imports only reach modules of the script's own package.
+                Some((module, _)) if module.starts_with('.') => continue, // C (3.c) Some(); C (3.a.) module.starts_with() == true; B (2.a.) .starts_with(); B (2.b.) continue
+                Some((module, names)) => (Some(module), names),           // C (3.c) Some()
+                None => continue, // C (3.c) None =>; B (2.b.) continue
+            },
+            None => (None, statement.trim_start_matches("import ")), // C (3.c) None =>; B (2.a.) .trim_start_matches()
+        };
+        for part in names.split(',') {
+            // A (1.a.) let part =; B (2.a.) .split()
+            let words: Vec<&str> = part.split_whitespace().collect(); // A (1.a.) let words =; B (2.a.) .split_whitespace(); B (2.a.) .collect()
+            let (name, alias) = match words.as_slice() {
+                // A (1.a.) let (name, alias) =; C (3.b) match; B (2.a.) .as_slice()
+                [name, "as", alias] => (*name, Some(*alias)), // C (3.c) [name, "as", alias] =>
+                [name] => (*name, None),                      // C (3.c) [name] =>
+                _ => continue,                                // C (3.c) _ =>; B (2.b.) continue
+            };
+            let imported_name = match module {
+                // A (1.a.) let imported_name =; C (3.b) match
+                Some(val) => format!("{}.{}", val, name), // C (3.c) Some()
+                None => name.to_string(),                 // C (3.c) None =>; B (2.a.) .to_string()
+            };
+            match (module, alias) {
+                // C (3.b) match
+                (Some(val), _) if name == "*" => star_modules.push(val.to_string()), // C (3.c) Some(); C (3.a.) name == "*"; A (1.c.) .push(); B (2.a.) .to_string()
+                (_, Some(val)) => {
+                    // C (3.c) Some()
+                    aliases.insert(val.to_string(), imported_name.clone()); // A (1.c.) .insert(); B (2.a.) .to_string(); B (2.a.) .clone()
+                }
+                (Some(_), None) => {
+                    // C (3.c) Some(); C (3.c) None
+                    aliases.insert(name.to_string(), imported_name.clone()); // A (1.c.) .insert(); B (2.a.) .to_string(); B (2.a.) .clone()
+                }
+                (None, None) => {
+                    // C (3.c) None
+                    // `import a.b` binds only `a`, attributes below it are spelled out at every use.
+                    let top_name = name.split('.').next().unwrap_or(name); // A (1.a.) let top_name =; B (2.a.) .split(); B (2.a.) .next(); B (2.a.) .unwrap_or()
+                    aliases.insert(top_name.to_string(), top_name.to_string()); // A (1.c.) .insert(); B (2.a.) .to_string()
+                }
+            }
+            if let Some((_, _, description)) = find_python_script_risky_name(&imported_name) {
+                // A (1.d.) if let Some(); B (2.a.) find_python_script_risky_name()
+                let message = format!("Imports `{}`, which {}", imported_name, description); // A (1.a.) let message =
+                push_warning(&imported_name, token, message); // B (2.a.) push_warning()
+            }
+        }
+    }
+    let mut index = 0; // A (1.a.) let mut index =
+    while index < tokens.len() {
+        // C (3.a.) index < tokens.len(); B (2.a.) .len()
+        if let Some((_, statement_end)) = import_statements.iter().find(|val| val.0 == index) {
+            // A (1.d.) if let Some(); B (2.a.) .iter(); B (2.a.) .find(); C (3.a.) val.0 == index
+            index = *statement_end; // A (1.a.) index =
+            continue; // B (2.b.) continue
+        }
+        let token = &tokens[index]; // A (1.a.) let token =
+        let is_attribute_or_definition =
+            index > 0 && matches!(tokens[index - 1].text.as_str(), "." | "def" | "class"); // A (1.a.) let is_attribute_or_definition =; C (3.a.) index > 0; C (3.a.) matches!(); B (2.a.) .as_str()
+        if token.kind != PythonTokenKind::Name || is_attribute_or_definition {
+            // C (3.a.) token.kind != Name; C (3.a.) is_attribute_or_definition == true
+            index += 1; // A (1.b.) index +=
+            continue; // B (2.b.) continue
+        }
+        let mut name_parts = vec![token.text.clone()]; // A (1.a.) let mut name_parts =; B (2.a.) .clone()
+        index += 1; // A (1.b.) index +=
+        while index + 1 < tokens.len()
+            && tokens[index].text == "."
+            && tokens[index + 1].kind == PythonTokenKind::Name
+        {
+            // C (3.a.) index + 1 < tokens.len(); B (2.a.) .len(); C (3.a.) .text == "."; C (3.a.) .kind == Name
+            name_parts.push(tokens[index + 1].text.clone()); // A (1.c.) .push(); B (2.a.) .clone()
+            index += 2; // A (1.b.) index +=
+        }
+        let dotted_name = name_parts.join("."); // A (1.a.) let dotted_name =; B (2.a.) .join()
+        let resolved_name = match aliases.get(&name_parts[0]) {
+            // A (1.a.) let resolved_name =; C (3.b) match; B (2.a.) .get()
+            Some(val) => Some(format!("{}{}", val, &dotted_name[name_parts[0].len()..])), // C (3.c) Some(); B (2.a.) .len()
+            None if local_names.contains(&name_parts[0].as_str()) => None, // C (3.c) None =>; C (3.a.) .contains() == true; B (2.a.) .contains(); B (2.a.) .as_str()
+            None => star_modules
+                .iter()
+                .map(|val| format!("{}.{}", val, dotted_name))
+                .find(|val| find_python_script_risky_name(val).is_some()), // C (3.c) None =>; B (2.a.) .iter(); B (2.a.) .map(); B (2.a.) .find(); B (2.a.) find_python_script_risky_name(); B (2.a.) .is_some()
+        };
+        let Some(resolved_name) = resolved_name else {
+            // A (1.d.) let Some() else
+            continue; // B (2.b.) continue
+        };
+        if let Some((_, _, description)) = find_python_script_risky_name(&resolved_name) {
+            // A (1.d.) if let Some(); B (2.a.) find_python_script_risky_name()
+            let message = format!("`{}` {}", resolved_name, description); // A (1.a.) let message =
+            push_warning(&resolved_name, token, message); // B (2.a.) push_warning()
+        }
+    }
+    warnings
+}
+
+/// ID: PS_021
+/// ABC analīzes rezultāts:12,6,14
+pub fn get_python_statement_starts(tokens: &[PythonToken]) -> Vec<bool> {
+    let mut statement_starts = vec![false; tokens.len()]; // A (1.a.) let mut statement_starts =; B (2.a.) .len()
+    let mut is_next_start = true; // A (1.a.) let mut is_next_start =
+    let mut bracket_depth = 0; // A (1.a.) let mut bracket_depth =
+    let mut is_header = false; // A (1.a.) let mut is_header =
+    let mut is_lambda = false; // A (1.a.) let mut is_lambda =
+    for (index, token) in tokens.iter().enumerate() {
+        // A (1.a.) let (index, token) =; B (2.a.) .iter(); B (2.a.) .enumerate()
+        if matches!(
+            token.kind,
+            PythonTokenKind::Newline | PythonTokenKind::Indent | PythonTokenKind::Dedent
+        ) {
+            // C (3.a.) matches!()
+            is_next_start = true; // A (1.a.) is_next_start =
+            continue; // B (2.b.) continue
+        }
+        if is_next_start {
+            // C (3.a.) is_next_start == true
+            statement_starts[index] = true; // A (1.a.) statement_starts[index] =
+            is_next_start = false; // A (1.a.) is_next_start =
+            is_header = PYTHON_COMPOUND_KEYWORDS.contains(&token.text.as_str()); // A (1.a.) is_header =; B (2.a.) .contains(); B (2.a.) .as_str()
+            is_lambda = false; // A (1.a.) is_lambda =
+        }
+        if token.kind != PythonTokenKind::Operator && token.text != "lambda" {
+            // C (3.a.) token.kind != Operator; C (3.a.) token.text != "lambda"
+            continue; // B (2.b.) continue
+        }
+
+        // The first `:` outside brackets that doesn't belong to a lambda ends a compound statement
+        // header, so `if x: import os` holds a statement of its own.
+        match token.text.as_str() {
+            // C (3.b) match; B (2.a.) .as_str()
+            "(" | "[" | "{" => bracket_depth += 1, // C (3.c) "(" | "[" | "{" =>; A (1.b.) bracket_depth +=
+            ")" | "]" | "}" => bracket_depth -= 1, // C (3.c) ")" | "]" | "}" =>; A (1.b.) bracket_depth -=
+            ";" if bracket_depth == 0 => is_next_start = true, // C (3.c) ";" =>; C (3.a.) bracket_depth == 0; A (1.a.) is_next_start =
+            "lambda" if bracket_depth == 0 => is_lambda = true, // C (3.c) "lambda" =>; C (3.a.) bracket_depth == 0; A (1.a.) is_lambda =
+            ":" if bracket_depth == 0 && is_lambda => is_lambda = false, // C (3.c) ":" =>; C (3.a.) bracket_depth == 0; C (3.a.) is_lambda == true; A (1.a.) is_lambda =
+            ":" if bracket_depth == 0 && is_header => {
+                // C (3.c) ":" =>; C (3.a.) bracket_depth == 0; C (3.a.) is_header == true
+                is_header = false; // A (1.a.) is_header =
+                is_next_start = true; // A (1.a.) is_next_start =
+            }
+            _ => {} // C (3.c) _ =>
+        }
+    }
+    statement_starts
+}
+
+/// ID: PS_019
+/// ABC analīzes rezultāts:2,3,1
+pub fn check_python_script_safety(content: &str) -> Vec<PythonScriptSafetyWarning> {
+    let (tokens, syntax_warning) = tokenize_python_source(content); // A (1.a.) let (tokens, syntax_warning) =; B (2.a.) tokenize_python_source()
+
+    // Tokens before a syntax error are still checked, the rest of the file is not.
+    let mut warnings = find_python_script_safety_warnings(&tokens); // A (1.a.) let mut warnings =; B (2.a.) find_python_script_safety_warnings()
+    if let Some(val) = syntax_warning {
+        // A (1.d.) if let Some()
+        warnings.insert(0, val); // A (1.c.) .insert()
+    }
+    warnings
+}
+
+/// ID: PS_020
+/// ABC analīzes rezultāts:2,9,2
+pub fn get_python_script_safety_summary(entry: &PythonScript) -> Option<String> {
+    let warnings: Vec<PythonScriptSafetyWarning> =
+        serde_json::from_str(&entry.safety_warnings_json).unwrap_or_default(); // A (1.a.) let warnings =; B (2.a.) ::from_str(); B (2.a.) .unwrap_or_default()
+    if warnings.is_empty() {
+        // C (3.a.) warnings.is_empty() == true; B (2.a.) .is_empty()
+        return None; // B (2.b.) priekšlaicīgs return
+    }
+    let script_name = if entry.name.is_empty() {
+        // A (1.a.) let script_name =; C (3.a.) entry.name.is_empty() == true; B (2.a.) .is_empty()
+        &entry.script_file_path
+    } else {
+        // C (3.b.) else
+        &entry.name
+    };
+    let summary = warnings
+        .iter()
+        .map(|val| format!("{}, line {}: {}", script_name, val.line, val.message))
+        .collect::<Vec<String>>()
+        .join("\n"); // A (1.a.) let summary =; B (2.a.) .iter(); B (2.a.) .map(); B (2.a.) .collect(); B (2.a.) .join()
+    Some(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_categories(content: &str) -> Vec<(String, usize)> {
+        check_python_script_safety(content)
+            .into_iter()
+            .map(|val| (val.category, val.line))
+            .collect()
+    }
+
+    fn get_syntax_error(content: &str) -> (String, usize, usize) {
+        let warning = tokenize_python_source(content).1.unwrap();
+        (warning.message, warning.line, warning.column)
+    }
+
+    fn to_categories(categories: &[(&str, usize)]) -> Vec<(String, usize)> {
+        categories
+            .iter()
+            .map(|(category, line)| (category.to_string(), *line))
+            .collect()
+    }
+
+    fn get_statement_start_texts(content: &str) -> Vec<String> {
+        let tokens = tokenize_python_source(content).0;
+        get_python_statement_starts(&tokens)
+            .into_iter()
+            .zip(tokens)
+            .filter(|(is_start, _)| *is_start)
+            .map(|(_, token)| token.text)
+            .collect()
+    }
+
+    fn create_test_script(content: &str) -> (std::path::PathBuf, PythonScript) {
+        let directory_path =
+            std::env::temp_dir().join(format!("blendio-test-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&directory_path).unwrap();
+        let script_file_path = directory_path.join("render_helper.py");
+        std::fs::write(&script_file_path, content).unwrap();
+        let entry = PythonScript {
+            id: uuid::Uuid::new_v4().to_string(),
+            script_file_path: script_file_path.to_string_lossy().to_string(),
+            ..Default::default()
+        };
+        (directory_path, entry)
+    }
+
+    async fn create_test_pool() -> sqlx::SqlitePool {
+        // Every connection to :memory: opens its own database, so the pool keeps just one.
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        sqlx::migrate!().run(&pool).await.unwrap();
+        pool
+    }
+
+    async fn get_scripts_by_file_name(
+        pool: &sqlx::SqlitePool,
+    ) -> std::collections::HashMap<String, PythonScript> {
+        PythonScriptRepository::new(pool)
+            .fetch(None, None, None)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|val| {
+                let file_name = std::path::Path::new(&val.script_file_path)
+                    .file_name()
+                    .unwrap()
+                    .to_string_lossy()
+                    .to_string();
+                (file_name, val)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn check_python_script_safety_reports_every_category() {
+        assert_eq!(
+            get_categories("import os\nos.remove(path)\n"),
+            to_categories(&[("file_deletion", 2)])
+        );
+        assert_eq!(
+            get_categories("import shutil\n\nshutil.rmtree(path)\n"),
+            to_categories(&[("file_deletion", 3)])
+        );
+        assert_eq!(
+            get_categories("import subprocess\nsubprocess.run(['ls'])\n"),
+            to_categories(&[("subprocess", 1), ("subprocess", 2)])
+        );
+        assert_eq!(
+            get_categories("import socket\nimport urllib.request\nimport requests\n"),
+            to_categories(&[("network", 1), ("network", 2), ("network", 3)])
+        );
+        assert_eq!(
+            get_categories("import bpy\nbpy.ops.wm.quit_blender()\n"),
+            to_categories(&[("quit_blender", 2)])
+        );
+        assert!(get_categories("import bpy\nbpy.ops.render.render()\n").is_empty());
+    }
+
+    #[test]
+    fn check_python_script_safety_follows_aliases() {
+        assert_eq!(
+            get_categories("import os as o; o.remove(path)\n"),
+            to_categories(&[("file_deletion", 1)])
+        );
+        assert_eq!(
+            get_categories("from os import remove\n"),
+            to_categories(&[("file_deletion", 1)])
+        );
+        assert_eq!(
+            get_categories("from os import (\n    path,\n    unlink as delete,\n)\ndelete(p)\n"),
+            to_categories(&[("file_deletion", 1), ("file_deletion", 5)])
+        );
+        assert_eq!(
+            get_categories("from os import *\n\nremove(path)\n"),
+            to_categories(&[("file_deletion", 3)])
+        );
+        assert_eq!(
+            get_categories("def run():\n    os.remove(path)\n\nimport os\n"),
+            to_categories(&[("file_deletion", 2)])
+        );
+    }
+
+    #[test]
+    fn check_python_script_safety_ignores_unrelated_names() {
+        assert_eq!(
+            get_categories("from os import remove\nitems.remove(item)\n"),
+            to_categories(&[("file_deletion", 1)])
+        );
+        assert!(get_categories("import os\nbpy.data.os.remove(item)\n").is_empty());
+        assert!(get_categories("socket = connect()\nsocket.send(data)\n").is_empty());
+        assert!(get_categories(
+            "from os import *\n\ndef remove(item):\n    pass\n\nremove(item)\n"
+        )
+        .is_empty());
+        assert!(get_categories(
+            "import os\nprint(\"os.remove(path)\")  # shutil.rmtree(path)\ntext = '''\nos.remove(path)\n'''\n"
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn check_python_script_safety_reads_imports_after_block_headers() {
+        assert_eq!(
+            get_categories("if enabled: import os\nos.remove(path)\n"),
+            to_categories(&[("file_deletion", 2)])
+        );
+        assert_eq!(
+            get_categories("try: import subprocess\nexcept ImportError: pass\n"),
+            to_categories(&[("subprocess", 1)])
+        );
+        assert_eq!(
+            get_statement_start_texts("if f(lambda x: x)[0:1]: import os\n"),
+            vec!["if", "import"]
+        );
+        assert_eq!(
+            get_statement_start_texts("value = {'a': 1}; x: int = 2\n"),
+            vec!["value", "x"]
+        );
+    }
+
+    #[test]
+    fn tokenize_python_source_reports_syntax_error_positions() {
+        assert_eq!(
+            get_syntax_error("x = (1,\n     2\n"),
+            (String::from("'(' was never closed"), 1, 5)
+        );
+        assert_eq!(
+            get_syntax_error("x = [1, 2)\n"),
+            (
+                String::from("Closing bracket ')' does not match '[' opened on line 1"),
+                1,
+                10
+            )
+        );
+        assert_eq!(
+            get_syntax_error("x = 1\ny = 2)\n"),
+            (String::from("Unmatched ')'"), 2, 6)
+        );
+        assert_eq!(
+            get_syntax_error("x = 1\nname = 'abc\n"),
+            (String::from("Unterminated string literal"), 2, 8)
+        );
+        assert_eq!(
+            get_syntax_error("text = \"\"\"abc\n"),
+            (String::from("Unterminated string literal"), 1, 8)
+        );
+        assert_eq!(
+            get_syntax_error("if x:\n        a = 1\n    b = 2\n"),
+            (
+                String::from("Unindent does not match any outer indentation level"),
+                3,
+                5
+            )
+        );
+        assert_eq!(
+            get_syntax_error("x = 1\n    y = 2\n"),
+            (String::from("Unexpected indent"), 2, 5)
+        );
+        assert!(tokenize_python_source("if x:\n    a = (1,\n2)\nb = 3\n")
+            .1
+            .is_none());
+    }
+
+    #[test]
+    fn parse_python_script_metadata_reads_docstring_and_bl_info() {
+        let metadata = parse_python_script_metadata(
+            r#"#!/usr/bin/env python
+# Helper script
+
+"""Render helper.
+
+Sets up the scene for batch rendering.
+"""
+
+bl_info = {
+    "name": "Render Helper",
+    'description': 'Sets up batch renders',
+    "author": "Jane",
+    "version": (1, 2, 0),
+    "blender": (4, 2, 0),
+    "category": "Render",
+    "unknown": "ignored",
+}
+"#,
+        );
+        assert_eq!(
+            metadata.docstring.as_deref(),
+            Some("Render helper.\n\nSets up the scene for batch rendering.")
+        );
+        assert_eq!(metadata.name.as_deref(), Some("Render Helper"));
+        assert_eq!(
+            metadata.description.as_deref(),
+            Some("Sets up batch renders")
+        );
+        assert_eq!(metadata.author.as_deref(), Some("Jane"));
+        assert_eq!(metadata.version.as_deref(), Some("1.2.0"));
+        assert_eq!(metadata.blender.as_deref(), Some("4.2.0"));
+        assert_eq!(metadata.category.as_deref(), Some("Render"));
+    }
+
+    #[test]
+    fn parse_python_script_metadata_ignores_late_docstring() {
+        let metadata = parse_python_script_metadata("import bpy\n'''Not a docstring.'''\n");
+        assert_eq!(metadata.docstring, None);
+        assert_eq!(metadata.name, None);
+        let metadata = parse_python_script_metadata("'''Single quoted.'''\n");
+        assert_eq!(metadata.docstring.as_deref(), Some("Single quoted."));
+    }
+
+    #[test]
+    fn refresh_python_script_metadata_fills_only_empty_fields() {
+        let (directory_path, mut entry) = create_test_script(
+            "\"\"\"Sets up renders.\n\nDetails.\"\"\"\nimport os\nos.remove('a')\n",
+        );
+        refresh_python_script_metadata(&mut entry).unwrap();
+        assert_eq!(entry.name, "render_helper");
+        assert_eq!(entry.description, "Sets up renders.");
+        assert_eq!(
+            entry.content_hash,
+            Some(
+                file_system_utility::get_file_sha256_checksum(std::path::Path::new(
+                    &entry.script_file_path
+                ))
+                .unwrap()
+            )
+        );
+        assert!(get_python_script_safety_summary(&entry).is_some());
+
+        entry.name = "Edited name".to_string();
+        entry.description = "Edited description".to_string();
+        std::fs::write(&entry.script_file_path, "print('safe')\n").unwrap();
+        refresh_python_script_metadata(&mut entry).unwrap();
+        assert_eq!(entry.name, "Edited name");
+        assert_eq!(entry.description, "Edited description");
+        assert!(get_python_script_safety_summary(&entry).is_none());
+
+        std::fs::remove_dir_all(&directory_path).unwrap();
+        assert!(refresh_python_script_metadata(&mut entry).is_err());
+    }
+
+    #[test]
+    fn write_python_script_snapshot_stores_written_bytes_by_hash() {
+        let (directory_path, mut entry) = create_test_script("print('first')\n");
+        let snapshot_directory_path = directory_path.join("snapshots");
+        std::fs::create_dir_all(&snapshot_directory_path).unwrap();
+
+        // A stale content hash on the entry does not name the snapshot.
+        entry.content_hash = Some("stale".to_string());
+        let snapshot = write_python_script_snapshot(&entry, &snapshot_directory_path).unwrap();
+        assert_eq!(
+            snapshot.content_hash,
+            file_system_utility::get_file_sha256_checksum(std::path::Path::new(
+                &entry.script_file_path
+            ))
+            .unwrap()
+        );
+        assert_eq!(
+            std::fs::read_to_string(&snapshot.snapshot_file_path).unwrap(),
+            "print('first')\n"
+        );
+        assert_eq!(snapshot.python_script_id, entry.id);
+        assert_eq!(snapshot.launch_session_id, None);
+
+        let unchanged_snapshot =
+            write_python_script_snapshot(&entry, &snapshot_directory_path).unwrap();
+        assert_eq!(
+            unchanged_snapshot.snapshot_file_path,
+            snapshot.snapshot_file_path
+        );
+        assert_ne!(unchanged_snapshot.id, snapshot.id);
+
+        std::fs::write(&entry.script_file_path, "print('second')\n").unwrap();
+        let changed_snapshot =
+            write_python_script_snapshot(&entry, &snapshot_directory_path).unwrap();
+        assert_ne!(changed_snapshot.content_hash, snapshot.content_hash);
+        assert_eq!(
+            std::fs::read_to_string(&snapshot.snapshot_file_path).unwrap(),
+            "print('first')\n"
+        );
+        assert_eq!(
+            std::fs::read_dir(&snapshot_directory_path).unwrap().count(),
+            2
+        );
+        std::fs::remove_dir_all(&directory_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn sync_python_script_library_folder_scripts_tracks_folder_contents() {
+        let pool = create_test_pool().await;
+        let folder_path =
+            std::env::temp_dir().join(format!("blendio-test-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(folder_path.join("render").join("batch")).unwrap();
+        std::fs::create_dir_all(folder_path.join(".hidden")).unwrap();
+        std::fs::write(folder_path.join("setup.py"), "'''Sets up the scene.'''\n").unwrap();
+        let nested_file_path = folder_path.join("render").join("batch").join("bake.py");
+        std::fs::write(&nested_file_path, "print('bake')\n").unwrap();
+        std::fs::write(folder_path.join(".hidden").join("skipped.py"), "").unwrap();
+        std::fs::write(folder_path.join("notes.txt"), "").unwrap();
+        let folder = PythonScriptLibraryFolder {
+            id: uuid::Uuid::new_v4().to_string(),
+            folder_path: folder_path.to_string_lossy().to_string(),
+            ..Default::default()
+        };
+        PythonScriptLibraryFolderRepository::new(&pool)
+            .insert(&folder)
+            .await
+            .unwrap();
+
+        sync_python_script_library_folder_scripts(&pool)
+            .await
+            .unwrap();
+        let scripts = get_scripts_by_file_name(&pool).await;
+        assert_eq!(scripts.len(), 2);
+        assert_eq!(scripts["setup.py"].tags_json, "[]");
+        assert_eq!(scripts["setup.py"].description, "Sets up the scene.");
+        assert_eq!(scripts["bake.py"].tags_json, r#"["render","batch"]"#);
+        assert_eq!(
+            scripts["bake.py"].library_folder_id.as_deref(),
+            Some(folder.id.as_str())
+        );
+        assert!(scripts["bake.py"].content_hash.is_some());
+
+        // A second sync finds the same files and keeps their entries.
+        sync_python_script_library_folder_scripts(&pool)
+            .await
+            .unwrap();
+        let resynced_scripts = get_scripts_by_file_name(&pool).await;
+        assert_eq!(resynced_scripts.len(), 2);
+        assert_eq!(resynced_scripts["bake.py"].id, scripts["bake.py"].id);
+        assert_eq!(resynced_scripts["setup.py"].id, scripts["setup.py"].id);
+
+        std::fs::remove_file(&nested_file_path).unwrap();
+        sync_python_script_library_folder_scripts(&pool)
+            .await
+            .unwrap();
+        let missing_scripts = get_scripts_by_file_name(&pool).await;
+        assert_eq!(missing_scripts.len(), 2);
+        assert!(missing_scripts["bake.py"].is_missing);
+        assert!(!missing_scripts["setup.py"].is_missing);
+
+        std::fs::write(&nested_file_path, "print('bake again')\n").unwrap();
+        sync_python_script_library_folder_scripts(&pool)
+            .await
+            .unwrap();
+        let restored_scripts = get_scripts_by_file_name(&pool).await;
+        assert_eq!(restored_scripts.len(), 2);
+        assert!(!restored_scripts["bake.py"].is_missing);
+        assert_eq!(restored_scripts["bake.py"].id, scripts["bake.py"].id);
+        assert_ne!(
+            restored_scripts["bake.py"].content_hash,
+            scripts["bake.py"].content_hash
+        );
+        std::fs::remove_dir_all(&folder_path).unwrap();
+    }
+}
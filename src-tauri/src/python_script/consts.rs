@@ -0,0 +1,33 @@
+/// Dotted names reported when a script references them, as (name, category, description).
+/// A module name also matches everything imported from that module.
+pub const PYTHON_SCRIPT_RISKY_NAMES: &[(&str, &str, &str)] = &[
+    ("os.remove", "file_deletion", "deletes files"),
+    ("os.unlink", "file_deletion", "deletes files"),
+    ("os.rmdir", "file_deletion", "deletes directories"),
+    ("os.removedirs", "file_deletion", "deletes directories"),
+    ("shutil.rmtree", "file_deletion", "deletes directory trees"),
+    ("os.system", "subprocess", "runs shell commands"),
+    ("os.popen", "subprocess", "runs shell commands"),
+    ("subprocess", "subprocess", "runs external programs"),
+    ("socket", "network", "accesses the network"),
+    ("ssl", "network", "accesses the network"),
+    ("http", "network", "accesses the network"),
+    ("urllib", "network", "accesses the network"),
+    ("requests", "network", "accesses the network"),
+    ("ftplib", "network", "accesses the network"),
+    ("smtplib", "network", "accesses the network"),
+    ("bpy.ops.wm.quit_blender", "quit_blender", "quits Blender"),
+];
+/// Operators the tokenizer recognizes, longest first so `**=` is not read as `**` and `=`.
+pub const PYTHON_OPERATORS: &[&str] = &[
+    "**=", "//=", ">>=", "<<=", "...", "**", "//", "==", "!=", "<=", ">=", "->", "+=", "-=", "*=",
+    "/=", "%=", "&=", "|=", "^=", "@=", "<<", ">>", ":=", "+", "-", "*", "/", "%", "@", "&", "|",
+    "^", "~", "<", ">", "=", ".", ",", ":", ";",
+];
+/// Width of a tab when measuring indentation, the same as the Python tokenizer uses.
+pub const PYTHON_TAB_SIZE: usize = 8;
+/// Keywords that start a compound statement, its body may follow the header `:` on the same line.
+pub const PYTHON_COMPOUND_KEYWORDS: &[&str] = &[
+    "if", "elif", "else", "for", "while", "with", "try", "except", "finally", "def", "class",
+    "async", "match", "case",
+];
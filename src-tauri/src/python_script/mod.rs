@@ -1,3 +1,5 @@
 mod commands;
+mod consts;
 
-pub use commands::*;
\ No newline at end of file
+pub use commands::*;
+pub use consts::*;
@@ -10,6 +10,7 @@ const TitleBar = () => {
         <Link to="/projectFiles" className="navlink">Project Files</Link>
         <Link to="/installedBlenderVersions" className="navlink">Installed Versions</Link>
         <Link to="/blenderdownload" className="navlink">Downloads</Link>
+        <Link to="/launchHistory" className="navlink">Launch History</Link>
         <Link to="/settings" className="navlink">Settings</Link>
       </div>
     </div>
@@ -19,7 +19,6 @@ const CreateBlendPopup = () => {
 
     const loadInstalledBlenderVersions = async () => {
         try {
-            await invoke("insert_and_refresh_installed_blender_versions");
             const versions = await invoke("fetch_installed_blender_versions", {
                 id: null,
                 limit: null,
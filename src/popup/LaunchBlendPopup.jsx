@@ -1,24 +1,32 @@
 import React, { useEffect, useState } from "react";
 import { invoke } from "@tauri-apps/api/core";
 import { getCurrentWindow } from "@tauri-apps/api/window";
-import { emit } from "@tauri-apps/api/event";
+import { emit, listen } from "@tauri-apps/api/event";
 
 const LaunchBlendPopup = () => {
     const [installedBlenderVersions, setInstalledBlenderVersions] = useState([]);
     const [selectedVersionId, setSelectedVersionId] = useState(null);
     const [launchArgs, setLaunchArgs] = useState("");
-    const [pythonFilePath, setPythonFilePath] = useState("");
-    const [selectedPythonScript, setSelectedPythonScript] = useState(null);
+    const [selectedPythonScripts, setSelectedPythonScripts] = useState([]);
+    const [pythonExpressions, setPythonExpressions] = useState("");
     const [recentPythonScripts, setRecentPythonScripts] = useState([]);
     const [recentLaunchArgs, setRecentLaunchArgs] = useState([]);
 
-    // Match if last argument is --python or -P (trailing whitespace allowed)
-    const needsPythonFile = /(?:^|\s)(--python|-P)\s*$/.test(launchArgs.trim());
 
     useEffect(() => {
         loadInstalledBlenderVersions();
         loadPythonScripts();
         loadLaunchArgs();
+
+        // The project list answers with the last used or best matching version for the file.
+        const unlistenPromise = listen("launch-project-file-default-version", (event) => {
+            if (event.payload.versionId) setSelectedVersionId(event.payload.versionId);
+        });
+        unlistenPromise.then(() => emit("launch-project-file-popup-ready"));
+
+        return () => {
+            unlistenPromise.then((unlisten) => unlisten());
+        };
     }, []);
 
     const closeWindow = async () => {
@@ -28,7 +36,6 @@ const LaunchBlendPopup = () => {
 
     const loadInstalledBlenderVersions = async () => {
         try {
-            await invoke("insert_and_refresh_installed_blender_versions");
             const versions = await invoke("fetch_installed_blender_versions", {
                 id: null,
                 limit: null,
@@ -72,8 +79,7 @@ const LaunchBlendPopup = () => {
         try {
             const pythonScript = await invoke("insert_python_script");
             if (pythonScript) {
-                setSelectedPythonScript(pythonScript);
-                setPythonFilePath(pythonScript.script_file_path);
+                setSelectedPythonScripts([...selectedPythonScripts, pythonScript]);
             }
             loadPythonScripts();
         } catch (err) {
@@ -81,12 +87,24 @@ const LaunchBlendPopup = () => {
         }
     };
 
+    const handleMovePythonScript = (index, offset) => {
+        const scripts = [...selectedPythonScripts];
+        [scripts[index], scripts[index + offset]] = [scripts[index + offset], scripts[index]];
+        setSelectedPythonScripts(scripts);
+    };
+
+    const getPythonExpressions = () =>
+        pythonExpressions
+            .split("\n")
+            .map((e) => e.trim())
+            .filter((e) => e !== "");
+
     const handleOpen = async () => {
         if (!selectedVersionId) return;
-        if (needsPythonFile && !selectedPythonScript) return;
         await emit("open-project-file-confirmed", {
             versionId: selectedVersionId,
-            pythonScriptId: selectedPythonScript?.id || null,
+            pythonScriptIds: selectedPythonScripts.map((script) => script.id),
+            pythonExpressions: getPythonExpressions(),
             launchArgs: launchArgs.trim(),
         });
         await closeWindow();
@@ -100,7 +118,7 @@ const LaunchBlendPopup = () => {
             <input
                 type="text"
                 className="mb-4"
-                placeholder="e.g., --background --python"
+                placeholder="e.g., --background -- --script-arg"
                 value={launchArgs}
                 onChange={(e) => setLaunchArgs(e.target.value)}
             />
@@ -149,17 +167,51 @@ const LaunchBlendPopup = () => {
             )}
 
             <div className="mb-4">
-                <button
-                    onClick={handlePythonFileSelect}
-                    disabled={!needsPythonFile}
-                >
-                    {pythonFilePath ? "Python File Selected" : "Select Python Script"}
-                </button>
-                {pythonFilePath && (
-                    <p>{pythonFilePath}</p>
+                <button onClick={handlePythonFileSelect}>Add Python Script</button>
+                {selectedPythonScripts.length > 0 && (
+                    <div className="mt-2">
+                        <p>Scripts run in this order:</p>
+                        <ol>
+                            {selectedPythonScripts.map((script, index) => (
+                                <li key={index}>
+                                    {script.script_file_path}
+                                    {JSON.parse(script.safety_warnings_json).length > 0 && (
+                                        <span
+                                            className="text-red-500"
+                                            title={JSON.parse(script.safety_warnings_json)
+                                                .map((e) => `Line ${e.line}: ${e.message}`)
+                                                .join("\n")}
+                                        >
+                                            {" "}(safety warnings)
+                                        </span>
+                                    )}
+                                    <button
+                                        disabled={index === 0}
+                                        onClick={() => handleMovePythonScript(index, -1)}
+                                    >
+                                        Up
+                                    </button>
+                                    <button
+                                        disabled={index === selectedPythonScripts.length - 1}
+                                        onClick={() => handleMovePythonScript(index, 1)}
+                                    >
+                                        Down
+                                    </button>
+                                    <button
+                                        className="text-red-500"
+                                        onClick={() =>
+                                            setSelectedPythonScripts(
+                                                selectedPythonScripts.filter((_, i) => i !== index)
+                                            )
+                                        }
+                                    >
+                                        Remove
+                                    </button>
+                                </li>
+                            ))}
+                        </ol>
+                    </div>
                 )}
-
-
                 {recentPythonScripts.length > 0 && (
                     <div className="mt-2">
                         <p>Recently Used Scripts:</p>
@@ -167,13 +219,9 @@ const LaunchBlendPopup = () => {
                             {recentPythonScripts.map((script) => (
                                 <li key={script.id}>
                                     <button
-                                        disabled={!needsPythonFile}
-                                        onClick={() => {
-                                            if (needsPythonFile) {
-                                                setSelectedPythonScript(script);
-                                                setPythonFilePath(script.script_file_path);
-                                            }
-                                        }}
+                                        onClick={() =>
+                                            setSelectedPythonScripts([...selectedPythonScripts, script])
+                                        }
                                     >
                                         {script.script_file_path}
                                     </button>
@@ -182,20 +230,16 @@ const LaunchBlendPopup = () => {
                         </ul>
                     </div>
                 )}
-
-                {selectedPythonScript && (
-                    <button
-                        onClick={() => {
-                            setSelectedPythonScript(null);
-                            setPythonFilePath("");
-                        }}
-                        className="mt-2 text-red-500"
-                    >
-                        Clear selected Python script
-                    </button>
-                )}
             </div>
 
+            <label className="mb-2">Python Expressions (one per line)</label>
+            <textarea
+                className="mb-4"
+                value={pythonExpressions}
+                onChange={(e) => setPythonExpressions(e.target.value)}
+                placeholder="e.g. import bpy; bpy.context.scene.render.fps = 30"
+            />
+
             <br />
             {installedBlenderVersions.length > 0 && (
                 <button
@@ -217,6 +261,7 @@ const LaunchBlendPopup = () => {
                 {installedBlenderVersions.map((v) => (
                     <li key={v.id}>
                         <button
+                            className={v.id === selectedVersionId ? "font-bold" : ""}
                             onClick={() => setSelectedVersionId(v.id)}
                         >
                             {v.version} {v.variant_type}
@@ -233,9 +278,7 @@ const LaunchBlendPopup = () => {
                 </button>
                 <button
                     onClick={handleOpen}
-                    disabled={
-                        !selectedVersionId || (needsPythonFile && !selectedPythonScript)
-                    }
+                    disabled={!selectedVersionId}
                 >
                     Open
                 </button>
@@ -5,12 +5,11 @@ import { emit } from "@tauri-apps/api/event";
 
 const LaunchBlenderPopup = () => {
     const [launchArgs, setLaunchArgs] = useState("");
-    const [pythonFilePath, setPythonFilePath] = useState("");
-    const [selectedPythonScript, setSelectedPythonScript] = useState(null);
+    const [selectedPythonScripts, setSelectedPythonScripts] = useState([]);
+    const [pythonExpressions, setPythonExpressions] = useState("");
     const [recentPythonScripts, setRecentPythonScripts] = useState([]);
     const [recentLaunchArgs, setRecentLaunchArgs] = useState([]);
 
-    const needsPythonFile = /(?:^|\s)(--python|-P)\s*$/.test(launchArgs.trim());
 
     useEffect(() => {
         loadPythonScripts();
@@ -54,8 +53,7 @@ const LaunchBlenderPopup = () => {
         try {
             const pythonScript = await invoke("insert_python_script");
             if (pythonScript) {
-                setSelectedPythonScript(pythonScript);
-                setPythonFilePath(pythonScript.script_file_path);
+                setSelectedPythonScripts([...selectedPythonScripts, pythonScript]);
             }
             loadPythonScripts();
         } catch (err) {
@@ -63,10 +61,23 @@ const LaunchBlenderPopup = () => {
         }
     };
 
+    const handleMovePythonScript = (index, offset) => {
+        const scripts = [...selectedPythonScripts];
+        [scripts[index], scripts[index + offset]] = [scripts[index + offset], scripts[index]];
+        setSelectedPythonScripts(scripts);
+    };
+
+    const getPythonExpressions = () =>
+        pythonExpressions
+            .split("\n")
+            .map((e) => e.trim())
+            .filter((e) => e !== "");
+
     const handleLaunch = async () => {
         try {
             await emit("launch-blender-instance-requested", {
-                pythonScriptId: selectedPythonScript?.id || null,
+                pythonScriptIds: selectedPythonScripts.map((script) => script.id),
+            pythonExpressions: getPythonExpressions(),
                 launchArgs: launchArgs.trim(),
             });
             await closeWindow();
@@ -86,7 +97,7 @@ const LaunchBlenderPopup = () => {
                 className="mb-4"
                 value={launchArgs}
                 onChange={(e) => setLaunchArgs(e.target.value)}
-                placeholder="e.g. --background --python"
+                placeholder="e.g. --background -- --script-arg"
             />
 
 
@@ -132,14 +143,50 @@ const LaunchBlenderPopup = () => {
             )}
 
             <div className="mb-4">
-                <button
-                    onClick={handlePythonFileSelect}
-                    disabled={!needsPythonFile}
-                >
-                    {pythonFilePath ? "Python File Selected" : "Select Python Script"}
-                </button>
-                {pythonFilePath && (
-                    <p>{pythonFilePath}</p>
+                <button onClick={handlePythonFileSelect}>Add Python Script</button>
+                {selectedPythonScripts.length > 0 && (
+                    <div className="mt-2">
+                        <p>Scripts run in this order:</p>
+                        <ol>
+                            {selectedPythonScripts.map((script, index) => (
+                                <li key={index}>
+                                    {script.script_file_path}
+                                    {JSON.parse(script.safety_warnings_json).length > 0 && (
+                                        <span
+                                            className="text-red-500"
+                                            title={JSON.parse(script.safety_warnings_json)
+                                                .map((e) => `Line ${e.line}: ${e.message}`)
+                                                .join("\n")}
+                                        >
+                                            {" "}(safety warnings)
+                                        </span>
+                                    )}
+                                    <button
+                                        disabled={index === 0}
+                                        onClick={() => handleMovePythonScript(index, -1)}
+                                    >
+                                        Up
+                                    </button>
+                                    <button
+                                        disabled={index === selectedPythonScripts.length - 1}
+                                        onClick={() => handleMovePythonScript(index, 1)}
+                                    >
+                                        Down
+                                    </button>
+                                    <button
+                                        className="text-red-500"
+                                        onClick={() =>
+                                            setSelectedPythonScripts(
+                                                selectedPythonScripts.filter((_, i) => i !== index)
+                                            )
+                                        }
+                                    >
+                                        Remove
+                                    </button>
+                                </li>
+                            ))}
+                        </ol>
+                    </div>
                 )}
                 {recentPythonScripts.length > 0 && (
                     <div className="mt-2">
@@ -148,11 +195,9 @@ const LaunchBlenderPopup = () => {
                             {recentPythonScripts.map((script) => (
                                 <li key={script.id}>
                                     <button
-                                        disabled={!needsPythonFile}
-                                        onClick={() => {
-                                            setSelectedPythonScript(script);
-                                            setPythonFilePath(script.script_file_path);
-                                        }}
+                                        onClick={() =>
+                                            setSelectedPythonScripts([...selectedPythonScripts, script])
+                                        }
                                     >
                                         {script.script_file_path}
                                     </button>
@@ -161,30 +206,23 @@ const LaunchBlenderPopup = () => {
                         </ul>
                     </div>
                 )}
-
-                {selectedPythonScript && (
-                    <button
-                        onClick={() => {
-                            setSelectedPythonScript(null);
-                            setPythonFilePath("");
-                        }}
-                        className="mt-2 text-red-500"
-                    >
-                        Clear selected Python script
-                    </button>
-                )}
             </div>
 
+            <label className="mb-2">Python Expressions (one per line)</label>
+            <textarea
+                className="mb-4"
+                value={pythonExpressions}
+                onChange={(e) => setPythonExpressions(e.target.value)}
+                placeholder="e.g. import bpy; bpy.context.scene.render.fps = 30"
+            />
+
             <div>
                 <button
                     onClick={closeWindow}
                 >
                     Cancel
                 </button>
-                <button
-                    onClick={handleLaunch}
-                    disabled={needsPythonFile && !selectedPythonScript}
-                >
+                <button onClick={handleLaunch}>
                     Launch
                 </button>
             </div>
@@ -5,6 +5,7 @@ import BlenderDownload from './views/BlenderDownload';
 import DownloadPopup from './popup/DownloadPopup';
 import InstalledBlenderVersions from './views/InstalledBlenderVersions';
 import ProjectFiles from './views/ProjectFiles';
+import LaunchHistory from './views/LaunchHistory';
 import CreateBlendPopup from './popup/CreateBlendPopup';
 import LaunchBlendPopup from './popup/LaunchBlendPopup';
 import LaunchBlenderPopup from './popup/LaunchBlenderPopup';
@@ -15,6 +16,7 @@ const AppRouter = () => (
     <Route path="/projectFiles" element={<ProjectFiles />} />
     <Route path="/installedBlenderVersions" element={<InstalledBlenderVersions />} />
     <Route path="/blenderdownload" element={<BlenderDownload />} />
+    <Route path="/launchHistory" element={<LaunchHistory />} />
     <Route path="/settings" element={<Settings />} />
     <Route path="/popup/DownloadPopup" element={<DownloadPopup />} />
     <Route path="/popup/CreateBlendPopup" element={<CreateBlendPopup />} />
@@ -1,22 +1,29 @@
-import { download } from "@tauri-apps/plugin-upload";
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
 
-export async function downloadFile(url, filePath, buttonId) {
+export async function downloadFile(downloadId, build, repoDirectoryPath, buttonId) {
   const button = document.getElementById(buttonId);
-  if (!button) return;
+  if (!button) return null;
 
-  let accumulated = 0;
   const originalText = button.textContent;
   button.disabled = true;
   button.textContent = "Starting...";
+  const unlisten = await listen("blender-download-progress", (event) => {
+    const { download_id, downloaded_bytes, total_bytes, eta_seconds } = event.payload;
+    if (download_id !== downloadId) return;
+    const button = document.getElementById(buttonId);
+    if (button && total_bytes) {
+      const percent = Math.floor((downloaded_bytes / total_bytes) * 100);
+      const eta = eta_seconds != null ? ` (${Math.ceil(eta_seconds)}s left)` : "";
+      button.textContent = `Downloading... ${percent}%${eta}`;
+      button.disabled = true;
+    }
+  });
   try {
-    await download(url, filePath, ({ progress, total }) => {
-      accumulated += progress;
-      const percent = Math.floor((accumulated / total) * 100);
-      const button = document.getElementById(buttonId);
-      if (button) {
-        button.textContent = `Downloading... ${percent}%`;
-        button.disabled = true;
-      }
+    const archiveFilePath = await invoke("download_blender_version", {
+      downloadId,
+      repoDirectoryPath,
+      downloadableBlenderVersion: build,
     });
 
     const finishedButton = document.getElementById(buttonId);
@@ -24,10 +31,14 @@ export async function downloadFile(url, filePath, buttonId) {
       finishedButton.textContent = originalText;
       finishedButton.disabled = false;
     }
+    return archiveFilePath;
   } catch (err) {
     console.error("Download failed:", err);
-    if (button) button.textContent = "Error";
+    const isCancelled = String(err).includes("download cancelled");
+    if (button) button.textContent = isCancelled ? originalText : "Error";
+    return null;
   } finally {
+    unlisten();
     if (button) button.disabled = false;
   }
-}
+}
\ No newline at end of file
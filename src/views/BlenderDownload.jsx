@@ -6,6 +6,7 @@ import { listen } from "@tauri-apps/api/event";
 export default function BlenderDownload() {
     const [isOnline, setIsOnline] = useState(false);
     const [downloadableBuilds, setDownloadableBuilds] = useState([]);
+    const [activeDownloadIds, setActiveDownloadIds] = useState([]);
     const pendingDownloadRef = useRef(null);
 
     useEffect(() => {
@@ -21,13 +22,17 @@ export default function BlenderDownload() {
             const selectedPath = event.payload?.path;
             const pending = pendingDownloadRef.current;
             if (selectedPath && pending) {
-                const { build, url, fileName, buttonId } = pending;
+                const { build, fileName, buttonId } = pending;
                 pendingDownloadRef.current = null;
-                await downloadFile(url, `${selectedPath}\\${fileName}`, buttonId);
-                await invoke("download_and_install_blender_version", {
-                    archiveFilePath: `${selectedPath}\\${fileName}`,
-                    downloadableBlenderVersion: build,
-                });
+                setActiveDownloadIds((ids) => [...ids, fileName]);
+                const archiveFilePath = await downloadFile(fileName, build, selectedPath, buttonId);
+                setActiveDownloadIds((ids) => ids.filter((id) => id !== fileName));
+                if (archiveFilePath) {
+                    await invoke("download_and_install_blender_version", {
+                        archiveFilePath,
+                        downloadableBlenderVersion: build,
+                    });
+                }
             }
         });
 
@@ -75,6 +80,14 @@ export default function BlenderDownload() {
         }
     };
 
+    const cancelDownload = async (downloadId) => {
+        try {
+            await invoke("cancel_blender_version_download", { downloadId });
+        } catch (err) {
+            console.error("Failed to cancel download:", err);
+        }
+    };
+
     return (
         <div className="p-4">
             <h1 className="mb-4">Blender Download (Daily Builds)</h1>
@@ -119,6 +132,14 @@ export default function BlenderDownload() {
                                     >
                                         Download
                                     </button>
+                                    {activeDownloadIds.includes(build.file_name) && (
+                                        <button
+                                            className="ml-2 text-red-500"
+                                            onClick={() => cancelDownload(build.file_name)}
+                                        >
+                                            Cancel
+                                        </button>
+                                    )}
                                 </td>
                             </tr>
                         );
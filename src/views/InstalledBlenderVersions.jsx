@@ -4,13 +4,15 @@ import { listen } from "@tauri-apps/api/event";
 
 export default function InstalledBlenderVersions() {
     const [installedBlenderVersions, setInstalledBlenderVersions] = useState([]);
+    const [runningInstances, setRunningInstances] = useState([]);
     const pendingLaunchVersionRef = useRef(null);
 
     useEffect(() => {
         loadInstalledBlenderVersions();
+        loadRunningInstances();
 
         const unlisten = listen("launch-blender-instance-requested", async (event) => {
-            const { pythonScriptId, launchArgs } = event.payload;
+            const { pythonScriptIds, pythonExpressions, launchArgs } = event.payload;
             const versionId = pendingLaunchVersionRef.current;
             if (!versionId) {
                 console.error("Missing versionId — did you forget to set the ref?");
@@ -24,15 +26,18 @@ export default function InstalledBlenderVersions() {
                     launchArgumentId = await invoke("insert_launch_argument", {
                         argumentString: launchArgs.trim(),
                         projectFileId: null,
-                        pythonScriptId: pythonScriptId || null,
+                        pythonScriptId: pythonScriptIds[0] || null,
                     });
                 }
                 await invoke("launch_blender_version_with_launch_args", {
                     id: versionId,
                     launchArgumentsId: launchArgumentId || null,
-                    pythonScriptId: pythonScriptId || null,
+                    pythonScriptIds,
+                    pythonExpressions,
+                    scriptArguments: [],
                 });
                 await loadInstalledBlenderVersions();
+                await loadRunningInstances();
             } catch (err) {
                 console.error("Failed to launch Blender version from popup:", err);
             } finally {
@@ -40,14 +45,23 @@ export default function InstalledBlenderVersions() {
             }
         });
 
+        const unlistenChanged = listen("installed-blender-versions-changed", async () => {
+            await loadInstalledBlenderVersions();
+        });
+
+        const unlistenExited = listen("blender-instance-exited", async () => {
+            await loadRunningInstances();
+        });
+
         return () => {
             unlisten.then((f) => f());
+            unlistenChanged.then((f) => f());
+            unlistenExited.then((f) => f());
         };
     }, []);
 
     const loadInstalledBlenderVersions = async () => {
         try {
-            await invoke("insert_and_refresh_installed_blender_versions");
             const versions = await invoke("fetch_installed_blender_versions", {
                 id: null,
                 limit: null,
@@ -60,6 +74,37 @@ export default function InstalledBlenderVersions() {
         }
     };
 
+    const loadRunningInstances = async () => {
+        try {
+            const instances = await invoke("fetch_running_blender_instances");
+            setRunningInstances(instances);
+        } catch (err) {
+            setRunningInstances([]);
+            console.error("Failed to load running Blender instances:", err);
+        }
+    };
+
+    const handleTerminate = async (instanceId) => {
+        try {
+            await invoke("terminate_blender_instance", { id: instanceId });
+        } catch (err) {
+            await loadRunningInstances();
+            console.error("Failed to terminate Blender instance:", err);
+        }
+    };
+
+    const handleScanSystem = async () => {
+        try {
+            await invoke("insert_system_installed_blender_versions", {
+                installSources: ["system", "local", "snap", "flatpak", "steam"],
+            });
+            await loadInstalledBlenderVersions();
+        } catch (err) {
+            await loadInstalledBlenderVersions();
+            console.error("Failed to scan for system installed Blender versions:", err);
+        }
+    };
+
     const handleSetDefault = async (selectedId) => {
         try {
             await invoke("update_installed_blender_version", {
@@ -102,11 +147,15 @@ export default function InstalledBlenderVersions() {
     return (
         <div className="p-4">
             <h1 className="mb-4">Installed Blender Versions</h1>
+            <button className="mb-4" onClick={handleScanSystem}>
+                Scan system installs
+            </button>
             <table className="border-collapse">
                 <thead>
                     <tr>
                         <th className="p-2">Version</th>
                         <th className="p-2">Variant</th>
+                        <th className="p-2">Source</th>
                         <th className="p-2">Installation Path</th>
                         <th className="p-2">Executable file path</th>
                         <th className="p-2">Created</th>
@@ -121,6 +170,12 @@ export default function InstalledBlenderVersions() {
                         <tr key={entry.id}>
                             <td className="p-2">{entry.version}</td>
                             <td className="p-2">{entry.variant_type}</td>
+                            <td className="p-2">
+                                {entry.install_source}
+                                {entry.is_checksum_verified === false && (
+                                    <span className="ml-1 text-red-500">(unverified)</span>
+                                )}
+                            </td>
                             <td className="p-2">{entry.installation_directory_path}</td>
                             <td className="p-2">{entry.executable_file_path}</td>
                             <td className="p-2">{entry.created}</td>
@@ -150,13 +205,53 @@ export default function InstalledBlenderVersions() {
                     ))}
                     {installedBlenderVersions.length === 0 && (
                         <tr>
-                            <td colSpan="9" className="p-4">
+                            <td colSpan="10" className="p-4">
                                 No installed versions found.
                             </td>
                         </tr>
                     )}
                 </tbody>
             </table>
+
+            <h2 className="mt-8 mb-2">Running instances</h2>
+            <table className="border-collapse">
+                <thead>
+                    <tr>
+                        <th className="p-2">PID</th>
+                        <th className="p-2">Version</th>
+                        <th className="p-2">Arguments</th>
+                        <th className="p-2">Started</th>
+                        <th className="p-2">Actions</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {runningInstances.map((instance) => (
+                        <tr key={instance.id}>
+                            <td className="p-2">{instance.pid}</td>
+                            <td className="p-2">
+                                {installedBlenderVersions.find((e) => e.id === instance.installed_blender_version_id)?.version}
+                            </td>
+                            <td className="p-2">{instance.arguments.join(" ")}</td>
+                            <td className="p-2">{instance.started}</td>
+                            <td className="p-2">
+                                <button
+                                    className="text-red-500"
+                                    onClick={() => handleTerminate(instance.id)}
+                                >
+                                    Terminate
+                                </button>
+                            </td>
+                        </tr>
+                    ))}
+                    {runningInstances.length === 0 && (
+                        <tr>
+                            <td colSpan="5" className="p-4">
+                                No running instances.
+                            </td>
+                        </tr>
+                    )}
+                </tbody>
+            </table>
         </div>
     );
 }
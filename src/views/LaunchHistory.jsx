@@ -0,0 +1,206 @@
+import React, { useEffect, useState } from "react";
+import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
+
+export default function LaunchHistory() {
+    const [launchSessions, setLaunchSessions] = useState([]);
+    const [onlyFailed, setOnlyFailed] = useState(false);
+    const [selectedLog, setSelectedLog] = useState(null);
+    const [selectedCrashReport, setSelectedCrashReport] = useState(null);
+    const [selectedScriptSnapshots, setSelectedScriptSnapshots] = useState(null);
+
+    useEffect(() => {
+        loadLaunchSessions();
+
+        const unlisten = listen("blender-instance-exited", async () => {
+            await loadLaunchSessions();
+        });
+
+        const unlistenCrashed = listen("blender-instance-crashed", async (event) => {
+            setSelectedLog(null);
+            setSelectedCrashReport(event.payload);
+            await loadLaunchSessions();
+        });
+
+        return () => {
+            unlisten.then((f) => f());
+            unlistenCrashed.then((f) => f());
+        };
+    }, [onlyFailed]);
+
+    const loadLaunchSessions = async () => {
+        try {
+            const sessions = await invoke("fetch_launch_sessions", {
+                id: null,
+                limit: 200,
+                installedBlenderVersionId: null,
+                projectFileId: null,
+                onlyFailed,
+            });
+            setLaunchSessions(sessions);
+        } catch (err) {
+            setLaunchSessions([]);
+            console.error("Failed to load launch sessions:", err);
+        }
+    };
+
+    const handleShowLog = async (id) => {
+        try {
+            const log = await invoke("read_launch_session_log", { id });
+            setSelectedCrashReport(null);
+            setSelectedScriptSnapshots(null);
+            setSelectedLog(log);
+        } catch (err) {
+            setSelectedLog(null);
+            console.error("Failed to read launch session log:", err);
+        }
+    };
+
+    const handleShowCrashReport = async (id) => {
+        try {
+            const crashReport = await invoke("read_launch_session_crash_report", { id });
+            setSelectedLog(null);
+            setSelectedScriptSnapshots(null);
+            setSelectedCrashReport(crashReport);
+        } catch (err) {
+            setSelectedCrashReport(null);
+            console.error("Failed to read launch session crash report:", err);
+        }
+    };
+
+    const handleShowScripts = async (launchSessionId) => {
+        try {
+            const snapshots = await invoke("fetch_python_script_snapshots", {
+                launchSessionId,
+                pythonScriptId: null,
+            });
+            const contents = await Promise.all(
+                snapshots.map((snapshot) =>
+                    invoke("read_python_script_snapshot", { id: snapshot.id }).catch((err) => `${err}`)
+                )
+            );
+            setSelectedLog(null);
+            setSelectedCrashReport(null);
+            setSelectedScriptSnapshots(
+                snapshots.map((snapshot, index) => ({ ...snapshot, content: contents[index] }))
+            );
+        } catch (err) {
+            setSelectedScriptSnapshots(null);
+            console.error("Failed to load python script snapshots:", err);
+        }
+    };
+
+    const handlePurgeLogs = async () => {
+        try {
+            await invoke("purge_launch_session_logs", { olderThanDays: 30 });
+            await loadLaunchSessions();
+        } catch (err) {
+            await loadLaunchSessions();
+            console.error("Failed to purge launch session logs:", err);
+        }
+    };
+
+    return (
+        <div className="p-4">
+            <h1 className="mb-4">Launch History</h1>
+            <div className="mb-4">
+                <label className="mr-4">
+                    <input
+                        type="checkbox"
+                        checked={onlyFailed}
+                        onChange={() => setOnlyFailed(!onlyFailed)}
+                    />
+                    Only failed sessions
+                </label>
+                <button onClick={handlePurgeLogs}>
+                    Purge logs older than 30 days
+                </button>
+            </div>
+            <table className="border-collapse">
+                <thead>
+                    <tr>
+                        <th className="p-2">Blender version</th>
+                        <th className="p-2">Arguments</th>
+                        <th className="p-2">Started</th>
+                        <th className="p-2">Ended</th>
+                        <th className="p-2">Exit code</th>
+                        <th className="p-2">Signal</th>
+                        <th className="p-2">Actions</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {launchSessions.map((entry) => (
+                        <tr key={entry.id}>
+                            <td className="p-2">{entry.blender_version}</td>
+                            <td className="p-2">{JSON.parse(entry.arguments_json).join(" ")}</td>
+                            <td className="p-2">{entry.started}</td>
+                            <td className="p-2">{entry.ended ?? "Running"}</td>
+                            <td className="p-2">{entry.exit_code}</td>
+                            <td className="p-2">{entry.exit_signal}</td>
+                            <td className="p-2">
+                                <button
+                                    disabled={!entry.log_file_path}
+                                    onClick={() => handleShowLog(entry.id)}
+                                >
+                                    Log
+                                </button>
+                                <button
+                                    disabled={!entry.crash_report_file_path && !entry.stderr_tail}
+                                    onClick={() => handleShowCrashReport(entry.id)}
+                                >
+                                    Crash report
+                                </button>
+                                <button
+                                    disabled={JSON.parse(entry.python_script_ids_json).length === 0}
+                                    onClick={() => handleShowScripts(entry.id)}
+                                >
+                                    Scripts
+                                </button>
+                            </td>
+                        </tr>
+                    ))}
+                    {launchSessions.length === 0 && (
+                        <tr>
+                            <td colSpan="7" className="p-4">
+                                No launch sessions found.
+                            </td>
+                        </tr>
+                    )}
+                </tbody>
+            </table>
+            {selectedLog !== null && (
+                <pre className="mt-4 p-2 whitespace-pre-wrap">{selectedLog}</pre>
+            )}
+            {selectedScriptSnapshots !== null && (
+                <div className="mt-4">
+                    {selectedScriptSnapshots.map((snapshot) => (
+                        <div key={snapshot.id} className="mb-4">
+                            <h2 className="mb-2">
+                                {snapshot.script_file_path} ({snapshot.content_hash.slice(0, 12)})
+                            </h2>
+                            <pre className="p-2 whitespace-pre-wrap">{snapshot.content}</pre>
+                        </div>
+                    ))}
+                </div>
+            )}
+            {selectedCrashReport !== null && (
+                <div className="mt-4">
+                    <h2 className="mb-2">
+                        Crash report (exit code: {selectedCrashReport.exit_code ?? "-"}, signal:{" "}
+                        {selectedCrashReport.exit_signal ?? "-"})
+                    </h2>
+                    <p>{selectedCrashReport.crash_report_file_path ?? "No crash file was found."}</p>
+                    {selectedCrashReport.crash_report && (
+                        <pre className="p-2 whitespace-pre-wrap">
+                            {selectedCrashReport.crash_report}
+                        </pre>
+                    )}
+                    <h2 className="mt-2 mb-2">Last stderr lines</h2>
+                    <pre className="p-2 whitespace-pre-wrap">
+                        {selectedCrashReport.stderr_tail.join("\n")}
+                    </pre>
+                </div>
+            )}
+        </div>
+    );
+}
@@ -1,15 +1,21 @@
 import React, { useEffect, useRef, useState } from "react";
 import { invoke } from "@tauri-apps/api/core";
-import { listen } from "@tauri-apps/api/event";
+import { emit, listen } from "@tauri-apps/api/event";
 
 export default function ProjectFiles() {
     const [projectFiles, setProjectFiles] = useState([]);
+    const [thumbnailUrls, setThumbnailUrls] = useState({});
+    const [dependencyTree, setDependencyTree] = useState(null);
+    const [packCompressionMethod, setPackCompressionMethod] = useState("deflate");
     const pendingOpenProjectRef = useRef(null);
 
     useEffect(() => {
         loadProjectFiles();
 
         const unlistenPromise = Promise.all([
+            listen("project-files-changed", async () => {
+                await loadProjectFiles();
+            }),
             listen("create-project-file-confirmed", async (event) => {
                 const { fileName, versionId } = event.payload;
                 try {
@@ -23,8 +29,20 @@ export default function ProjectFiles() {
                     console.error("Failed to create new project file from popup:", err);
                 }
             }),
+            listen("launch-project-file-popup-ready", async () => {
+                const projectFileId = pendingOpenProjectRef.current;
+                if (!projectFileId) return;
+                try {
+                    const versionId = await invoke("fetch_project_file_default_blender_version", {
+                        id: projectFileId,
+                    });
+                    await emit("launch-project-file-default-version", { versionId });
+                } catch (err) {
+                    console.error("Failed to pick default Blender version for project file:", err);
+                }
+            }),
             listen("open-project-file-confirmed", async (event) => {
-                const { versionId, pythonScriptId, launchArgs } = event.payload;
+                const { versionId, pythonScriptIds, pythonExpressions, launchArgs } = event.payload;
                 const projectFileId = pendingOpenProjectRef.current;
                 if (!projectFileId) {
                     console.error("Missing projectFileId — did you forget to set the ref?");
@@ -38,13 +56,15 @@ export default function ProjectFiles() {
                         launchArgumentId = await invoke("insert_launch_argument", {
                             argumentString: launchArgs.trim(),
                             projectFileId,
-                            pythonScriptId: pythonScriptId || null,
+                            pythonScriptId: pythonScriptIds[0] || null,
                         });
                     }
                     await invoke("open_blend_file", {
                         id: projectFileId,
                         installedBlenderVersionId: versionId,
-                        pythonScriptId: pythonScriptId || null,
+                        pythonScriptIds,
+                        pythonExpressions,
+                        scriptArguments: [],
                         launchArgumentsId: launchArgumentId || null,
                     });
                 } catch (err) {
@@ -65,19 +85,38 @@ export default function ProjectFiles() {
 
     const loadProjectFiles = async () => {
         try {
-            await invoke("insert_and_refresh_blend_files");
             const files = await invoke("fetch_blend_files", {
                 id: null,
                 limit: null,
                 filePath: null,
             });
             setProjectFiles(files);
+            await loadThumbnails(files);
         } catch (err) {
             setProjectFiles([]);
             console.error("Failed to load .blend project files:", err);
         }
     };
 
+    const loadThumbnails = async (files) => {
+        const urls = {};
+        for (const entry of files) {
+            try {
+                const bytes = await invoke("fetch_project_file_thumbnail", { id: entry.id });
+                if (bytes) {
+                    const blob = new Blob([new Uint8Array(bytes)], { type: "image/png" });
+                    urls[entry.id] = URL.createObjectURL(blob);
+                }
+            } catch (err) {
+                console.error("Failed to load .blend thumbnail:", err);
+            }
+        }
+        setThumbnailUrls((previousUrls) => {
+            Object.values(previousUrls).forEach((url) => URL.revokeObjectURL(url));
+            return urls;
+        });
+    };
+
     const handleOpen = async (id) => {
         pendingOpenProjectRef.current = id;
         try {
@@ -111,15 +150,40 @@ export default function ProjectFiles() {
         }
     };
 
-    const handleInsertIntoArchive = async (id) => {
+    const handlePack = async (id) => {
         try {
-            await invoke("create_project_file_archive_file", { id });
+            await invoke("pack_project_file", { id, compressionMethod: packCompressionMethod });
         } catch (err) {
             await loadProjectFiles();
-            console.error("Failed to insert .blend file into archive:", err);
+            console.error("Failed to pack .blend file with its dependencies:", err);
         }
     };
 
+    const handleShowDependencies = async (entry) => {
+        try {
+            const dependencies = await invoke("fetch_project_file_dependencies", { id: entry.id });
+            setDependencyTree({ fileName: entry.file_name, dependencies });
+        } catch (err) {
+            setDependencyTree(null);
+            console.error("Failed to load .blend dependencies:", err);
+        }
+    };
+
+    const renderDependencies = (dependencies) => (
+        <ul className="ml-4 list-disc">
+            {dependencies.map((dependency, index) => (
+                <li key={index} className={dependency.is_missing ? "text-red-500" : ""}>
+                    {dependency.dependency_type}: {dependency.name} —{" "}
+                    <span title={dependency.stored_path}>{dependency.resolved_path}</span>
+                    {dependency.is_packed && " (packed)"}
+                    {dependency.is_missing && " (missing)"}
+                    {dependency.dependencies.length > 0 &&
+                        renderDependencies(dependency.dependencies)}
+                </li>
+            ))}
+        </ul>
+    );
+
     const handleCreateNewBlendFile = async () => {
         try {
             await invoke("instance_popup_window", {
@@ -143,14 +207,29 @@ export default function ProjectFiles() {
                 >
                     Create New .blend File
                 </button>
+                <label className="ml-4">
+                    Pack Compression{" "}
+                    <select
+                        value={packCompressionMethod}
+                        onChange={(e) => setPackCompressionMethod(e.target.value)}
+                    >
+                        <option value="deflate">Deflate</option>
+                        <option value="zstd">Zstd</option>
+                    </select>
+                </label>
             </div>
 
             <table className="border-collapse">
                 <thead>
                     <tr>
+                        <th className="p-2">Preview</th>
                         <th className="p-2">File Name</th>
                         <th className="p-2">File Path</th>
                         <th className="p-2">Associated Blender Series</th>
+                        <th className="p-2">Saved With</th>
+                        <th className="p-2">Scenes</th>
+                        <th className="p-2">Frames</th>
+                        <th className="p-2">Render</th>
                         <th className="p-2">Created</th>
                         <th className="p-2">Modified</th>
                         <th className="p-2">Accessed</th>
@@ -167,13 +246,51 @@ export default function ProjectFiles() {
                             seriesList = [];
                         }
 
+                        let sceneNames = [];
+
+                        try {
+                            sceneNames = JSON.parse(entry.scene_names_json);
+                        } catch {
+                            sceneNames = [];
+                        }
+
                         return (
                             <tr key={entry.id}>
+                                <td className="p-2">
+                                    {thumbnailUrls[entry.id] ? (
+                                        <img
+                                            src={thumbnailUrls[entry.id]}
+                                            alt={entry.file_name}
+                                            className="w-16 h-16 object-contain"
+                                        />
+                                    ) : (
+                                        "—"
+                                    )}
+                                </td>
                                 <td className="p-2">{entry.file_name}</td>
                                 <td className="p-2">{entry.file_path}</td>
                                 <td className="p-2">
                                     {seriesList.length > 0 ? seriesList.join(", ") : "—"}
                                 </td>
+                                <td className="p-2">{entry.saved_blender_version ?? "—"}</td>
+                                <td className="p-2">
+                                    {sceneNames.length > 0 ? sceneNames.join(", ") : "—"}
+                                </td>
+                                <td className="p-2">
+                                    {entry.frame_start != null && entry.frame_end != null
+                                        ? `${entry.frame_start}–${entry.frame_end}`
+                                        : "—"}
+                                </td>
+                                <td className="p-2">
+                                    {entry.render_engine ?? "—"}
+                                    {entry.resolution_x != null && entry.resolution_y != null && (
+                                        <div>
+                                            {entry.resolution_x}×{entry.resolution_y}
+                                            {entry.resolution_percentage != null &&
+                                                ` (${entry.resolution_percentage}%)`}
+                                        </div>
+                                    )}
+                                </td>
                                 <td className="p-2">{entry.created}</td>
                                 <td className="p-2">{entry.modified}</td>
                                 <td className="p-2">{entry.accessed}</td>
@@ -195,9 +312,14 @@ export default function ProjectFiles() {
                                         Reveal
                                     </button>
                                     <button
-                                        onClick={() => handleInsertIntoArchive(entry.id)}
+                                        onClick={() => handlePack(entry.id)}
                                     >
-                                        Archive
+                                        Pack
+                                    </button>
+                                    <button
+                                        onClick={() => handleShowDependencies(entry)}
+                                    >
+                                        Dependencies
                                     </button>
                                 </td>
                             </tr>
@@ -205,13 +327,23 @@ export default function ProjectFiles() {
                     })}
                     {projectFiles.length === 0 && (
                         <tr>
-                            <td colSpan="8" className="p-4">
+                            <td colSpan="12" className="p-4">
                                 No project files found.
                             </td>
                         </tr>
                     )}
                 </tbody>
             </table>
+
+            {dependencyTree && (
+                <div className="mt-4">
+                    <h2>Dependencies of {dependencyTree.fileName}</h2>
+                    <button onClick={() => setDependencyTree(null)}>Close</button>
+                    {dependencyTree.dependencies.length > 0
+                        ? renderDependencies(dependencyTree.dependencies)
+                        : <p>No external files referenced.</p>}
+                </div>
+            )}
         </div>
     );
 }
@@ -1,17 +1,42 @@
 import React, { useState, useEffect } from "react";
 import { invoke } from "@tauri-apps/api/core";
+import { listen } from "@tauri-apps/api/event";
 
 const Settings = () => {
     const [repoPaths, setRepoPaths] = useState([]);
     const [launchArgs, setLaunchArgs] = useState([]);
     const [pythonScripts, setPythonScripts] = useState([]);
+    const [libraryFolders, setLibraryFolders] = useState([]);
+    const [cliOptions, setCliOptions] = useState([]);
+    const [presetOptions, setPresetOptions] = useState([]);
+    const [presetPassthrough, setPresetPassthrough] = useState("");
+    const [selectedCliOptionName, setSelectedCliOptionName] = useState("");
+    const [selectedCliOptionValues, setSelectedCliOptionValues] = useState("");
+    const [presetPreview, setPresetPreview] = useState("");
+    const [environmentLaunchArgId, setEnvironmentLaunchArgId] = useState(null);
+    const [environmentVariables, setEnvironmentVariables] = useState([]);
+    const [workingDirectory, setWorkingDirectory] = useState("");
 
     useEffect(() => {
         loadPaths();
         loadLaunchArgs();
         loadPythonScripts();
+        loadLibraryFolders();
+        loadCliOptions();
+
+        const unlistenScriptsChanged = listen("python-scripts-changed", async () => {
+            await loadPythonScripts();
+        });
+
+        return () => {
+            unlistenScriptsChanged.then((f) => f());
+        };
     }, []);
 
+    useEffect(() => {
+        previewPreset();
+    }, [presetOptions, presetPassthrough]);
+
     const loadPaths = async () => {
         try {
             const paths = await invoke("fetch_blender_version_installation_locations", {
@@ -40,6 +65,67 @@ const Settings = () => {
         }
     };
 
+    const loadCliOptions = async () => {
+        try {
+            const options = await invoke("fetch_blender_cli_options");
+            setCliOptions(options);
+        } catch (err) {
+            setCliOptions([]);
+            console.error("Failed to fetch Blender CLI options:", err);
+        }
+    };
+
+    const getPreset = () => ({
+        options: presetOptions,
+        passthrough_arguments: presetPassthrough.split(" ").filter((e) => e !== ""),
+    });
+
+    const previewPreset = async () => {
+        try {
+            const argumentString = await invoke("preview_launch_argument_preset", {
+                preset: getPreset(),
+            });
+            setPresetPreview(argumentString);
+        } catch (err) {
+            setPresetPreview(err);
+        }
+    };
+
+    const handleAddPresetOption = () => {
+        if (!selectedCliOptionName) {
+            return;
+        }
+        setPresetOptions([
+            ...presetOptions,
+            {
+                name: selectedCliOptionName,
+                values: selectedCliOptionValues.split(" ").filter((e) => e !== ""),
+            },
+        ]);
+        setSelectedCliOptionValues("");
+    };
+
+    const handleRemovePresetOption = (index) => {
+        setPresetOptions(presetOptions.filter((_, i) => i !== index));
+    };
+
+    const handleSavePreset = async () => {
+        try {
+            await invoke("insert_launch_argument", {
+                argumentString: "",
+                projectFileId: null,
+                pythonScriptId: null,
+                preset: getPreset(),
+            });
+            setPresetOptions([]);
+            setPresetPassthrough("");
+            await loadLaunchArgs();
+        } catch (err) {
+            await loadLaunchArgs();
+            console.error("Failed to save launch argument preset:", err);
+        }
+    };
+
     const loadPythonScripts = async () => {
         try {
             const scripts = await invoke("fetch_python_scripts", {
@@ -54,9 +140,55 @@ const Settings = () => {
         }
     };
 
+    const loadLibraryFolders = async () => {
+        try {
+            const folders = await invoke("fetch_python_script_library_folders");
+            setLibraryFolders(folders);
+        } catch (err) {
+            setLibraryFolders([]);
+            console.error("Failed to fetch script library folders:", err);
+        }
+    };
+
+    const handleAddLibraryFolder = async () => {
+        try {
+            await invoke("insert_python_script_library_folder");
+            await invoke("restart_file_watcher");
+            await loadLibraryFolders();
+            await loadPythonScripts();
+        } catch (err) {
+            await loadLibraryFolders();
+            console.error("Failed to insert script library folder:", err);
+        }
+    };
+
+    const handleSyncLibraryFolders = async () => {
+        try {
+            await invoke("sync_python_script_library_folders");
+            await loadPythonScripts();
+        } catch (err) {
+            await loadPythonScripts();
+            console.error("Failed to sync script library folders:", err);
+        }
+    };
+
+    const handleDeleteLibraryFolder = async (id) => {
+        try {
+            await invoke("delete_python_script_library_folder", { id });
+            await invoke("restart_file_watcher");
+            await loadLibraryFolders();
+            await loadPythonScripts();
+        } catch (err) {
+            await loadLibraryFolders();
+            console.error("Failed to delete script library folder:", err);
+        }
+    };
+
     const handleAddPath = async () => {
         try {
             await invoke("insert_blender_version_installation_location");
+            await invoke("insert_and_refresh_installed_blender_versions");
+            await invoke("restart_file_watcher");
             await loadPaths();
         } catch (err) {
             await loadPaths();
@@ -77,11 +209,36 @@ const Settings = () => {
         }
     };
 
+    const handleChangeScanSettings = (selectedId, field, value) => {
+        setRepoPaths((paths) =>
+            paths.map((e) => (e.id === selectedId ? { ...e, [field]: value } : e))
+        );
+    };
+
+    const handleSaveScanSettings = async (selectedId) => {
+        try {
+            const entry = repoPaths.find((e) => e.id === selectedId);
+            await invoke("update_blender_version_installation_location_scan_settings", {
+                id: selectedId,
+                scanDepth: Number(entry.scan_depth) || 1,
+                includePatterns: entry.include_patterns,
+                excludePatterns: entry.exclude_patterns,
+            });
+            await invoke("insert_and_refresh_installed_blender_versions");
+            await invoke("restart_file_watcher");
+            await loadPaths();
+        } catch (err) {
+            await loadPaths();
+            console.error("Failed to update scan settings:", err);
+        }
+    };
+
     const handleSetDefaultLaunchArg = async (selectedId) => {
         try {
             await invoke("update_launch_argument", {
                 id: selectedId,
                 isDefault: launchArgs.find((e) => e.id === selectedId).is_default,
+                argumentString: null,
             });
             await loadLaunchArgs();
         } catch (err) {
@@ -93,6 +250,8 @@ const Settings = () => {
     const handleDeleteBlenderVersionInstallationPath = async (id) => {
         try {
             await invoke("delete_blender_version_installation_location", { id });
+            await invoke("insert_and_refresh_installed_blender_versions");
+            await invoke("restart_file_watcher");
             await loadPaths();
         } catch (err) {
             await loadPaths();
@@ -100,6 +259,59 @@ const Settings = () => {
         }
     };
 
+    const handleLaunchArgChange = (id, value) => {
+        setLaunchArgs(
+            launchArgs.map((entry) =>
+                entry.id === id ? { ...entry, argument_string: value } : entry
+            )
+        );
+    };
+
+    const handleSaveLaunchArg = async (entry) => {
+        try {
+            await invoke("update_launch_argument", {
+                id: entry.id,
+                isDefault: null,
+                argumentString: entry.argument_string,
+            });
+            await loadLaunchArgs();
+        } catch (err) {
+            await loadLaunchArgs();
+            console.error("Failed to update launch argument:", err);
+        }
+    };
+
+    const handleEditEnvironment = (entry) => {
+        setEnvironmentLaunchArgId(entry.id);
+        setEnvironmentVariables(JSON.parse(entry.environment_json));
+        setWorkingDirectory(entry.working_directory ?? "");
+    };
+
+    const handleEnvironmentVariableChange = (index, key, value) => {
+        setEnvironmentVariables(
+            environmentVariables.map((entry, i) =>
+                i === index ? { ...entry, [key]: value } : entry
+            )
+        );
+    };
+
+    const handleSaveEnvironment = async () => {
+        try {
+            await invoke("update_launch_argument_environment", {
+                id: environmentLaunchArgId,
+                environment: {
+                    variables: environmentVariables,
+                    working_directory: workingDirectory,
+                },
+            });
+            setEnvironmentLaunchArgId(null);
+            await loadLaunchArgs();
+        } catch (err) {
+            await loadLaunchArgs();
+            console.error("Failed to update launch environment:", err);
+        }
+    };
+
     const handleDeleteLaunchArg = async (id) => {
         try {
             await invoke("delete_launch_argument", { id });
@@ -110,6 +322,26 @@ const Settings = () => {
         }
     };
 
+    const handlePythonScriptChange = (id, key, value) => {
+        setPythonScripts(
+            pythonScripts.map((entry) => (entry.id === id ? { ...entry, [key]: value } : entry))
+        );
+    };
+
+    const handleSavePythonScript = async (entry) => {
+        try {
+            await invoke("update_python_script", {
+                id: entry.id,
+                name: entry.name,
+                description: entry.description,
+            });
+            await loadPythonScripts();
+        } catch (err) {
+            await loadPythonScripts();
+            console.error("Failed to update python script:", err);
+        }
+    };
+
     const handleDeletePythonScript = async (id) => {
         try {
             await invoke("delete_python_script", { id });
@@ -137,6 +369,9 @@ const Settings = () => {
                 <thead>
                     <tr>
                         <th className="p-2">Directory path</th>
+                        <th className="p-2">Scan depth</th>
+                        <th className="p-2">Include patterns</th>
+                        <th className="p-2">Exclude patterns</th>
                         <th className="p-2">Created</th>
                         <th className="p-2">Modified</th>
                         <th className="p-2">Accessed</th>
@@ -148,6 +383,30 @@ const Settings = () => {
                     {repoPaths.map((entry) => (
                         <tr key={entry.id}>
                             <td className="p-2">{entry.repo_directory_path}</td>
+                            <td className="p-2">
+                                <input
+                                    type="number"
+                                    min="1"
+                                    value={entry.scan_depth}
+                                    onChange={(e) => handleChangeScanSettings(entry.id, "scan_depth", e.target.value)}
+                                />
+                            </td>
+                            <td className="p-2">
+                                <input
+                                    type="text"
+                                    placeholder="4.x/*;daily/*"
+                                    value={entry.include_patterns}
+                                    onChange={(e) => handleChangeScanSettings(entry.id, "include_patterns", e.target.value)}
+                                />
+                            </td>
+                            <td className="p-2">
+                                <input
+                                    type="text"
+                                    placeholder="*alpha*"
+                                    value={entry.exclude_patterns}
+                                    onChange={(e) => handleChangeScanSettings(entry.id, "exclude_patterns", e.target.value)}
+                                />
+                            </td>
                             <td className="p-2">{entry.created}</td>
                             <td className="p-2">{entry.modified}</td>
                             <td className="p-2">{entry.accessed}</td>
@@ -159,6 +418,11 @@ const Settings = () => {
                                 />
                             </td>
                             <td className="p-2">
+                                <button
+                                    onClick={() => handleSaveScanSettings(entry.id)}
+                                >
+                                    Save
+                                </button>
                                 <button
                                     className="text-red-500 "
                                     onClick={() => handleDeleteBlenderVersionInstallationPath(entry.id)}
@@ -186,7 +450,13 @@ const Settings = () => {
                 <tbody>
                     {launchArgs.map((arg) => (
                         <tr key={arg.id}>
-                            <td className="p-2">{arg.argument_string}</td>
+                            <td className="p-2">
+                                <input
+                                    type="text"
+                                    value={arg.argument_string}
+                                    onChange={(e) => handleLaunchArgChange(arg.id, e.target.value)}
+                                />
+                            </td>
                             <td className="p-2">{arg.created}</td>
                             <td className="p-2">{arg.modified}</td>
                             <td className="p-2">{arg.accessed}</td>
@@ -198,6 +468,12 @@ const Settings = () => {
                                 />
                             </td>
                             <td className="p-2">
+                                <button onClick={() => handleSaveLaunchArg(arg)}>
+                                    Save
+                                </button>
+                                <button onClick={() => handleEditEnvironment(arg)}>
+                                    Environment
+                                </button>
                                 <button
                                     className="text-red-500 "
                                     onClick={() => handleDeleteLaunchArg(arg.id)}
@@ -210,11 +486,172 @@ const Settings = () => {
                 </tbody>
             </table>
 
+            {environmentLaunchArgId !== null && (
+                <div className="mb-6">
+                    <h2 className="mb-2">Launch Environment</h2>
+                    <p>
+                        Values may use ${"{PROJECT_DIR}"}, ${"{PROJECT_FILE}"}, ${"{PROJECT_NAME}"} and
+                        launcher environment variables.
+                    </p>
+                    {environmentVariables.map((entry, index) => (
+                        <div key={index}>
+                            <input
+                                type="text"
+                                placeholder="Name"
+                                value={entry.name}
+                                onChange={(e) =>
+                                    handleEnvironmentVariableChange(index, "name", e.target.value)
+                                }
+                            />
+                            <select
+                                value={entry.action}
+                                onChange={(e) =>
+                                    handleEnvironmentVariableChange(index, "action", e.target.value)
+                                }
+                            >
+                                <option value="set">Set</option>
+                                <option value="unset">Unset</option>
+                                <option value="prepend_path">Prepend to path</option>
+                            </select>
+                            <input
+                                type="text"
+                                placeholder="Value"
+                                value={entry.value}
+                                disabled={entry.action === "unset"}
+                                onChange={(e) =>
+                                    handleEnvironmentVariableChange(index, "value", e.target.value)
+                                }
+                            />
+                            <button
+                                className="text-red-500 "
+                                onClick={() =>
+                                    setEnvironmentVariables(
+                                        environmentVariables.filter((_, i) => i !== index)
+                                    )
+                                }
+                            >
+                                Remove
+                            </button>
+                        </div>
+                    ))}
+                    <button
+                        onClick={() =>
+                            setEnvironmentVariables([
+                                ...environmentVariables,
+                                { name: "", action: "set", value: "" },
+                            ])
+                        }
+                    >
+                        Add Variable
+                    </button>
+                    <div className="mt-2">
+                        <input
+                            type="text"
+                            placeholder="Working directory"
+                            value={workingDirectory}
+                            onChange={(e) => setWorkingDirectory(e.target.value)}
+                        />
+                    </div>
+                    <button className="mt-2 bg-green-500" onClick={handleSaveEnvironment}>
+                        Save Environment
+                    </button>
+                    <button className="mt-2" onClick={() => setEnvironmentLaunchArgId(null)}>
+                        Cancel
+                    </button>
+                </div>
+            )}
+
+            <h2 className="mt-8 mb-2">New Launch Argument Preset</h2>
+            <div className="mb-6">
+                <select
+                    value={selectedCliOptionName}
+                    onChange={(e) => setSelectedCliOptionName(e.target.value)}
+                >
+                    <option value="">Select option</option>
+                    {cliOptions.map((option) => (
+                        <option key={option.name} value={option.name} title={option.description}>
+                            {option.name} ({option.category})
+                        </option>
+                    ))}
+                </select>
+                <input
+                    type="text"
+                    placeholder="Values"
+                    value={selectedCliOptionValues}
+                    onChange={(e) => setSelectedCliOptionValues(e.target.value)}
+                />
+                <button onClick={handleAddPresetOption}>Add Option</button>
+                <ul>
+                    {presetOptions.map((option, index) => (
+                        <li key={index}>
+                            {option.name} {option.values.join(" ")}
+                            <button
+                                className="text-red-500 "
+                                onClick={() => handleRemovePresetOption(index)}
+                            >
+                                Remove
+                            </button>
+                        </li>
+                    ))}
+                </ul>
+                <input
+                    type="text"
+                    placeholder="Passthrough arguments"
+                    value={presetPassthrough}
+                    onChange={(e) => setPresetPassthrough(e.target.value)}
+                />
+                <p className="mt-2">{presetPreview}</p>
+                <button className="mt-2 bg-green-500" onClick={handleSavePreset}>
+                    Save Preset
+                </button>
+            </div>
+
+            <h2 className="mt-8 mb-2">Script Library Folders</h2>
+            <div className="mb-6">
+                <button className="mt-2 bg-green-500" onClick={handleAddLibraryFolder}>
+                    Add Folder
+                </button>
+                <button className="mt-2" onClick={handleSyncLibraryFolders}>
+                    Sync
+                </button>
+            </div>
+            <table className="border-collapse mb-6">
+                <thead>
+                    <tr>
+                        <th className="p-2">Folder path</th>
+                        <th className="p-2">Created</th>
+                        <th className="p-2">Actions</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {libraryFolders.map((folder) => (
+                        <tr key={folder.id}>
+                            <td className="p-2">{folder.folder_path}</td>
+                            <td className="p-2">{folder.created}</td>
+                            <td className="p-2">
+                                <button
+                                    className="text-red-500 "
+                                    onClick={() => handleDeleteLibraryFolder(folder.id)}
+                                >
+                                    Delete
+                                </button>
+                            </td>
+                        </tr>
+                    ))}
+                </tbody>
+            </table>
+
             <h2 className="mt-8 mb-2">Python Scripts</h2>
             <table className="border-collapse">
                 <thead>
                     <tr>
+                        <th className="p-2">Name</th>
+                        <th className="p-2">Description</th>
                         <th className="p-2">Script Path</th>
+                        <th className="p-2">Tags</th>
+                        <th className="p-2">Version</th>
+                        <th className="p-2">Content Hash</th>
+                        <th className="p-2">Safety</th>
                         <th className="p-2">Created</th>
                         <th className="p-2">Modified</th>
                         <th className="p-2">Accessed</th>
@@ -224,11 +661,48 @@ const Settings = () => {
                 <tbody>
                     {pythonScripts.map((script) => (
                         <tr key={script.id}>
-                            <td className="p-2">{script.script_file_path}</td>
+                            <td className="p-2">
+                                <input
+                                    type="text"
+                                    value={script.name}
+                                    onChange={(e) =>
+                                        handlePythonScriptChange(script.id, "name", e.target.value)
+                                    }
+                                />
+                            </td>
+                            <td className="p-2">
+                                <input
+                                    type="text"
+                                    value={script.description}
+                                    onChange={(e) =>
+                                        handlePythonScriptChange(script.id, "description", e.target.value)
+                                    }
+                                />
+                            </td>
+                            <td className={script.is_missing ? "p-2 text-red-500" : "p-2"}>
+                                {script.script_file_path}
+                                {script.is_missing && " (missing)"}
+                            </td>
+                            <td className="p-2">{JSON.parse(script.tags_json).join(", ") || "-"}</td>
+                            <td className="p-2">{JSON.parse(script.metadata_json).version ?? "-"}</td>
+                            <td className="p-2">{script.content_hash?.slice(0, 12) ?? "-"}</td>
+                            <td
+                                className={JSON.parse(script.safety_warnings_json).length > 0 ? "p-2 text-red-500" : "p-2"}
+                                title={JSON.parse(script.safety_warnings_json)
+                                    .map((e) => `Line ${e.line}: ${e.message}`)
+                                    .join("\n")}
+                            >
+                                {JSON.parse(script.safety_warnings_json).length > 0
+                                    ? `${JSON.parse(script.safety_warnings_json).length} warnings`
+                                    : "OK"}
+                            </td>
                             <td className="p-2">{script.created}</td>
                             <td className="p-2">{script.modified}</td>
                             <td className="p-2">{script.accessed}</td>
                             <td className="p-2 ">
+                                <button onClick={() => handleSavePythonScript(script)}>
+                                    Save
+                                </button>
                                 <button
                                     className="text-red-500"
                                     onClick={() => handleDeletePythonScript(script.id)}